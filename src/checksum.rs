@@ -0,0 +1,168 @@
+// --------------------------------------------------
+// external
+// --------------------------------------------------
+use thiserror::Error;
+
+/// Key of the checksum item, for MISB 0601, MISB 0806 and MISB 0903
+pub const CHECKSUM_KEY: u8 = 0x01;
+
+/// Length of the checksum item, for MISB 0601, MISB 0806 and MISB 0903
+pub const CHECKSUM_LEN: u8 = 0x02;
+
+#[derive(Error, Debug, PartialEq)]
+/// Error type when computing or verifying a MISB checksum
+pub enum ChecksumError {
+    #[error("Checksum mismatch: expected {expected:#06X}, got {actual:#06X}.")]
+    /// The checksum computed over the packet does not match the
+    /// checksum within the packet
+    Mismatch {
+        /// Checksum computed over the packet
+        expected: u16,
+        /// Checksum contained within the packet
+        actual: u16,
+    },
+    #[error("Checksum is not the final item of the packet.")]
+    /// The packet does not end with a checksum item
+    NotFound,
+}
+
+/// Running 16-bit sum over `input`, where bytes at even indices
+/// are summed as the upper byte and bytes at odd indices are
+/// summed as the lower byte. Overflow is discarded.
+/// 
+/// This is the checksum algorithm defined in MISB ST 0601, and
+/// referenced by MISB ST 0903 for standalone VMTI.
+/// 
+/// # Example
+/// 
+/// ```
+/// use misb::checksum::bcc16;
+/// assert_eq!(bcc16(&[0x01, 0x02, 0x03]), 0x0402);
+/// assert_eq!(bcc16(&[0xFF, 0xFF, 0x00, 0x01]), 0x0000);
+/// ```
+pub fn bcc16(input: &[u8]) -> u16 {
+    input
+        .iter()
+        .enumerate()
+        .fold(0u16, |acc, (i, byte)| acc.wrapping_add((*byte as u16) << (8 * ((i + 1) % 2))))
+}
+
+/// CRC-16-CCITT over `input`, using the polynomial `0x1021`, an
+/// initial value of `0xFFFF`, and no final XOR
+/// 
+/// This is the checksum algorithm defined in MISB ST 0806.
+/// 
+/// # Example
+/// 
+/// ```
+/// use misb::checksum::crc16_ccitt;
+/// assert_eq!(crc16_ccitt(b"123456789"), 0x29B1);
+/// assert_eq!(crc16_ccitt(&[]), 0xFFFF);
+/// ```
+pub fn crc16_ccitt(input: &[u8]) -> u16 {
+    input.iter().fold(0xFFFFu16, |acc, byte| {
+        (0..8).fold(acc ^ ((*byte as u16) << 8), |crc, _| match crc & 0x8000 {
+            0 => crc << 1,
+            _ => (crc << 1) ^ 0x1021,
+        })
+    })
+}
+
+/// Computes the checksum of a complete packet, from the first byte
+/// of the UL key to the last byte of the checksum value
+/// 
+/// The checksum item must be the final item in the packet. The sum
+/// covers every byte up to and including the checksum's key and
+/// length, meaning the current checksum value is ignored.
+pub fn compute(packet: &[u8]) -> Result<u16, ChecksumError> {
+    compute_with(packet, bcc16)
+}
+
+/// Same as [`compute`], using the checksum `algorithm` provided,
+/// e.g. [`bcc16`] or [`crc16_ccitt`]
+pub fn compute_with(packet: &[u8], algorithm: fn(&[u8]) -> u16) -> Result<u16, ChecksumError> {
+    let n = packet.len();
+    if n < 4 || packet[n - 4..n - 2] != [CHECKSUM_KEY, CHECKSUM_LEN] { return Err(ChecksumError::NotFound) }
+    Ok(algorithm(&packet[..n - 2]))
+}
+
+/// Verifies the checksum of a complete packet, from the first byte
+/// of the UL key to the last byte of the checksum value
+/// 
+/// See [`compute`]
+pub fn verify(packet: &[u8]) -> Result<(), ChecksumError> {
+    verify_with(packet, bcc16)
+}
+
+/// Same as [`verify`], using the checksum `algorithm` provided,
+/// e.g. [`bcc16`] or [`crc16_ccitt`]
+pub fn verify_with(packet: &[u8], algorithm: fn(&[u8]) -> u16) -> Result<(), ChecksumError> {
+    let n = packet.len();
+    let expected = compute_with(packet, algorithm)?;
+    let actual = u16::from_be_bytes([packet[n - 2], packet[n - 1]]);
+    match expected == actual {
+        true => Ok(()),
+        false => Err(ChecksumError::Mismatch { expected, actual }),
+    }
+}
+
+/// Re-computes the checksum of a complete, already-encoded packet and
+/// writes it in place, returning the new checksum
+/// 
+/// Useful when modifying items of a packet (e.g., re-stamping the
+/// precision timestamp) without fully re-encoding it.
+/// 
+/// See [`compute`]
+/// 
+/// # Example
+/// 
+/// ```
+/// use misb::checksum;
+/// let mut packet = vec![
+///     0x06, 0x0E, 0x2B, 0x34, 0x02, 0x0B, 0x01, 0x01, 0x0E, 0x01, 0x03, 0x01, 0x01, 0x00, 0x00, 0x00,
+///     0x0E, 0x02, 0x08, 0x00, 0x04, 0x59, 0xF4, 0xA6, 0xAA, 0x4A, 0xA8, 0x01, 0x02, 0x00, 0x00,
+/// ];
+/// assert!(checksum::verify(&packet).is_err());
+/// let value = checksum::patch(&mut packet).unwrap();
+/// assert_eq!(checksum::verify(&packet), Ok(()));
+/// assert_eq!(packet[packet.len() - 2..], value.to_be_bytes());
+/// ```
+pub fn patch(packet: &mut [u8]) -> Result<u16, ChecksumError> {
+    let n = packet.len();
+    let value = compute(packet)?;
+    packet[n - 2..].copy_from_slice(&value.to_be_bytes());
+    Ok(value)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn verify_and_patch() {
+        let mut packet = vec![
+            0x06, 0x0E, 0x2B, 0x34, 0x02, 0x0B, 0x01, 0x01, 0x0E, 0x01, 0x03, 0x01, 0x01, 0x00, 0x00, 0x00,
+            0x0E, 0x02, 0x08, 0x00, 0x04, 0x59, 0xF4, 0xA6, 0xAA, 0x4A, 0xA8, 0x01, 0x02, 0x00, 0x00,
+        ];
+        let expected = bcc16(&packet[..packet.len() - 2]);
+        assert_eq!(compute(&packet), Ok(expected));
+        assert_eq!(verify(&packet), Err(ChecksumError::Mismatch { expected, actual: 0x0000 }));
+        assert_eq!(patch(&mut packet), Ok(expected));
+        assert_eq!(verify(&packet), Ok(()));
+        // --------------------------------------------------
+        // re-stamp the timestamp, then patch again. byte 26
+        // is at an even index, so it is the upper byte of the sum
+        // --------------------------------------------------
+        packet[26] = 0xA9;
+        assert!(verify(&packet).is_err());
+        assert_eq!(patch(&mut packet), Ok(expected.wrapping_add(0x100)));
+        assert_eq!(verify(&packet), Ok(()));
+    }
+
+    #[test]
+    fn not_found() {
+        assert_eq!(compute(&[]), Err(ChecksumError::NotFound));
+        assert_eq!(verify(&[0x02, 0x01, 0x00, 0x00]), Err(ChecksumError::NotFound));
+        assert_eq!(patch(&mut [0x01, 0x02, 0x00]), Err(ChecksumError::NotFound));
+    }
+}
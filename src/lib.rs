@@ -1,14 +1,56 @@
 #![forbid(unsafe_code)]
+
+// --------------------------------------------------
+// tinyklv
+// --------------------------------------------------
+use tinyklv::prelude::*;
+
+pub mod checksum;
 pub mod misb0102;
 pub mod misb0601;
+pub mod misb0806;
 pub mod misb0903;
+pub mod misb1002;
+pub mod misb1010;
 pub mod misb1201;
 pub mod misb1204;
+pub mod misb1206;
+pub mod misb1303;
+pub mod misb1601;
+pub mod misb1602;
+pub mod misb1607;
 
 /// Length in bytes of a type
 pub trait LengthBytes {
     const LENGTH_BYTES: usize;
 }
 
+/// Appends a single item to `output`, as a BER-OID encoded key,
+/// BER encoded length, and then the value itself
+pub(crate) fn encode_item(output: &mut Vec<u8>, key: u64, value: &[u8]) {
+    output.extend(tinyklv::codecs::ber::enc::ber_oid(&key));
+    output.extend(tinyklv::codecs::ber::enc::ber_length(&value.len()));
+    output.extend_from_slice(value);
+}
+
+/// Decodes a `T` from the value of a single item of length `len`,
+/// failing if `T` does not consume the entire value
+/// 
+/// Used for local sets and packs embedded as the value of an item, since
+/// their own decoders are not bounded by the length of the item.
+pub(crate) fn decode_exact<T>(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<T>
+where
+    T: for<'a> tinyklv::prelude::Decode<&'a [u8]>,
+{
+    move |input: &mut &[u8]| {
+        let mut value = winnow::token::take(len).parse_next(input)?;
+        let output = T::decode(&mut value)?;
+        match value.is_empty() {
+            true => Ok(output),
+            false => Err(tinyklv::err!()),
+        }
+    }
+}
+
 #[path = "misb0903/target/mod.rs"] mod module_name;
 mod sandbox;
\ No newline at end of file
@@ -28,13 +28,15 @@ fn main() {
     ];
     let input = &mut data;
 
-    let results: winnow::PResult<Vec<Misb0601>> = winnow::combinator::repeat(0.., Misb0601::extract).parse_next(input);
     // Misb0601::decode(input);
     // let res = .parse_next(input);
 
-    match results {
-        Ok(results) => for res in results {
-            println!("{:?}", res);
+    match Misb0601::demux(input) {
+        Ok(substreams) => for (id, results) in substreams {
+            println!("substream {:?}", id);
+            for res in results {
+                println!("{:?}", res);
+            }
         },
         Err(e) => println!("{:?}", e),
     }
@@ -197,6 +197,44 @@ pub struct Misb0102 {
     pub object_country_coding_method_version_date: chrono::NaiveDate,
 }
 
+#[cfg(any(
+    feature = "misb0102-12",
+))]
+/// [`Misb0102`] implementation
+impl Misb0102 {
+    /// Encodes the items of the [`Misb0102`] as a Local Set value,
+    /// without the Universal Label (UL) or length
+    /// 
+    /// Used when nesting within another Local Set, such as
+    /// [`crate::misb0601::Misb0601::security_local_set`]
+    pub fn encode_value(&self) -> Vec<u8> {
+        let mut output = Vec::new();
+        crate::encode_item(&mut output, 0x01, &self.security_classification.encode());
+        crate::encode_item(&mut output, 0x02, &self.country_coding_method.encode());
+        crate::encode_item(&mut output, 0x03, self.classifying_country.as_bytes());
+        if let Some(value) = &self.sci_shi_information { crate::encode_item(&mut output, 0x04, value.as_bytes()) }
+        if let Some(value) = &self.caveats { crate::encode_item(&mut output, 0x05, value.as_bytes()) }
+        if let Some(value) = &self.releasing_instructions { crate::encode_item(&mut output, 0x06, value.as_bytes()) }
+        if let Some(value) = &self.classified_by { crate::encode_item(&mut output, 0x07, value.as_bytes()) }
+        if let Some(value) = &self.derived_from { crate::encode_item(&mut output, 0x08, value.as_bytes()) }
+        if let Some(value) = &self.classification_reason { crate::encode_item(&mut output, 0x09, value.as_bytes()) }
+        if let Some(value) = &self.declassification_date {
+            crate::encode_item(&mut output, 0x0A, value.format("%Y%m%d").to_string().as_bytes())
+        }
+        if let Some(value) = &self.classification_and_marking_system { crate::encode_item(&mut output, 0x0B, value.as_bytes()) }
+        if let Some(value) = &self.object_country_coding_method { crate::encode_item(&mut output, 0x0C, &value.encode()) }
+        if let Some(value) = &self.object_country_codes {
+            let value: Vec<u8> = value.encode_utf16().flat_map(u16::to_be_bytes).collect();
+            crate::encode_item(&mut output, 0x0D, &value)
+        }
+        if let Some(value) = &self.classification_comments { crate::encode_item(&mut output, 0x0E, value.as_bytes()) }
+        crate::encode_item(&mut output, 0x16, &self.version.to_be_bytes());
+        crate::encode_item(&mut output, 0x17, self.country_coding_method_version_date.format("%Y-%m-%d").to_string().as_bytes());
+        crate::encode_item(&mut output, 0x18, self.object_country_coding_method_version_date.format("%Y-%m-%d").to_string().as_bytes());
+        output
+    }
+}
+
 #[derive(Const)]
 #[armtype(u8)]
 /// MISB Standard 0102 Security Classification
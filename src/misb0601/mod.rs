@@ -1,3 +1,8 @@
+// --------------------------------------------------
+// external
+// --------------------------------------------------
+use thiserror::Error;
+
 // --------------------------------------------------
 // tinyklv
 // --------------------------------------------------
@@ -8,6 +13,14 @@ use tinyklv::prelude::*;
 // local
 // --------------------------------------------------
 pub mod ops;
+pub mod primitives;
+pub use primitives::{WeaponLoad, WeaponFired, SensorFovName, ControlCommand, SensorControlMode, Wavelength, Payload, PayloadType};
+pub use primitives::{WeaponsStore, WeaponGeneralStatus, WeaponEngagementStatus, Waypoint, WaypointMode, WaypointSource, WaypointLocation};
+pub use primitives::{CountryCodes, PositioningMethodSource, PlatformStatus, SensorFrameRate};
+pub use primitives::{ImageHorizon, ImageHorizonLocations, ViewDomain, ViewDomainInterval, MetadataSubstreamId};
+
+/// MISB 0601 UAS Datalink LS Universal Label (UL)
+pub const UAS_DATALINK_LS_UL: &[u8; 16] = b"\x06\x0E\x2B\x34\x02\x0B\x01\x01\x0E\x01\x03\x01\x01\x00\x00\x00";
 
 #[cfg(any(
     feature = "misb0601-19",
@@ -552,7 +565,7 @@ pub struct Misb0601 {
     #[cfg(any(
         feature = "misb0601-19",
     ))]
-    #[klv(key = 0x30, dec = crate::misb0102::Misb0102::decode)]
+    #[klv(key = 0x30, dyn = true, dec = crate::decode_exact::<crate::misb0102::Misb0102>)]
     /// (Optional) MISB ST 0102 Local Set Security Metadata items
     /// 
     /// Units: None
@@ -593,84 +606,2217 @@ pub struct Misb0601 {
     /// Resolution: ~0.0055 m/s
     pub platform_vertical_speed: Option<f32>,
 
-    // #[cfg(any(
-    //     feature = "misb0601-19",
-    // ))]
-    // #[klv(key = 0x34)]
-    // pub platform_sideslip_angle: Option<f32>,
-}
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x34, dec = ops::to_platform_pitch_angle)]
+    /// (Optional) Angle between the platform longitudinal axis and relative wind
+    /// 
+    /// Units: Degrees (°)
+    /// 
+    /// Resolution: ~610 microdegrees
+    pub platform_sideslip_angle: Option<f32>,
 
-#[derive(Debug, PartialEq)]
-/// Icing status on the aircraft (i.e., the wings). Icing on
-/// wings can affect the continuation of the mission
-pub enum Icing {
-    DetectorOff,
-    NoIcingDetected,
-    IcingDetected,
-}
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x35, dec = ops::to_mbar_pressure)]
+    /// (Optional) Local pressure at airfield of known height
+    /// 
+    /// Units: Millibar (mbar)
+    /// 
+    /// Resolution: ~0.08 mbar
+    pub airfield_barometric_pressure: Option<f32>,
 
-#[derive(Debug, PartialEq)]
-/// IR sensor images use either black values indicating
-/// hot or white values indicating hot
-pub enum IrPolarity {
-    BlackHot,
-    WhiteHot,
-}
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x36, dec = ops::to_alt)]
+    /// (Optional) Elevation of airfield corresponding to airfield barometric pressure
+    /// 
+    /// Units: Meters (m)
+    /// 
+    /// Resolution: ~0.3 meters
+    pub airfield_elevation: Option<f32>,
 
-#[derive(Debug, PartialEq)]
-/// Slant range is measured (i.e., using Laser Range
-/// Finder) or calculated using gimbal/aircraft position
-/// and angles
-pub enum SlantRangeSource {
-    Measured,
-    Calculated,
-}
-#[derive(Debug, PartialEq)]
-/// See [`crate::misb0601::Misb0601::generic_flag_data`]
-pub struct GenericFlagData {
-    /// Laser Range Finder can be used to aid in geopositioning
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x37, dec = ops::to_relative_humidity)]
+    /// (Optional) Relative humidity at aircraft location
     /// 
-    /// Indicates whether or not laser range finder is on
-    pub laser_range_on: bool,
-    /// Sensor steering is automatically controlled by onboard tracking system
+    /// Units: Percent (%)
     /// 
-    /// Indicates whether or not sensor steering is on
-    pub auto_track_on: bool,
-    /// Indicates IR polarity
-    pub ir_polarity: IrPolarity,
-    /// Indicates icing status
-    pub icing_status: Icing,
-    /// Indicates if slant range is measured or calculated
-    pub slant_range_source: SlantRangeSource,
-    /// An invalid image may result from a lens change,
-    /// bad focus or other camera issues which
-    /// significantly degrades the image
+    /// Resolution: ~0.4%
+    pub relative_humidity: Option<f32>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x38)]
+    /// (Optional) Speed projected to the ground of an airborne platform passing overhead
     /// 
-    /// Indicates if image is invalid
-    pub is_image_invalid: bool,
-}
-#[cfg(feature = "misb0601-19")]
-/// [`GenericFlagData`] implementation of [`tinyklv::prelude::Decode`]
-impl tinyklv::prelude::Decode<&[u8]> for GenericFlagData {
-    /// See [`crate::misb0601::Misb0601::generic_flag_data`]
-    fn decode(input: &mut &[u8]) -> winnow::PResult<Self> {
-        let value = tinyklv::codecs::binary::dec::be_u8.parse_next(input)?;
-        Ok(crate::misb0601::GenericFlagData {
-            laser_range_on: (value >> 0) & 1 == 1,
-            auto_track_on: (value >> 1) & 1 == 1,
-            ir_polarity: match (value >> 2) & 1 == 1 {
-                false => crate::misb0601::IrPolarity::WhiteHot,
-                true => crate::misb0601::IrPolarity::BlackHot,
-            },
-            icing_status: match (value >> 3) & 1 == 1 {
-                false => crate::misb0601::Icing::NoIcingDetected,
-                true => crate::misb0601::Icing::IcingDetected,
-            },
-            slant_range_source: match (value >> 4) & 1 == 1 {
-                false => crate::misb0601::SlantRangeSource::Calculated,
-                true => crate::misb0601::SlantRangeSource::Measured,
-            },
-            is_image_invalid: (value >> 5) & 1 == 1,
-        })
+    /// Units: Meters per second (m/s)
+    /// 
+    /// Resolution: 1 m/s
+    pub platform_ground_speed: Option<u8>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x39, dec = ops::to_slant_range)]
+    /// (Optional) Horizontal distance from ground position of aircraft relative to nadir, and target of interest
+    /// 
+    /// Units: Meters (m)
+    /// 
+    /// Resolution: ~1.2 millimeters
+    pub ground_range: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x3a, dec = ops::to_fuel_remaining)]
+    /// (Optional) Remaining mass of fuel within the aircraft
+    /// 
+    /// Units: Kilograms (kg)
+    /// 
+    /// Resolution: ~0.15 kilograms
+    pub platform_fuel_remaining: Option<f32>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x3b)]
+    /// (Optional) Call sign of platform or operating unit
+    /// 
+    /// Units: None
+    /// 
+    /// Resolution: N/A
+    pub platform_call_sign: Option<String>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x3c, dec = WeaponLoad::decode)]
+    /// (Optional) Current weapons stored on aircraft
+    /// 
+    /// Units: None
+    /// 
+    /// Resolution: N/A
+    pub weapon_load: Option<WeaponLoad>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x3d, dec = WeaponFired::decode)]
+    /// (Optional) Indication when a particular weapon is released
+    /// 
+    /// Units: None
+    /// 
+    /// Resolution: N/A
+    pub weapon_fired: Option<WeaponFired>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x3e)]
+    /// (Optional) A laser's Pulse Repetition Frequency (PRF) code used to mark a target
+    /// 
+    /// Units: None
+    /// 
+    /// Resolution: N/A
+    pub laser_prf_code: Option<u16>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x3f, dec = SensorFovName::decode)]
+    /// (Optional) Names sensor field of view quantized steps
+    /// 
+    /// Units: None
+    /// 
+    /// Resolution: N/A
+    pub sensor_fov_name: Option<SensorFovName>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x40, dec = ops::to_platform_heading_angle)]
+    /// (Optional) Aircraft magnetic heading angle
+    /// 
+    /// Units: Degrees (°)
+    /// 
+    /// Resolution: ~5.5 millidegrees
+    pub platform_magnetic_heading: Option<f32>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x41)]
+    /// (Optional) Version number of the UAS Datalink LS document used to generate KLV metadata
+    /// 
+    /// Units: None
+    /// 
+    /// Resolution: N/A
+    pub uas_ls_version: Option<u8>,
+
+    // --------------------------------------------------
+    // tag 0x42 (66) is deprecated as of MISB ST 0601.19,
+    // and is not implemented
+    // --------------------------------------------------
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x43, dec = ops::to_lat)]
+    /// (Optional) Alternate platform latitude
+    /// 
+    /// Units: Degrees (°)
+    /// 
+    /// Resolution: ~42 nanodegrees
+    pub alternate_platform_latitude: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x44, dec = ops::to_lon)]
+    /// (Optional) Alternate platform longitude
+    /// 
+    /// Units: Degrees (°)
+    /// 
+    /// Resolution: ~84 nanodegrees
+    pub alternate_platform_longitude: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x45, dec = ops::to_alt)]
+    /// (Optional) Altitude of alternate platform as measured from Mean Sea Level (MSL)
+    /// 
+    /// Units: Meters (m)
+    /// 
+    /// Resolution: ~0.3 meters
+    pub alternate_platform_altitude: Option<f32>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x46)]
+    /// (Optional) Name of alternate platform connected to UAS
+    /// 
+    /// Units: None
+    /// 
+    /// Resolution: N/A
+    pub alternate_platform_name: Option<String>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x47, dec = ops::to_platform_heading_angle)]
+    /// (Optional) Heading angle of alternate platform connected to UAS
+    /// 
+    /// Units: Degrees (°)
+    /// 
+    /// Resolution: ~5.5 millidegrees
+    pub alternate_platform_heading: Option<f32>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x49, dyn = true, dec = crate::misb0806::ops::to_rvt)]
+    /// (Optional) MISB ST 0806 RVT Local Set metadata items
+    /// 
+    /// Embedded without the 16-byte UL, and without requiring
+    /// the [`crate::misb0806::Misb0806::checksum`]
+    /// 
+    /// Units: None
+    /// 
+    /// Resolution: N/A
+    pub rvt_local_set: Option<crate::misb0806::Misb0806>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x4a, dyn = true, dec = crate::decode_exact::<crate::misb0903::Misb0903>)]
+    /// (Optional) MISB ST 0903 VMTI Local Set metadata items
+    /// 
+    /// Embedded without the 16-byte UL, and without requiring
+    /// the [`crate::misb0903::Misb0903::checksum`]
+    /// 
+    /// Units: None
+    /// 
+    /// Resolution: N/A
+    pub vmti_local_set: Option<crate::misb0903::Misb0903>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x4b, dec = ops::to_alt)]
+    /// (Optional) Sensor ellipsoid height as measured from the reference WGS84 ellipsoid
+    /// 
+    /// Units: Meters (m)
+    /// 
+    /// Resolution: ~0.3 meters
+    pub sensor_ellipsoid_height: Option<f32>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x4c, dec = ops::to_alt)]
+    /// (Optional) Alternate platform ellipsoid height as measured from the reference WGS84 ellipsoid
+    /// 
+    /// Units: Meters (m)
+    /// 
+    /// Resolution: ~0.3 meters
+    pub alternate_platform_ellipsoid_height: Option<f32>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x4e, dec = ops::to_alt)]
+    /// (Optional) Frame center ellipsoid height as measured from the reference WGS84 ellipsoid
+    /// 
+    /// Units: Meters (m)
+    /// 
+    /// Resolution: ~0.3 meters
+    pub frame_center_hae: Option<f32>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x4f, dec = ops::to_sensor_velocity)]
+    /// (Optional) Northing velocity of the sensor or platform
+    /// 
+    /// Units: Meters per second (m/s)
+    /// 
+    /// Resolution: ~1 cm/s
+    pub sensor_north_velocity: Option<f32>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x50, dec = ops::to_sensor_velocity)]
+    /// (Optional) Easting velocity of the sensor or platform
+    /// 
+    /// Units: Meters per second (m/s)
+    /// 
+    /// Resolution: ~1 cm/s
+    pub sensor_east_velocity: Option<f32>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x51, dyn = true, dec = ImageHorizon::decode)]
+    /// (Optional) Location of the earth-sky horizon in the imagery
+    /// 
+    /// See [`Misb0601::horizon_line`]
+    /// 
+    /// Units: Percent (%) of the image width / height
+    /// 
+    /// Resolution: 1%
+    pub image_horizon: Option<ImageHorizon>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x52, dec = ops::to_lat)]
+    /// (Optional) Frame latitude for upper left corner, full range
+    /// 
+    /// Units: Degrees (°)
+    /// 
+    /// Resolution: ~42 nanodegrees
+    pub corner_latitude_p1_full: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x53, dec = ops::to_lon)]
+    /// (Optional) Frame longitude for upper left corner, full range
+    /// 
+    /// Units: Degrees (°)
+    /// 
+    /// Resolution: ~84 nanodegrees
+    pub corner_longitude_p1_full: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x54, dec = ops::to_lat)]
+    /// (Optional) Frame latitude for upper right corner, full range
+    /// 
+    /// Units: Degrees (°)
+    /// 
+    /// Resolution: ~42 nanodegrees
+    pub corner_latitude_p2_full: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x55, dec = ops::to_lon)]
+    /// (Optional) Frame longitude for upper right corner, full range
+    /// 
+    /// Units: Degrees (°)
+    /// 
+    /// Resolution: ~84 nanodegrees
+    pub corner_longitude_p2_full: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x56, dec = ops::to_lat)]
+    /// (Optional) Frame latitude for lower right corner, full range
+    /// 
+    /// Units: Degrees (°)
+    /// 
+    /// Resolution: ~42 nanodegrees
+    pub corner_latitude_p3_full: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x57, dec = ops::to_lon)]
+    /// (Optional) Frame longitude for lower right corner, full range
+    /// 
+    /// Units: Degrees (°)
+    /// 
+    /// Resolution: ~84 nanodegrees
+    pub corner_longitude_p3_full: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x58, dec = ops::to_lat)]
+    /// (Optional) Frame latitude for lower left corner, full range
+    /// 
+    /// Units: Degrees (°)
+    /// 
+    /// Resolution: ~42 nanodegrees
+    pub corner_latitude_p4_full: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x59, dec = ops::to_lon)]
+    /// (Optional) Frame longitude for lower left corner, full range
+    /// 
+    /// Units: Degrees (°)
+    /// 
+    /// Resolution: ~84 nanodegrees
+    pub corner_longitude_p4_full: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x5a, dec = ops::to_platform_angle_full)]
+    /// (Optional) Aircraft pitch angle, full range
+    /// 
+    /// Units: Degrees (°)
+    /// 
+    /// Resolution: ~42 nanodegrees
+    pub platform_pitch_angle_full: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x5b, dec = ops::to_platform_angle_full)]
+    /// (Optional) Platform roll angle, full range
+    /// 
+    /// Units: Degrees (°)
+    /// 
+    /// Resolution: ~42 nanodegrees
+    pub platform_roll_angle_full: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x5c, dec = ops::to_platform_angle_full)]
+    /// (Optional) Platform attack angle, full range
+    /// 
+    /// Units: Degrees (°)
+    /// 
+    /// Resolution: ~42 nanodegrees
+    pub platform_angle_of_attack_full: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x5d, dec = ops::to_platform_sideslip_angle_full)]
+    /// (Optional) Angle between the platform longitudinal axis and relative wind, full range
+    /// 
+    /// Units: Degrees (°)
+    /// 
+    /// Resolution: ~84 nanodegrees
+    pub platform_sideslip_angle_full: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x5e, dyn = true, dec = crate::decode_exact::<crate::misb1204::Misb1204Miis>)]
+    /// (Optional) MISB ST 1204 Motion Imagery Identification System (MIIS)
+    /// Core Identifier of the Motion Imagery
+    /// 
+    /// Units: None
+    /// 
+    /// Resolution: N/A
+    pub miis_core_identifier: Option<crate::misb1204::Misb1204Miis>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x5f, dyn = true, dec = crate::decode_exact::<crate::misb1206::Misb1206>)]
+    /// (Optional) MISB ST 1206 SAR Motion Imagery Local Set metadata items
+    /// 
+    /// Units: None
+    /// 
+    /// Resolution: N/A
+    pub sar_motion_imagery_local_set: Option<crate::misb1206::Misb1206>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x61, dyn = true, dec = crate::misb1002::ops::to_range_image)]
+    /// (Optional) MISB ST 1002 Range Image Local Set metadata items
+    /// 
+    /// Units: None
+    /// 
+    /// Resolution: N/A
+    pub range_image_local_set: Option<crate::misb1002::Misb1002>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x62, dyn = true, dec = crate::decode_exact::<crate::misb1601::Misb1601>)]
+    /// (Optional) MISB ST 1601 Geo-Registration Local Set metadata items
+    /// 
+    /// Units: None
+    /// 
+    /// Resolution: N/A
+    pub geo_registration_local_set: Option<crate::misb1601::Misb1601>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x63, dyn = true, dec = crate::decode_exact::<crate::misb1602::Misb1602>)]
+    /// (Optional) MISB ST 1602 Composite Imaging Local Set metadata items
+    /// 
+    /// Describes a single sub-image of a composite image, see [`crate::misb1602::find_sub_image`]
+    /// 
+    /// Units: None
+    /// 
+    /// Resolution: N/A
+    pub composite_imaging_local_set: Option<crate::misb1602::Misb1602>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x64, dyn = true, dec = crate::misb1607::ops::to_segment)]
+    /// (Optional) MISB ST 1607 Segment Local Sets, one per segment (e.g., sensor)
+    /// of the packet
+    /// 
+    /// The Segment LS may be repeated, so every segment is collected after
+    /// decoding, see [`crate::misb1607::ops::to_segments`]. To view each
+    /// segment as a [`Misb0601`] inheriting this packet's items, see
+    /// [`Misb0601::segments`].
+    /// 
+    /// Is "pseudo optional"; if not present, defaults to an empty vector.
+    /// 
+    /// Units: None
+    /// 
+    /// Resolution: N/A
+    pub segment_local_sets: Vec<crate::misb1607::Misb1607>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x65, dyn = true, dec = crate::misb1607::ops::to_amend)]
+    /// (Optional) MISB ST 1607 Amend Local Set, overriding items of this packet
+    /// 
+    /// To apply the amendments, see [`Misb0601::amended`]
+    /// 
+    /// Units: None
+    /// 
+    /// Resolution: N/A
+    pub amend_local_set: Option<crate::misb1607::Misb1607>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x66, dyn = true, dec = crate::misb1010::KeyedSdcc::decode)]
+    /// (Optional) MISB ST 1010 Standard Deviation and Correlation Coefficient
+    /// Floating Length Pack (SDCC-FLP), mapped to the keys of the items it
+    /// describes
+    /// 
+    /// See [`Misb0601::sensor_position_covariance`]
+    /// 
+    /// Units: None
+    /// 
+    /// Resolution: N/A
+    pub sdcc_flp: Option<crate::misb1010::KeyedSdcc>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x67, dyn = true, dec = ops::to_alt_extended)]
+    /// (Optional) Density altitude above mean sea level at aircraft location, extended range
+    /// 
+    /// Uses IMAP with range [-900, 40000] meters. Any length is
+    /// accepted when decoding, and 3 bytes are used when encoding
+    /// 
+    /// Units: Meters (m)
+    /// 
+    /// Resolution: Length dependent
+    pub density_altitude_extended: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x68, dyn = true, dec = ops::to_alt_extended)]
+    /// (Optional) Sensor ellipsoid height, extended range
+    /// 
+    /// Uses IMAP with range [-900, 40000] meters. Any length is
+    /// accepted when decoding, and 3 bytes are used when encoding
+    /// 
+    /// Units: Meters (m)
+    /// 
+    /// Resolution: Length dependent
+    pub sensor_ellipsoid_height_extended: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x69, dyn = true, dec = ops::to_alt_extended)]
+    /// (Optional) Alternate platform ellipsoid height, extended range
+    /// 
+    /// Uses IMAP with range [-900, 40000] meters. Any length is
+    /// accepted when decoding, and 3 bytes are used when encoding
+    /// 
+    /// Units: Meters (m)
+    /// 
+    /// Resolution: Length dependent
+    pub alternate_platform_ellipsoid_height_extended: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x6a)]
+    /// (Optional) A second designation given to a sortie
+    /// 
+    /// Units: None
+    /// 
+    /// Resolution: N/A
+    pub stream_designator: Option<String>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x6b)]
+    /// (Optional) Indicates the geographic location of the operational base
+    /// 
+    /// Units: None
+    /// 
+    /// Resolution: N/A
+    pub operational_base: Option<String>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x6c)]
+    /// (Optional) Name of the source, where the Motion Imagery is first broadcast
+    /// 
+    /// Units: None
+    /// 
+    /// Resolution: N/A
+    pub broadcast_source: Option<String>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x6d, dyn = true, dec = ops::to_range_to_recovery)]
+    /// (Optional) Distance from current position to airframe recovery position
+    /// 
+    /// Uses IMAP with range [0, 21000] kilometers. Any length is
+    /// accepted when decoding, and 3 bytes are used when encoding
+    /// 
+    /// Units: Kilometers (km)
+    /// 
+    /// Resolution: Length dependent
+    pub range_to_recovery_location: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x6e, dyn = true, dec = tinyklv::codecs::binary::dec::be_u32_lengthed)]
+    /// (Optional) Number of seconds of aircraft airborne
+    /// 
+    /// Units: Seconds (s)
+    /// 
+    /// Resolution: 1 second
+    pub time_airborne: Option<u32>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x6f, dyn = true, dec = tinyklv::codecs::binary::dec::be_u32_lengthed)]
+    /// (Optional) The speed the engine (or electric motor) is rotating at
+    /// 
+    /// Units: Revolutions per minute (RPM)
+    /// 
+    /// Resolution: 1 RPM
+    pub propulsion_unit_speed: Option<u32>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x70, dyn = true, dec = ops::to_course_angle)]
+    /// (Optional) Direction the aircraft is moving relative to True North
+    /// 
+    /// Uses IMAP with range [0, 360] degrees. Any length is
+    /// accepted when decoding, and 3 bytes are used when encoding
+    /// 
+    /// Units: Degrees (°)
+    /// 
+    /// Resolution: Length dependent
+    pub platform_course_angle: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x71, dyn = true, dec = ops::to_alt_extended)]
+    /// (Optional) Above Ground Level (AGL) height above the ground/water
+    /// 
+    /// Uses IMAP with range [-900, 40000] meters. Any length is
+    /// accepted when decoding, and 3 bytes are used when encoding
+    /// 
+    /// Units: Meters (m)
+    /// 
+    /// Resolution: Length dependent
+    pub altitude_agl: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x72, dyn = true, dec = ops::to_alt_extended)]
+    /// (Optional) Height above the ground/water as reported by a RADAR altimeter
+    /// 
+    /// Uses IMAP with range [-900, 40000] meters. Any length is
+    /// accepted when decoding, and 3 bytes are used when encoding
+    /// 
+    /// Units: Meters (m)
+    /// 
+    /// Resolution: Length dependent
+    pub radar_altimeter: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x73, dyn = true, dec = ControlCommand::decode)]
+    /// (Optional) Record of command from GCS to aircraft
+    /// 
+    /// Units: None
+    /// 
+    /// Resolution: N/A
+    pub control_command: Option<ControlCommand>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x74, dyn = true, dec = ops::to_ber_oid_list)]
+    /// (Optional) Acknowledgement of one or more control commands,
+    /// identified by [`ControlCommand::id`], received by the platform
+    /// 
+    /// Units: None
+    /// 
+    /// Resolution: N/A
+    pub control_command_verification_list: Option<Vec<u64>>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x75, dyn = true, dec = ops::to_sensor_rate)]
+    /// (Optional) The rate the sensor azimuth angle is changing
+    /// 
+    /// Uses IMAP with range [-1000, 1000] degrees per second. Any length
+    /// is accepted when decoding, and 3 bytes are used when encoding
+    /// 
+    /// Units: Degrees per second (°/s)
+    /// 
+    /// Resolution: Length dependent
+    pub sensor_azimuth_rate: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x76, dyn = true, dec = ops::to_sensor_rate)]
+    /// (Optional) The rate the sensor elevation angle is changing
+    /// 
+    /// Uses IMAP with range [-1000, 1000] degrees per second. Any length
+    /// is accepted when decoding, and 3 bytes are used when encoding
+    /// 
+    /// Units: Degrees per second (°/s)
+    /// 
+    /// Resolution: Length dependent
+    pub sensor_elevation_rate: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x77, dyn = true, dec = ops::to_sensor_rate)]
+    /// (Optional) The rate the sensor roll angle is changing
+    /// 
+    /// Uses IMAP with range [-1000, 1000] degrees per second. Any length
+    /// is accepted when decoding, and 3 bytes are used when encoding
+    /// 
+    /// Units: Degrees per second (°/s)
+    /// 
+    /// Resolution: Length dependent
+    pub sensor_roll_rate: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x78, dyn = true, dec = ops::to_percentage)]
+    /// (Optional) Amount of Motion Imagery storage used, as a percentage of the total storage
+    /// 
+    /// Uses IMAP with range [0, 100] percent. Any length is
+    /// accepted when decoding, and 2 bytes are used when encoding
+    /// 
+    /// Units: Percent (%)
+    /// 
+    /// Resolution: Length dependent
+    pub onboard_storage_percent_full: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x79, dyn = true, dec = ops::to_ber_oid_list)]
+    /// (Optional) List of wavelengths, by [`Wavelength::id`], in
+    /// the currently active motion imagery
+    /// 
+    /// Units: None
+    /// 
+    /// Resolution: N/A
+    pub active_wavelength_list: Option<Vec<u64>>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x7a, dyn = true, dec = CountryCodes::decode)]
+    /// (Optional) Country codes which are associated with the platform and its operation
+    /// 
+    /// Units: None
+    /// 
+    /// Resolution: N/A
+    pub country_codes: Option<CountryCodes>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x7b)]
+    /// (Optional) Count of navigation satellites in view of platform
+    /// 
+    /// Units: Count
+    /// 
+    /// Resolution: 1
+    pub number_of_navsats_in_view: Option<u8>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x7c, dec = PositioningMethodSource::decode)]
+    /// (Optional) Source of the navigation positioning information
+    /// 
+    /// Units: None
+    /// 
+    /// Resolution: N/A
+    pub positioning_method_source: Option<PositioningMethodSource>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x7d, dec = PlatformStatus::decode)]
+    /// (Optional) Enumeration of operational modes of the platform
+    /// 
+    /// Units: None
+    /// 
+    /// Resolution: N/A
+    pub platform_status: Option<PlatformStatus>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x7e, dec = SensorControlMode::decode)]
+    /// (Optional) Value indicating the sensor control operational status
+    /// 
+    /// Units: None
+    /// 
+    /// Resolution: N/A
+    pub sensor_control_mode: Option<SensorControlMode>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x7f, dyn = true, dec = SensorFrameRate::decode)]
+    /// (Optional) Values used to compute the frame rate of the Motion Imagery at the sensor
+    /// 
+    /// Units: Frames per second (Hz)
+    /// 
+    /// Resolution: N/A
+    pub sensor_frame_rate: Option<SensorFrameRate>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x80, dyn = true, dec = Wavelength::decode_series)]
+    /// (Optional) List of wavelength ranges, in addition to the default wavelengths
+    /// 
+    /// Is "pseudo optional"; if not present, defaults to an empty vector.
+    /// 
+    /// Units: None
+    /// 
+    /// Resolution: N/A
+    pub wavelengths_list: Vec<Wavelength>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x83, dyn = true, dec = ops::to_timestamp_lengthed)]
+    /// (Optional) Time when aircraft became airborne
+    /// 
+    /// Units: Microseconds (μs)
+    /// 
+    /// Resolution: 1 μs
+    pub take_off_time: Option<chrono::DateTime<chrono::Utc>>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x85, dyn = true, dec = tinyklv::codecs::binary::dec::be_u32_lengthed)]
+    /// (Optional) Total capacity of on-board Motion Imagery storage
+    /// 
+    /// Units: Gigabytes (GB)
+    /// 
+    /// Resolution: 1 GB
+    pub onboard_storage_capacity: Option<u32>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x86, dyn = true, dec = ops::to_percentage)]
+    /// (Optional) For a variable zoom system, the percentage of zoom
+    /// 
+    /// Uses IMAP with range [0, 100] percent. Any length is
+    /// accepted when decoding, and 2 bytes are used when encoding
+    /// 
+    /// Units: Percent (%)
+    /// 
+    /// Resolution: Length dependent
+    pub zoom_percentage: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x87)]
+    /// (Optional) Type of communications used with platform
+    /// 
+    /// Units: None
+    /// 
+    /// Resolution: N/A
+    pub communications_method: Option<String>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x88, dyn = true, dec = ops::to_leap_seconds)]
+    /// (Optional) Number of leap seconds to adjust MISP time (i.e., GPS time) to UTC
+    /// 
+    /// If not present, [`ops::DEFAULT_LEAP_SECONDS`] is assumed. See [`Misb0601::utc_time`]
+    /// 
+    /// Units: Seconds (s)
+    /// 
+    /// Resolution: 1 second
+    pub leap_seconds: Option<i32>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x89, dyn = true, dec = ops::to_i64_lengthed)]
+    /// (Optional) Post-flight time adjustment to correct the [`Misb0601::precision_timestamp`]
+    /// 
+    /// See [`Misb0601::corrected_precision_timestamp`]
+    /// 
+    /// Units: Microseconds (μs)
+    /// 
+    /// Resolution: 1 μs
+    pub correction_offset: Option<i64>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x8a, dyn = true, dec = Payload::decode_list)]
+    /// (Optional) List of payloads available on the platform
+    /// 
+    /// Is "pseudo optional"; if not present, defaults to an empty vector.
+    /// 
+    /// Units: None
+    /// 
+    /// Resolution: N/A
+    pub payload_list: Vec<Payload>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x8b, dyn = true, dec = ops::to_active_payloads)]
+    /// (Optional) List of currently active payloads, by [`Payload::id`]
+    /// 
+    /// Encoded as a bitmap, where each bit corresponds to a payload ID, up
+    /// to [`ops::ACTIVE_PAYLOAD_ID_MAX`]
+    /// 
+    /// Units: None
+    /// 
+    /// Resolution: N/A
+    pub active_payloads: Option<Vec<u64>>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x8c, dyn = true, dec = WeaponsStore::decode_series)]
+    /// (Optional) List of weapon stores and their status
+    /// 
+    /// Is "pseudo optional"; if not present, defaults to an empty vector.
+    /// 
+    /// Units: None
+    /// 
+    /// Resolution: N/A
+    pub weapons_stores: Vec<WeaponsStore>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x8d, dyn = true, dec = Waypoint::decode_series)]
+    /// (Optional) List of waypoints and their status
+    /// 
+    /// Is "pseudo optional"; if not present, defaults to an empty vector.
+    /// 
+    /// Units: None
+    /// 
+    /// Resolution: N/A
+    pub waypoint_list: Vec<Waypoint>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x8e, dyn = true, dec = ViewDomain::decode)]
+    /// (Optional) Range of view angles of the sensor, relative to the platform
+    /// 
+    /// Units: Degrees (°)
+    /// 
+    /// Resolution: Length dependent
+    pub view_domain: Option<ViewDomain>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    #[klv(key = 0x8f, dyn = true, dec = MetadataSubstreamId::decode)]
+    /// (Optional) Identifies the metadata substream this packet belongs to
+    /// 
+    /// See [`Misb0601::demux`]
+    /// 
+    /// Units: None
+    /// 
+    /// Resolution: N/A
+    pub metadata_substream_id: Option<MetadataSubstreamId>,
+
+    #[cfg(any(
+        feature = "misb0601-19",
+    ))]
+    /// Key and value of every item of the decoded packet, in stream order,
+    /// including any item which is not implemented
+    /// 
+    /// Kept so that a [`crate::misb1607::Misb1607`] is overlaid onto the
+    /// packet as it was received, see [`crate::misb1607::Misb1607::apply_to`].
+    /// 
+    /// Is empty for packets which were not decoded, and is ignored when
+    /// encoding.
+    pub raw_items: Vec<(u64, Vec<u8>)>,
+}
+#[cfg(feature = "misb0601-19")]
+/// [`Misb0601`] implementation
+impl Misb0601 {
+    /// Seeks to and decodes a single UAS Datalink LS packet, verifying
+    /// the [`Misb0601::checksum`] over the UL, length, and value bytes
+    /// 
+    /// Shadows the derived [`tinyklv`] extractor, so that
+    /// `Misb0601::extract` is checked by default. Any bytes skipped while
+    /// seeking to the UL are not part of the checksum. On mismatch, a [`winnow::error::ErrMode::Cut`] is returned
+    /// whose cause is [`Misb0601Error::ChecksumMismatch`], so that a
+    /// corrupted packet is not silently skipped by a repeated parser.
+    /// 
+    /// To skip verification, see [`Misb0601::extract_lenient`].
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use misb::misb0601::{Misb0601, Misb0601Error};
+    /// let mut packet: &[u8] = &[
+    ///     0x06, 0x0E, 0x2B, 0x34, 0x02, 0x0B, 0x01, 0x01, 0x0E, 0x01, 0x03, 0x01, 0x01, 0x00, 0x00, 0x00,
+    ///     0x0E, 0x02, 0x08, 0x00, 0x04, 0x59, 0xF4, 0xA6, 0xAA, 0x4A, 0xA8, 0x01, 0x02, 0xFF, 0xFF,
+    /// ];
+    /// let err = match Misb0601::extract(&mut packet) {
+    ///     Err(winnow::error::ErrMode::Cut(e)) => e,
+    ///     _ => panic!("expected a checksum mismatch"),
+    /// };
+    /// assert!(matches!(
+    ///     err.cause().and_then(|c| c.downcast_ref::<Misb0601Error>()),
+    ///     Some(Misb0601Error::ChecksumMismatch { actual: 0xFFFF, .. }),
+    /// ));
+    /// ```
+    pub fn extract(input: &mut &[u8]) -> winnow::PResult<Self> {
+        Self::skip_to_ul(input)?;
+        let start = *input;
+        let mut value = <Self as tinyklv::prelude::Seek<&[u8]>>::seek(input)?;
+        Self::verify_checksum(&start[..start.len() - input.len()]).map_err(|e| Self::checksum_error(&*input, e))?;
+        Self::decode_value(&mut value)
+    }
+
+    /// Seeks to and decodes a single UAS Datalink LS packet, without
+    /// verifying the [`Misb0601::checksum`]
+    /// 
+    /// See [`Misb0601::extract`]
+    pub fn extract_lenient(input: &mut &[u8]) -> winnow::PResult<Self> {
+        Self::skip_to_ul(input)?;
+        let mut value = <Self as tinyklv::prelude::Seek<&[u8]>>::seek(input)?;
+        Self::decode_value(&mut value)
+    }
+
+    /// Skips every byte before the next [`UAS_DATALINK_LS_UL`]
+    fn skip_to_ul(input: &mut &[u8]) -> winnow::PResult<()> {
+        winnow::token::take_until(0.., &UAS_DATALINK_LS_UL[..]).void().parse_next(input)
+    }
+
+    /// Extracts every UAS Datalink LS packet from `input`, and splits them
+    /// by their [`Misb0601::metadata_substream_id`]
+    /// 
+    /// Packets without a metadata substream ID are grouped under [`None`].
+    /// Within each substream, packets remain in stream order.
+    /// 
+    /// Each packet is verified as in [`Misb0601::extract`], meaning a
+    /// corrupted packet results in an error.
+    pub fn demux(input: &mut &[u8]) -> winnow::PResult<std::collections::BTreeMap<Option<u64>, Vec<Self>>> {
+        let packets: Vec<Self> = winnow::combinator::repeat(0.., Self::extract).parse_next(input)?;
+        let mut output = std::collections::BTreeMap::<Option<u64>, Vec<Self>>::new();
+        for packet in packets {
+            output.entry(packet.metadata_substream_id.as_ref().map(|x| x.id)).or_default().push(packet);
+        }
+        Ok(output)
+    }
+
+    /// Decodes a UAS Datalink LS packet which starts at the beginning of
+    /// `input` (i.e., UL, length, and value), verifying the [`Misb0601::checksum`]
+    /// 
+    /// Shadows the derived [`tinyklv`] decoder, so that `Misb0601::decode`
+    /// is checked by default. Since the checksum covers the UL and length,
+    /// `input` is a complete packet rather than only its value. Unlike
+    /// [`Misb0601::extract`], no bytes are skipped before the UL. The length
+    /// is read as-is, so any BER length form is accepted.
+    /// 
+    /// To decode only the value of a packet, see [`Misb0601::decode_lenient`].
+    pub fn decode(input: &mut &[u8]) -> winnow::PResult<Self> {
+        let start = *input;
+        winnow::token::literal(UAS_DATALINK_LS_UL).parse_next(input)?;
+        let len = tinyklv::codecs::ber::dec::ber_length.parse_next(input)?;
+        let mut value = winnow::token::take(len).parse_next(input)?;
+        Self::verify_checksum(&start[..start.len() - input.len()]).map_err(|e| Self::checksum_error(&*input, e))?;
+        Self::decode_value(&mut value)
+    }
+
+    /// Decodes the value of a UAS Datalink LS packet (i.e., without the UL
+    /// and length), without verifying the [`Misb0601::checksum`]
+    /// 
+    /// See [`Misb0601::decode`]
+    pub fn decode_lenient(input: &mut &[u8]) -> winnow::PResult<Self> {
+        Self::decode_value(input)
+    }
+
+    /// Decodes a UAS Datalink LS packet from the key and value of each of
+    /// its items, in stream order
+    /// 
+    /// Any checksum item is ignored, and the [`Misb0601::checksum`] is
+    /// computed over the resulting packet instead. This allows decoding
+    /// items which were never part of a sent packet, e.g. those of a
+    /// [`crate::misb1607::Misb1607`] overlay.
+    pub fn decode_items(items: &[(u64, Vec<u8>)]) -> winnow::PResult<Self> {
+        let mut value = Vec::new();
+        for (key, x) in items.iter().filter(|(key, _)| *key != crate::checksum::CHECKSUM_KEY as u64) {
+            crate::encode_item(&mut value, *key, x);
+        }
+        Self::extract_lenient(&mut Self::packet_from_value(value).as_slice())
+    }
+
+    /// Decodes the value of a UAS Datalink LS packet using the derived
+    /// [`tinyklv`] decoder, then collects every [`Misb0601::segment_local_sets`],
+    /// since the Segment LS may be repeated within a single packet, and
+    /// every [`Misb0601::raw_items`]
+    fn decode_value(input: &mut &[u8]) -> winnow::PResult<Self> {
+        let start = *input;
+        let mut output = <Self as tinyklv::prelude::Decode<&[u8]>>::decode(input)?;
+        let consumed = &start[..start.len() - input.len()];
+        output.segment_local_sets = crate::misb1607::ops::to_segments(consumed)?;
+        output.raw_items = crate::misb1607::Misb1607::decode(&mut &consumed[..])?.items;
+        Ok(output)
+    }
+
+    /// Returns this packet with the [`Misb0601::amend_local_set`] applied,
+    /// or a copy of this packet if there is none
+    /// 
+    /// The returned packet contains no Segment LS or Amend LS, see
+    /// [`Misb0601::segments`] for the segments.
+    /// 
+    /// See [`crate::misb1607::Misb1607::apply_to`]
+    pub fn amended(&self) -> winnow::PResult<Self> {
+        self.amend_local_set.clone().unwrap_or_default().apply_to(self)
+    }
+
+    /// Returns one [`Misb0601`] per [`Misb0601::segment_local_sets`], each
+    /// inheriting the items of the [`Misb0601::amended`] packet that the
+    /// segment does not contain
+    /// 
+    /// Returns an empty vector if the packet has no segments.
+    /// 
+    /// See [`crate::misb1607::Misb1607::apply_to`]
+    pub fn segments(&self) -> winnow::PResult<Vec<Self>> {
+        if self.segment_local_sets.is_empty() { return Ok(Vec::new()) }
+        let parent = self.amended()?;
+        self.segment_local_sets.iter().map(|x| x.apply_to(&parent)).collect()
+    }
+
+    /// Verifies the checksum of a complete UAS Datalink LS packet, from
+    /// the first byte of the UL to the last byte of the checksum value
+    /// 
+    /// The checksum must be the final item in the packet.
+    /// 
+    /// See [`crate::checksum::verify`]
+    pub fn verify_checksum(packet: &[u8]) -> Result<(), Misb0601Error> {
+        Ok(crate::checksum::verify(packet)?)
+    }
+
+    /// Converts a [`Misb0601Error`] into an unrecoverable parser error,
+    /// keeping the [`Misb0601Error`] as the cause
+    fn checksum_error(input: &&[u8], e: Misb0601Error) -> winnow::error::ErrMode<winnow::error::ContextError> {
+        winnow::error::ErrMode::Cut(<winnow::error::ContextError as winnow::error::FromExternalError<&[u8], Misb0601Error>>::from_external_error(
+            input,
+            winnow::error::ErrorKind::Verify,
+            e,
+        ))
+    }
+
+    /// The [`Misb0601::precision_timestamp`] adjusted by the
+    /// [`Misb0601::correction_offset`], if present
+    /// 
+    /// This is still in MISP time, which counts leap seconds (i.e., the
+    /// GPS time scale, with an epoch of 1970). See [`Misb0601::utc_time`]
+    /// and [`Misb0601::tai_time`] to convert to other time scales.
+    pub fn corrected_precision_timestamp(&self) -> chrono::DateTime<chrono::Utc> {
+        self.precision_timestamp + chrono::Duration::microseconds(self.correction_offset.unwrap_or(0))
+    }
+
+    /// The [`Misb0601::corrected_precision_timestamp`] converted to UTC, by
+    /// subtracting the [`Misb0601::leap_seconds`]
+    /// 
+    /// If no leap seconds are present, [`ops::DEFAULT_LEAP_SECONDS`] is used.
+    pub fn utc_time(&self) -> chrono::DateTime<chrono::Utc> {
+        let leap_seconds = self.leap_seconds.unwrap_or(ops::DEFAULT_LEAP_SECONDS);
+        self.corrected_precision_timestamp() - chrono::Duration::seconds(leap_seconds as i64)
+    }
+
+    /// The [`Misb0601::corrected_precision_timestamp`] on the GPS time scale,
+    /// with an epoch of 1970
+    /// 
+    /// MISP time is defined on the GPS time scale, so this is identical to
+    /// [`Misb0601::corrected_precision_timestamp`]
+    pub fn gps_time(&self) -> chrono::DateTime<chrono::Utc> {
+        self.corrected_precision_timestamp()
+    }
+
+    /// The [`Misb0601::corrected_precision_timestamp`] on the TAI time scale,
+    /// with an epoch of 1970
+    /// 
+    /// TAI is always [`ops::TAI_MINUS_GPS_SECONDS`] ahead of GPS time.
+    pub fn tai_time(&self) -> chrono::DateTime<chrono::Utc> {
+        self.corrected_precision_timestamp() + chrono::Duration::seconds(ops::TAI_MINUS_GPS_SECONDS)
+    }
+
+    /// The covariance matrix of the [`Misb0601::sensor_latitude`],
+    /// [`Misb0601::sensor_longitude`], and [`Misb0601::sensor_true_altitude`],
+    /// in that order, from the [`Misb0601::sdcc_flp`]
+    /// 
+    /// Returns [`None`] if any of the three is not described.
+    pub fn sensor_position_covariance(&self) -> Option<Vec<Vec<f64>>> {
+        self.sdcc_flp.as_ref()?.submatrix(&[0x0d, 0x0e, 0x0f])
+    }
+
+    /// The [`Misb0601::image_horizon`] line in pixel coordinates, as the
+    /// start and end points (column, row), for an image of `width` by
+    /// `height` pixels
+    /// 
+    /// See [`ImageHorizon::line`]
+    pub fn horizon_line(&self, width: u32, height: u32) -> Option<((f64, f64), (f64, f64))> {
+        self.image_horizon.as_ref().map(|x| x.line(width, height))
+    }
+
+    /// Encodes the [`Misb0601`] into a complete UAS Datalink LS packet
+    /// 
+    /// The packet consists of the 16-byte UL ([`UAS_DATALINK_LS_UL`]), the
+    /// BER encoded length, and every present item in tag order, starting
+    /// with [`Misb0601::precision_timestamp`]. The [`Misb0601::checksum`]
+    /// is always the final item, and is re-computed over the encoded
+    /// packet, meaning the value currently stored in the struct is ignored.
+    pub fn encode(&self) -> Vec<u8> {
+        Self::packet_from_value(self.encode_value())
+    }
+
+    /// Wraps the encoded items of a UAS Datalink LS packet, excluding the
+    /// checksum, with the UL and length, and appends the computed checksum
+    fn packet_from_value(mut value: Vec<u8>) -> Vec<u8> {
+        // --------------------------------------------------
+        // checksum key and length are included in the
+        // checksum, as well as the UL and packet length
+        // --------------------------------------------------
+        value.extend([crate::checksum::CHECKSUM_KEY, crate::checksum::CHECKSUM_LEN]);
+        let mut output = UAS_DATALINK_LS_UL.to_vec();
+        output.extend(tinyklv::codecs::ber::enc::ber_length(&(value.len() + 2)));
+        output.extend(value);
+        let checksum = crate::checksum::bcc16(&output);
+        output.extend(checksum.to_be_bytes());
+        output
+    }
+
+    /// Encodes every present item of the [`Misb0601`] in tag order,
+    /// excluding the [`Misb0601::checksum`]
+    pub(crate) fn encode_value(&self) -> Vec<u8> {
+        let mut output = Vec::new();
+        crate::encode_item(&mut output, 0x02, &ops::from_precision_timestamp(self.precision_timestamp));
+        if let Some(x) = &self.mission_id { crate::encode_item(&mut output, 0x03, x.as_bytes()) }
+        if let Some(x) = &self.platform_tail_number { crate::encode_item(&mut output, 0x04, x.as_bytes()) }
+        if let Some(x) = self.platform_heading_angle { crate::encode_item(&mut output, 0x05, &ops::from_platform_heading_angle(x)) }
+        if let Some(x) = self.platform_pitch_angle { crate::encode_item(&mut output, 0x06, &ops::from_platform_pitch_angle(x)) }
+        if let Some(x) = self.platform_roll_angle { crate::encode_item(&mut output, 0x07, &ops::from_platform_roll_angle(x)) }
+        if let Some(x) = self.platform_true_airspeed { crate::encode_item(&mut output, 0x08, &[x]) }
+        if let Some(x) = self.platform_indicated_airspeed { crate::encode_item(&mut output, 0x09, &[x]) }
+        if let Some(x) = &self.platform_designation { crate::encode_item(&mut output, 0x0a, x.as_bytes()) }
+        if let Some(x) = &self.image_source_sensor { crate::encode_item(&mut output, 0x0b, x.as_bytes()) }
+        if let Some(x) = &self.image_coordinate_system { crate::encode_item(&mut output, 0x0c, x.as_bytes()) }
+        if let Some(x) = self.sensor_latitude { crate::encode_item(&mut output, 0x0d, &ops::from_lat(x)) }
+        if let Some(x) = self.sensor_longitude { crate::encode_item(&mut output, 0x0e, &ops::from_lon(x)) }
+        if let Some(x) = self.sensor_true_altitude { crate::encode_item(&mut output, 0x0f, &ops::from_alt(x)) }
+        if let Some(x) = self.sensor_hfov { crate::encode_item(&mut output, 0x10, &ops::from_sensor_hvfov(x)) }
+        if let Some(x) = self.sensor_vfov { crate::encode_item(&mut output, 0x11, &ops::from_sensor_hvfov(x)) }
+        if let Some(x) = self.sensor_relative_azimuth_angle { crate::encode_item(&mut output, 0x12, &ops::from_sensor_relative_azimuth_angle(x)) }
+        if let Some(x) = self.sensor_relative_elevation_angle { crate::encode_item(&mut output, 0x13, &ops::from_sensor_relative_elevation_angle(x)) }
+        if let Some(x) = self.sensor_relative_roll_angle { crate::encode_item(&mut output, 0x14, &ops::from_sensor_relative_roll_angle(x)) }
+        if let Some(x) = self.slant_range { crate::encode_item(&mut output, 0x15, &ops::from_slant_range(x)) }
+        if let Some(x) = self.target_width { crate::encode_item(&mut output, 0x16, &ops::from_target_width(x)) }
+        if let Some(x) = self.frame_center_latitude { crate::encode_item(&mut output, 0x17, &ops::from_lat(x)) }
+        if let Some(x) = self.frame_center_longitude { crate::encode_item(&mut output, 0x18, &ops::from_lon(x)) }
+        if let Some(x) = self.frame_center_elevation { crate::encode_item(&mut output, 0x19, &ops::from_alt(x)) }
+        if let Some(x) = self.offset_corner_lat_p1 { crate::encode_item(&mut output, 0x1a, &ops::from_offset_ll(x)) }
+        if let Some(x) = self.offset_corner_lon_p1 { crate::encode_item(&mut output, 0x1b, &ops::from_offset_ll(x)) }
+        if let Some(x) = self.offset_corner_lat_p2 { crate::encode_item(&mut output, 0x1c, &ops::from_offset_ll(x)) }
+        if let Some(x) = self.offset_corner_lon_p2 { crate::encode_item(&mut output, 0x1d, &ops::from_offset_ll(x)) }
+        if let Some(x) = self.offset_corner_lat_p3 { crate::encode_item(&mut output, 0x1e, &ops::from_offset_ll(x)) }
+        if let Some(x) = self.offset_corner_lon_p3 { crate::encode_item(&mut output, 0x1f, &ops::from_offset_ll(x)) }
+        if let Some(x) = self.offset_corner_lat_p4 { crate::encode_item(&mut output, 0x20, &ops::from_offset_ll(x)) }
+        if let Some(x) = self.offset_corner_lon_p4 { crate::encode_item(&mut output, 0x21, &ops::from_offset_ll(x)) }
+        if let Some(x) = self.icing_detected { crate::encode_item(&mut output, 0x22, &[ops::from_icing_detected(x)]) }
+        if let Some(x) = self.wind_direction { crate::encode_item(&mut output, 0x23, &ops::from_wind_direction(x)) }
+        if let Some(x) = self.wind_speed { crate::encode_item(&mut output, 0x24, &ops::from_wind_speed(x)) }
+        if let Some(x) = self.static_pressure { crate::encode_item(&mut output, 0x25, &ops::from_mbar_pressure(x)) }
+        if let Some(x) = self.density_altitude { crate::encode_item(&mut output, 0x26, &ops::from_alt(x)) }
+        if let Some(x) = self.outside_air_temperature { crate::encode_item(&mut output, 0x27, &x.to_be_bytes()) }
+        if let Some(x) = self.target_location_latitude { crate::encode_item(&mut output, 0x28, &ops::from_lat(x)) }
+        if let Some(x) = self.target_location_longitude { crate::encode_item(&mut output, 0x29, &ops::from_lon(x)) }
+        if let Some(x) = self.target_location_elevation { crate::encode_item(&mut output, 0x2a, &ops::from_alt(x)) }
+        if let Some(x) = self.target_track_gate_width { crate::encode_item(&mut output, 0x2b, &ops::from_target_track_gate_hw(x)) }
+        if let Some(x) = self.target_track_gate_height { crate::encode_item(&mut output, 0x2c, &ops::from_target_track_gate_hw(x)) }
+        if let Some(x) = self.target_error_estimate_ce90 { crate::encode_item(&mut output, 0x2d, &ops::from_error_estimate(x)) }
+        if let Some(x) = self.target_error_estimate_le90 { crate::encode_item(&mut output, 0x2e, &ops::from_error_estimate(x)) }
+        if let Some(x) = &self.generic_flag_data { crate::encode_item(&mut output, 0x2f, &x.encode()) }
+        if let Some(x) = &self.security_local_set { crate::encode_item(&mut output, 0x30, &x.encode_value()) }
+        if let Some(x) = self.differential_pressure { crate::encode_item(&mut output, 0x31, &ops::from_mbar_pressure(x)) }
+        if let Some(x) = self.platform_angle_of_attack { crate::encode_item(&mut output, 0x32, &ops::from_platform_pitch_angle(x)) }
+        if let Some(x) = self.platform_vertical_speed { crate::encode_item(&mut output, 0x33, &ops::from_platform_vertical_speed(x)) }
+        if let Some(x) = self.platform_sideslip_angle { crate::encode_item(&mut output, 0x34, &ops::from_platform_pitch_angle(x)) }
+        if let Some(x) = self.airfield_barometric_pressure { crate::encode_item(&mut output, 0x35, &ops::from_mbar_pressure(x)) }
+        if let Some(x) = self.airfield_elevation { crate::encode_item(&mut output, 0x36, &ops::from_alt(x)) }
+        if let Some(x) = self.relative_humidity { crate::encode_item(&mut output, 0x37, &ops::from_relative_humidity(x)) }
+        if let Some(x) = self.platform_ground_speed { crate::encode_item(&mut output, 0x38, &[x]) }
+        if let Some(x) = self.ground_range { crate::encode_item(&mut output, 0x39, &ops::from_slant_range(x)) }
+        if let Some(x) = self.platform_fuel_remaining { crate::encode_item(&mut output, 0x3a, &ops::from_fuel_remaining(x)) }
+        if let Some(x) = &self.platform_call_sign { crate::encode_item(&mut output, 0x3b, x.as_bytes()) }
+        if let Some(x) = &self.weapon_load { crate::encode_item(&mut output, 0x3c, &x.encode()) }
+        if let Some(x) = &self.weapon_fired { crate::encode_item(&mut output, 0x3d, &x.encode()) }
+        if let Some(x) = self.laser_prf_code { crate::encode_item(&mut output, 0x3e, &x.to_be_bytes()) }
+        if let Some(x) = &self.sensor_fov_name { crate::encode_item(&mut output, 0x3f, &x.encode()) }
+        if let Some(x) = self.platform_magnetic_heading { crate::encode_item(&mut output, 0x40, &ops::from_platform_heading_angle(x)) }
+        if let Some(x) = self.uas_ls_version { crate::encode_item(&mut output, 0x41, &[x]) }
+        if let Some(x) = self.alternate_platform_latitude { crate::encode_item(&mut output, 0x43, &ops::from_lat(x)) }
+        if let Some(x) = self.alternate_platform_longitude { crate::encode_item(&mut output, 0x44, &ops::from_lon(x)) }
+        if let Some(x) = self.alternate_platform_altitude { crate::encode_item(&mut output, 0x45, &ops::from_alt(x)) }
+        if let Some(x) = &self.alternate_platform_name { crate::encode_item(&mut output, 0x46, x.as_bytes()) }
+        if let Some(x) = self.alternate_platform_heading { crate::encode_item(&mut output, 0x47, &ops::from_platform_heading_angle(x)) }
+        if let Some(x) = &self.rvt_local_set { crate::encode_item(&mut output, 0x49, &x.encode_value()) }
+        if let Some(x) = &self.vmti_local_set { crate::encode_item(&mut output, 0x4a, &x.encode_value()) }
+        if let Some(x) = self.sensor_ellipsoid_height { crate::encode_item(&mut output, 0x4b, &ops::from_alt(x)) }
+        if let Some(x) = self.alternate_platform_ellipsoid_height { crate::encode_item(&mut output, 0x4c, &ops::from_alt(x)) }
+        if let Some(x) = self.frame_center_hae { crate::encode_item(&mut output, 0x4e, &ops::from_alt(x)) }
+        if let Some(x) = self.sensor_north_velocity { crate::encode_item(&mut output, 0x4f, &ops::from_sensor_velocity(x)) }
+        if let Some(x) = self.sensor_east_velocity { crate::encode_item(&mut output, 0x50, &ops::from_sensor_velocity(x)) }
+        if let Some(x) = &self.image_horizon { crate::encode_item(&mut output, 0x51, &x.encode()) }
+        if let Some(x) = self.corner_latitude_p1_full { crate::encode_item(&mut output, 0x52, &ops::from_lat(x)) }
+        if let Some(x) = self.corner_longitude_p1_full { crate::encode_item(&mut output, 0x53, &ops::from_lon(x)) }
+        if let Some(x) = self.corner_latitude_p2_full { crate::encode_item(&mut output, 0x54, &ops::from_lat(x)) }
+        if let Some(x) = self.corner_longitude_p2_full { crate::encode_item(&mut output, 0x55, &ops::from_lon(x)) }
+        if let Some(x) = self.corner_latitude_p3_full { crate::encode_item(&mut output, 0x56, &ops::from_lat(x)) }
+        if let Some(x) = self.corner_longitude_p3_full { crate::encode_item(&mut output, 0x57, &ops::from_lon(x)) }
+        if let Some(x) = self.corner_latitude_p4_full { crate::encode_item(&mut output, 0x58, &ops::from_lat(x)) }
+        if let Some(x) = self.corner_longitude_p4_full { crate::encode_item(&mut output, 0x59, &ops::from_lon(x)) }
+        if let Some(x) = self.platform_pitch_angle_full { crate::encode_item(&mut output, 0x5a, &ops::from_platform_angle_full(x)) }
+        if let Some(x) = self.platform_roll_angle_full { crate::encode_item(&mut output, 0x5b, &ops::from_platform_angle_full(x)) }
+        if let Some(x) = self.platform_angle_of_attack_full { crate::encode_item(&mut output, 0x5c, &ops::from_platform_angle_full(x)) }
+        if let Some(x) = self.platform_sideslip_angle_full { crate::encode_item(&mut output, 0x5d, &ops::from_platform_sideslip_angle_full(x)) }
+        if let Some(x) = &self.miis_core_identifier { crate::encode_item(&mut output, 0x5e, &x.encode()) }
+        if let Some(x) = &self.sar_motion_imagery_local_set { crate::encode_item(&mut output, 0x5f, &x.encode_value()) }
+        if let Some(x) = &self.range_image_local_set { crate::encode_item(&mut output, 0x61, &x.encode_value()) }
+        if let Some(x) = &self.geo_registration_local_set { crate::encode_item(&mut output, 0x62, &x.encode_value()) }
+        if let Some(x) = &self.composite_imaging_local_set { crate::encode_item(&mut output, 0x63, &x.encode_value()) }
+        for x in &self.segment_local_sets { crate::encode_item(&mut output, 0x64, &x.encode_value()) }
+        if let Some(x) = &self.amend_local_set { crate::encode_item(&mut output, 0x65, &x.encode_value()) }
+        if let Some(x) = &self.sdcc_flp { crate::encode_item(&mut output, 0x66, &x.encode_value()) }
+        if let Some(x) = self.density_altitude_extended { crate::encode_item(&mut output, 0x67, &ops::from_alt_extended(x)) }
+        if let Some(x) = self.sensor_ellipsoid_height_extended { crate::encode_item(&mut output, 0x68, &ops::from_alt_extended(x)) }
+        if let Some(x) = self.alternate_platform_ellipsoid_height_extended { crate::encode_item(&mut output, 0x69, &ops::from_alt_extended(x)) }
+        if let Some(x) = &self.stream_designator { crate::encode_item(&mut output, 0x6a, x.as_bytes()) }
+        if let Some(x) = &self.operational_base { crate::encode_item(&mut output, 0x6b, x.as_bytes()) }
+        if let Some(x) = &self.broadcast_source { crate::encode_item(&mut output, 0x6c, x.as_bytes()) }
+        if let Some(x) = self.range_to_recovery_location { crate::encode_item(&mut output, 0x6d, &ops::from_range_to_recovery(x)) }
+        if let Some(x) = self.time_airborne { crate::encode_item(&mut output, 0x6e, &x.to_be_bytes()) }
+        if let Some(x) = self.propulsion_unit_speed { crate::encode_item(&mut output, 0x6f, &x.to_be_bytes()) }
+        if let Some(x) = self.platform_course_angle { crate::encode_item(&mut output, 0x70, &ops::from_course_angle(x)) }
+        if let Some(x) = self.altitude_agl { crate::encode_item(&mut output, 0x71, &ops::from_alt_extended(x)) }
+        if let Some(x) = self.radar_altimeter { crate::encode_item(&mut output, 0x72, &ops::from_alt_extended(x)) }
+        if let Some(x) = &self.control_command { crate::encode_item(&mut output, 0x73, &x.encode()) }
+        if let Some(x) = &self.control_command_verification_list { crate::encode_item(&mut output, 0x74, &ops::from_ber_oid_list(x)) }
+        if let Some(x) = self.sensor_azimuth_rate { crate::encode_item(&mut output, 0x75, &ops::from_sensor_rate(x)) }
+        if let Some(x) = self.sensor_elevation_rate { crate::encode_item(&mut output, 0x76, &ops::from_sensor_rate(x)) }
+        if let Some(x) = self.sensor_roll_rate { crate::encode_item(&mut output, 0x77, &ops::from_sensor_rate(x)) }
+        if let Some(x) = self.onboard_storage_percent_full { crate::encode_item(&mut output, 0x78, &ops::from_percentage(x)) }
+        if let Some(x) = &self.active_wavelength_list { crate::encode_item(&mut output, 0x79, &ops::from_ber_oid_list(x)) }
+        if let Some(x) = &self.country_codes { crate::encode_item(&mut output, 0x7a, &x.encode()) }
+        if let Some(x) = self.number_of_navsats_in_view { crate::encode_item(&mut output, 0x7b, &[x]) }
+        if let Some(x) = &self.positioning_method_source { crate::encode_item(&mut output, 0x7c, &x.encode()) }
+        if let Some(x) = &self.platform_status { crate::encode_item(&mut output, 0x7d, &x.encode()) }
+        if let Some(x) = &self.sensor_control_mode { crate::encode_item(&mut output, 0x7e, &x.encode()) }
+        if let Some(x) = &self.sensor_frame_rate { crate::encode_item(&mut output, 0x7f, &x.encode()) }
+        if !self.wavelengths_list.is_empty() { crate::encode_item(&mut output, 0x80, &self.wavelengths_list.iter().flat_map(|x| x.encode()).collect::<Vec<u8>>()) }
+        if let Some(x) = self.take_off_time { crate::encode_item(&mut output, 0x83, &ops::from_precision_timestamp(x)) }
+        if let Some(x) = self.onboard_storage_capacity { crate::encode_item(&mut output, 0x85, &x.to_be_bytes()) }
+        if let Some(x) = self.zoom_percentage { crate::encode_item(&mut output, 0x86, &ops::from_percentage(x)) }
+        if let Some(x) = &self.communications_method { crate::encode_item(&mut output, 0x87, x.as_bytes()) }
+        if let Some(x) = self.leap_seconds { crate::encode_item(&mut output, 0x88, &x.to_be_bytes()) }
+        if let Some(x) = self.correction_offset { crate::encode_item(&mut output, 0x89, &x.to_be_bytes()) }
+        if !self.payload_list.is_empty() { crate::encode_item(&mut output, 0x8a, &Payload::encode_list(&self.payload_list)) }
+        if let Some(x) = &self.active_payloads { crate::encode_item(&mut output, 0x8b, &ops::from_active_payloads(x)) }
+        if !self.weapons_stores.is_empty() { crate::encode_item(&mut output, 0x8c, &self.weapons_stores.iter().flat_map(|x| x.encode()).collect::<Vec<u8>>()) }
+        if !self.waypoint_list.is_empty() { crate::encode_item(&mut output, 0x8d, &self.waypoint_list.iter().flat_map(|x| x.encode()).collect::<Vec<u8>>()) }
+        if let Some(x) = &self.view_domain { crate::encode_item(&mut output, 0x8e, &x.encode()) }
+        if let Some(x) = &self.metadata_substream_id { crate::encode_item(&mut output, 0x8f, &x.encode()) }
+        output
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+/// Error type when decoding a [`Misb0601`] packet
+pub enum Misb0601Error {
+    #[error("Checksum mismatch: expected {expected:#06X}, got {actual:#06X}.")]
+    /// The checksum computed over the packet does not match the
+    /// [`Misb0601::checksum`] within the packet
+    ChecksumMismatch {
+        /// Checksum computed over the packet
+        expected: u16,
+        /// Checksum contained within the packet
+        actual: u16,
+    },
+    #[error("Checksum is not the final item of the packet.")]
+    /// The packet does not end with a [`Misb0601::checksum`] item
+    ChecksumNotFound,
+}
+/// [`Misb0601Error`] implementation of [`From<crate::checksum::ChecksumError>`]
+impl From<crate::checksum::ChecksumError> for Misb0601Error {
+    fn from(e: crate::checksum::ChecksumError) -> Self {
+        match e {
+            crate::checksum::ChecksumError::Mismatch { expected, actual } => Misb0601Error::ChecksumMismatch { expected, actual },
+            crate::checksum::ChecksumError::NotFound => Misb0601Error::ChecksumNotFound,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Icing status on the aircraft (i.e., the wings). Icing on
+/// wings can affect the continuation of the mission
+pub enum Icing {
+    DetectorOff,
+    NoIcingDetected,
+    IcingDetected,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// IR sensor images use either black values indicating
+/// hot or white values indicating hot
+pub enum IrPolarity {
+    BlackHot,
+    WhiteHot,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Slant range is measured (i.e., using Laser Range
+/// Finder) or calculated using gimbal/aircraft position
+/// and angles
+pub enum SlantRangeSource {
+    Measured,
+    Calculated,
+}
+#[derive(Debug, PartialEq)]
+/// See [`crate::misb0601::Misb0601::generic_flag_data`]
+pub struct GenericFlagData {
+    /// Laser Range Finder can be used to aid in geopositioning
+    /// 
+    /// Indicates whether or not laser range finder is on
+    pub laser_range_on: bool,
+    /// Sensor steering is automatically controlled by onboard tracking system
+    /// 
+    /// Indicates whether or not sensor steering is on
+    pub auto_track_on: bool,
+    /// Indicates IR polarity
+    pub ir_polarity: IrPolarity,
+    /// Indicates icing status
+    pub icing_status: Icing,
+    /// Indicates if slant range is measured or calculated
+    pub slant_range_source: SlantRangeSource,
+    /// An invalid image may result from a lens change,
+    /// bad focus or other camera issues which
+    /// significantly degrades the image
+    /// 
+    /// Indicates if image is invalid
+    pub is_image_invalid: bool,
+}
+#[cfg(feature = "misb0601-19")]
+/// [`GenericFlagData`] implementation of [`tinyklv::prelude::Decode`]
+impl tinyklv::prelude::Decode<&[u8]> for GenericFlagData {
+    /// See [`crate::misb0601::Misb0601::generic_flag_data`]
+    fn decode(input: &mut &[u8]) -> winnow::PResult<Self> {
+        let value = tinyklv::codecs::binary::dec::be_u8.parse_next(input)?;
+        Ok(crate::misb0601::GenericFlagData {
+            laser_range_on: (value >> 0) & 1 == 1,
+            auto_track_on: (value >> 1) & 1 == 1,
+            ir_polarity: match (value >> 2) & 1 == 1 {
+                false => crate::misb0601::IrPolarity::WhiteHot,
+                true => crate::misb0601::IrPolarity::BlackHot,
+            },
+            icing_status: match (value >> 3) & 1 == 1 {
+                false => crate::misb0601::Icing::NoIcingDetected,
+                true => crate::misb0601::Icing::IcingDetected,
+            },
+            slant_range_source: match (value >> 4) & 1 == 1 {
+                false => crate::misb0601::SlantRangeSource::Calculated,
+                true => crate::misb0601::SlantRangeSource::Measured,
+            },
+            is_image_invalid: (value >> 5) & 1 == 1,
+        })
+    }
+}
+#[cfg(feature = "misb0601-19")]
+/// [`GenericFlagData`] implementation of [`tinyklv::prelude::Encode`]
+impl tinyklv::prelude::Encode<Vec<u8>> for GenericFlagData {
+    /// See [`crate::misb0601::Misb0601::generic_flag_data`]
+    fn encode(&self) -> Vec<u8> {
+        let value = (self.laser_range_on as u8)
+            | (self.auto_track_on as u8) << 1
+            | ((self.ir_polarity == IrPolarity::BlackHot) as u8) << 2
+            | ((self.icing_status == Icing::IcingDetected) as u8) << 3
+            | ((self.slant_range_source == SlantRangeSource::Measured) as u8) << 4
+            | (self.is_image_invalid as u8) << 5;
+        vec![value]
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "misb0601-19")]
+mod test {
+    use super::*;
+    use tinyklv::prelude::*;
+
+    /// Sample UAS Datalink LS packet
+    const PACKET: &[u8] = &[
+        0x06, 0x0e, 0x2b, 0x34, 0x02, 0x0b, 0x01, 0x01, 0x0e, 0x01, 0x03, 0x01, 0x01, 0x00, 0x00, 0x00,
+        0x73, 0x02, 0x08, 0x00, 0x06, 0x12, 0x8a, 0x86, 0xe2, 0x7e, 0x12, 0x41, 0x01, 0x04, 0x0b, 0x08,
+        0x45, 0x4f, 0x57, 0x20, 0x2d, 0x20, 0x44, 0x4c, 0x0c, 0x0e, 0x47, 0x65, 0x6f, 0x64, 0x65, 0x74,
+        0x69, 0x63, 0x20, 0x57, 0x47, 0x53, 0x38, 0x34, 0x0d, 0x04, 0x2e, 0xaa, 0x4f, 0x8e, 0x0e, 0x04,
+        0xac, 0xb4, 0xc1, 0x09, 0x0f, 0x02, 0x0d, 0x38, 0x10, 0x02, 0x2c, 0x5a, 0x11, 0x02, 0x18, 0xf3,
+        0x12, 0x04, 0x40, 0x3a, 0x4f, 0x80, 0x13, 0x04, 0x40, 0x01, 0x6c, 0x16, 0x14, 0x04, 0x00, 0x00,
+        0x00, 0x00, 0x19, 0x02, 0x0b, 0x94, 0x2a, 0x02, 0x0b, 0x94, 0x38, 0x01, 0x00, 0x39, 0x04, 0x00,
+        0x00, 0x00, 0x00, 0x03, 0x0b, 0x49, 0x4d, 0x53, 0x41, 0x52, 0x20, 0x4d, 0x58, 0x2d, 0x31, 0x30,
+        0x01, 0x02, 0x6b, 0x84,
+    ];
+
+    #[test]
+    fn encode_roundtrip() {
+        let decoded = Misb0601::extract(&mut &PACKET[..]).unwrap();
+        let encoded = decoded.encode();
+        let n = encoded.len();
+        // --------------------------------------------------
+        // UL first, checksum last
+        // --------------------------------------------------
+        assert_eq!(&encoded[..16], UAS_DATALINK_LS_UL);
+        assert_eq!(&encoded[n - 4..n - 2], &[0x01, 0x02]);
+        assert_eq!(crate::checksum::verify(&encoded), Ok(()));
+        // --------------------------------------------------
+        // re-encoding a decoded packet is lossless
+        // --------------------------------------------------
+        let redecoded = Misb0601::extract(&mut encoded.as_slice()).unwrap();
+        assert_eq!(redecoded.precision_timestamp, decoded.precision_timestamp);
+        assert_eq!(redecoded.mission_id, decoded.mission_id);
+        assert_eq!(redecoded.uas_ls_version, Some(4));
+        assert_eq!(redecoded.platform_ground_speed, Some(0));
+        assert_eq!(redecoded.ground_range, Some(0.0));
+        assert_eq!(redecoded.encode(), encoded);
+    }
+
+    #[test]
+    fn every_item_roundtrip() {
+        use crate::misb1607::Misb1607;
+        let timestamp = Misb0601::extract(&mut &PACKET[..]).unwrap().precision_timestamp;
+        let mut security = Vec::new();
+        crate::encode_item(&mut security, 0x01, &[0x01]);
+        crate::encode_item(&mut security, 0x02, &[0x01]);
+        crate::encode_item(&mut security, 0x03, b"//US");
+        crate::encode_item(&mut security, 0x16, &12u16.to_be_bytes());
+        crate::encode_item(&mut security, 0x17, b"2008-01-01");
+        crate::encode_item(&mut security, 0x18, b"2008-01-01");
+        let vmti: &[u8] = &[0x03, 0x04, 0x56, 0x4d, 0x54, 0x49, 0x06, 0x01, 0x00];
+        let mut sarmi = Vec::new();
+        crate::encode_item(&mut sarmi, 0x01, &crate::misb1206::ops::from_grazing_angle(30.0));
+        crate::encode_item(&mut sarmi, 0x02, &crate::misb1206::ops::from_squint_angle(-12.5));
+        crate::encode_item(&mut sarmi, 0x03, &[0x01]);
+        crate::encode_item(&mut sarmi, 0x04, &[0x01]);
+        for key in 0x05..=0x08 { crate::encode_item(&mut sarmi, key, &crate::misb1206::ops::from_resolution(0.25)) }
+        crate::encode_item(&mut sarmi, 0x09, &1080u32.to_be_bytes());
+        crate::encode_item(&mut sarmi, 0x0A, &1920u32.to_be_bytes());
+        let miis = "0170:F592-F023-7336-4AF8-AA91-62C0-0F2E-B2DA/16B7-4341-0008-41A0-BE36-5B5A-B5D5-5B8D:35AD"
+            .parse::<crate::misb1204::Misb1204Miis>()
+            .unwrap();
+        let sdcc = crate::misb1010::KeyedSdcc {
+            keys: vec![0x0f, 0x0d, 0x0e],
+            sdcc: crate::misb1010::Misb1010::new(vec![4.0, 2.0, 3.0], vec![-0.25, 0.0, 0.5]).unwrap(),
+        };
+        let store = WeaponsStore {
+            station_id: 1,
+            hardpoint_id: 2,
+            carriage_id: 3,
+            store_id: 200,
+            general_status: WeaponGeneralStatus::ReadyAllUpRound,
+            engagement_status: WeaponEngagementStatus { fuze_enabled: true, laser_enabled: false, target_enabled: true, weapon_armed: true },
+            weapon_type: String::from("GBU-12"),
+        };
+        let waypoint = Waypoint {
+            id: 2,
+            order: -1,
+            mode: WaypointMode::Manual,
+            source: WaypointSource::AdHoc,
+            location: Some(WaypointLocation { latitude: 38.8895, longitude: -77.0353, hae: 120.0 }),
+        };
+        // --------------------------------------------------
+        // one item for every implemented tag, in tag order
+        // --------------------------------------------------
+        let items: Vec<(u64, Vec<u8>)> = vec![
+            (0x02, ops::from_precision_timestamp(timestamp).to_vec()),
+            (0x03, b"MISSION".to_vec()),
+            (0x04, b"TAIL".to_vec()),
+            (0x05, ops::from_platform_heading_angle(159.97).to_vec()),
+            (0x06, ops::from_platform_pitch_angle(-0.43).to_vec()),
+            (0x07, ops::from_platform_roll_angle(3.41).to_vec()),
+            (0x08, vec![147]),
+            (0x09, vec![159]),
+            (0x0a, b"MQ1-B".to_vec()),
+            (0x0b, b"EO".to_vec()),
+            (0x0c, b"WGS-84".to_vec()),
+            (0x0d, ops::from_lat(60.18).to_vec()),
+            (0x0e, ops::from_lon(128.43).to_vec()),
+            (0x0f, ops::from_alt(14190.72).to_vec()),
+            (0x10, ops::from_sensor_hvfov(144.57).to_vec()),
+            (0x11, ops::from_sensor_hvfov(152.64).to_vec()),
+            (0x12, ops::from_sensor_relative_azimuth_angle(160.72).to_vec()),
+            (0x13, ops::from_sensor_relative_elevation_angle(-168.79).to_vec()),
+            (0x14, ops::from_sensor_relative_roll_angle(176.87).to_vec()),
+            (0x15, ops::from_slant_range(68590.98).to_vec()),
+            (0x16, ops::from_target_width(722.82).to_vec()),
+            (0x17, ops::from_lat(-10.54).to_vec()),
+            (0x18, ops::from_lon(29.16).to_vec()),
+            (0x19, ops::from_alt(3216.04).to_vec()),
+        ];
+        let items: Vec<(u64, Vec<u8>)> = items
+            .into_iter()
+            .chain((0x1a..=0x21).map(|key| (key, ops::from_offset_ll(-0.01).to_vec())))
+            .chain(vec![
+                (0x22, vec![ops::from_icing_detected(Icing::NoIcingDetected)]),
+                (0x23, ops::from_wind_direction(235.92).to_vec()),
+                (0x24, ops::from_wind_speed(69.81).to_vec()),
+                (0x25, ops::from_mbar_pressure(3725.19).to_vec()),
+                (0x26, ops::from_alt(14818.68).to_vec()),
+                (0x27, (-5i8).to_be_bytes().to_vec()),
+                (0x28, ops::from_lat(-79.16).to_vec()),
+                (0x29, ops::from_lon(166.40).to_vec()),
+                (0x2a, ops::from_alt(18389.05).to_vec()),
+                (0x2b, ops::from_target_track_gate_hw(6).to_vec()),
+                (0x2c, ops::from_target_track_gate_hw(30).to_vec()),
+                (0x2d, ops::from_error_estimate(425.21).to_vec()),
+                (0x2e, ops::from_error_estimate(608.92).to_vec()),
+                (0x2f, vec![0x31]),
+                (0x30, security),
+                (0x31, ops::from_mbar_pressure(1191.96).to_vec()),
+                (0x32, ops::from_platform_pitch_angle(-8.67).to_vec()),
+                (0x33, ops::from_platform_vertical_speed(-61.89).to_vec()),
+                (0x34, ops::from_platform_pitch_angle(-5.08).to_vec()),
+                (0x35, ops::from_mbar_pressure(2088.96).to_vec()),
+                (0x36, ops::from_alt(8306.81).to_vec()),
+                (0x37, ops::from_relative_humidity(81.18).to_vec()),
+                (0x38, vec![140]),
+                (0x39, ops::from_slant_range(3506979.03).to_vec()),
+                (0x3a, ops::from_fuel_remaining(6420.54).to_vec()),
+                (0x3b, b"TOP GUN".to_vec()),
+                (0x3c, WeaponLoad { station: 1, substation: 2, weapon_type: 3, weapon_variant: 4 }.encode()),
+                (0x3d, WeaponFired { station: 1, substation: 2 }.encode()),
+                (0x3e, 1111u16.to_be_bytes().to_vec()),
+                (0x3f, SensorFovName::Wide.encode()),
+                (0x40, ops::from_platform_heading_angle(321.42).to_vec()),
+                (0x41, vec![19]),
+                (0x43, ops::from_lat(-86.04).to_vec()),
+                (0x44, ops::from_lon(-179.14).to_vec()),
+                (0x45, ops::from_alt(9.96).to_vec()),
+                (0x46, b"APACHE".to_vec()),
+                (0x47, ops::from_platform_heading_angle(32.60).to_vec()),
+                (0x49, vec![0x07, 0x04, 0x00, 0x00, 0x00, 0x07]),
+                (0x4a, vmti.to_vec()),
+                (0x4b, ops::from_alt(14190.72).to_vec()),
+                (0x4c, ops::from_alt(9.96).to_vec()),
+                (0x4e, ops::from_alt(9.96).to_vec()),
+                (0x4f, ops::from_sensor_velocity(25.5).to_vec()),
+                (0x50, ops::from_sensor_velocity(-12.25).to_vec()),
+                (0x51, ImageHorizon { start_x: 0, start_y: 50, end_x: 100, end_y: 50, locations: None }.encode()),
+            ])
+            .chain((0x52..=0x59).map(|key| match key % 2 {
+                0 => (key, ops::from_lat(-10.58).to_vec()),
+                _ => (key, ops::from_lon(29.12).to_vec()),
+            }))
+            .chain(vec![
+                (0x5a, ops::from_platform_angle_full(-0.43).to_vec()),
+                (0x5b, ops::from_platform_angle_full(3.41).to_vec()),
+                (0x5c, ops::from_platform_angle_full(-8.67).to_vec()),
+                (0x5d, ops::from_platform_sideslip_angle_full(-5.08).to_vec()),
+                (0x5e, miis.encode()),
+                (0x5f, sarmi),
+                (0x61, crate::misb1002::Misb1002 { document_version: Some(2), ..Default::default() }.encode_value()),
+                (0x62, vec![0x01, 0x01, 0x02]),
+                (0x63, vec![0x02, 0x01, 0x01]),
+                (0x64, Misb1607 { items: vec![(0x0b, b"IR".to_vec())] }.encode_value()),
+                (0x65, Misb1607 { items: vec![(0x03, b"AMENDED".to_vec())] }.encode_value()),
+                (0x66, sdcc.encode_value()),
+                (0x67, ops::from_alt_extended(23456.24)),
+                (0x68, ops::from_alt_extended(23456.24)),
+                (0x69, ops::from_alt_extended(23456.24)),
+                (0x6a, b"BLUE".to_vec()),
+                (0x6b, b"BASE01".to_vec()),
+                (0x6c, b"HOME".to_vec()),
+                (0x6d, ops::from_range_to_recovery(1625.37)),
+                (0x6e, 2000u32.to_be_bytes().to_vec()),
+                (0x6f, 2000u32.to_be_bytes().to_vec()),
+                (0x70, ops::from_course_angle(29.7)),
+                (0x71, ops::from_alt_extended(2150.0)),
+                (0x72, ops::from_alt_extended(2154.5)),
+                (0x73, ControlCommand { id: 1, command: String::from("Fly to Waypoint 1"), timestamp: None }.encode()),
+                (0x74, ops::from_ber_oid_list(&[1, 2])),
+                (0x75, ops::from_sensor_rate(1.0)),
+                (0x76, ops::from_sensor_rate(-5.0)),
+                (0x77, ops::from_sensor_rate(0.5)),
+                (0x78, ops::from_percentage(50.0)),
+                (0x79, ops::from_ber_oid_list(&[1, 2])),
+                (0x7a, CountryCodes {
+                    coding_method: crate::misb0102::CountryCodingMethod::Iso3166TwoLetter,
+                    overflight_country: String::from("CA"),
+                    operator_country: Some(String::from("US")),
+                    country_of_manufacture: None,
+                }.encode()),
+                (0x7b, vec![7]),
+                (0x7c, vec![0x03]),
+                (0x7d, PlatformStatus::Ingress.encode()),
+                (0x7e, SensorControlMode::AutoTracking.encode()),
+                (0x7f, SensorFrameRate { numerator: 60000, denominator: 1001 }.encode()),
+                (0x80, Wavelength { id: 7, min: 400.0, max: 700.0, name: String::from("VIS2") }.encode()),
+                (0x83, ops::from_precision_timestamp(timestamp).to_vec()),
+                (0x85, 1024u32.to_be_bytes().to_vec()),
+                (0x86, ops::from_percentage(25.0)),
+                (0x87, b"LOS".to_vec()),
+                (0x88, 18i32.to_be_bytes().to_vec()),
+                (0x89, (-1_500_000i64).to_be_bytes().to_vec()),
+                (0x8a, Payload::encode_list(&[Payload { id: 0, payload_type: PayloadType::ElectroOptical, name: String::from("EO") }])),
+                (0x8b, ops::from_active_payloads(&[0])),
+                (0x8c, store.encode()),
+                (0x8d, waypoint.encode()),
+                (0x8e, ViewDomain { azimuth: Some(ViewDomainInterval { start: 0.0, range: 360.0 }), elevation: None, roll: None }.encode()),
+                (0x8f, MetadataSubstreamId::new(7).encode()),
+            ])
+            .collect();
+        let mut value = Vec::new();
+        for (key, x) in &items { crate::encode_item(&mut value, *key, x) }
+        let decoded = Misb0601::extract(&mut Misb0601::packet_from_value(value).as_slice()).unwrap();
+        // --------------------------------------------------
+        // every item is decoded and encoded again, so a tag
+        // missing from `encode_value` is caught here
+        // --------------------------------------------------
+        let encoded = decoded.encode_value();
+        let keys = Misb1607::decode(&mut encoded.as_slice()).unwrap().items.into_iter().map(|(key, _)| key).collect::<Vec<_>>();
+        assert_eq!(keys, items.iter().map(|(key, _)| *key).collect::<Vec<_>>());
+        // --------------------------------------------------
+        // and decoding the encoded packet is lossless
+        // --------------------------------------------------
+        let redecoded = Misb0601::extract(&mut decoded.encode().as_slice()).unwrap();
+        assert_eq!(redecoded.encode_value(), encoded);
+    }
+
+    #[test]
+    fn checksum_verification() {
+        assert_eq!(Misb0601::verify_checksum(PACKET), Ok(()));
+        // --------------------------------------------------
+        // corrupt the first byte of the sensor latitude
+        // value: 0x2E -> 0xD1
+        // --------------------------------------------------
+        let mut corrupted = PACKET.to_vec();
+        corrupted[58] ^= 0xFF;
+        assert_eq!(
+            Misb0601::verify_checksum(&corrupted),
+            Err(Misb0601Error::ChecksumMismatch { expected: 0x0E84, actual: 0x6B84 }),
+        );
+        assert!(Misb0601::extract(&mut corrupted.as_slice()).is_err());
+        assert!(Misb0601::extract_lenient(&mut corrupted.as_slice()).is_ok());
+    }
+
+    #[test]
+    fn checksum_after_junk() {
+        // --------------------------------------------------
+        // bytes skipped before the UL are not part of the
+        // checksum, including a partial UL
+        // --------------------------------------------------
+        let mut stream = vec![0xDE, 0xAD, 0x06, 0x0E, 0x2B, 0x34];
+        stream.extend_from_slice(PACKET);
+        stream.extend_from_slice(&[0xBE, 0xEF]);
+        stream.extend_from_slice(PACKET);
+        let expected = Misb0601::extract_lenient(&mut &PACKET[..]).unwrap().encode();
+        assert_eq!(Misb0601::extract(&mut stream.as_slice()).unwrap().encode(), expected);
+        let demuxed = Misb0601::demux(&mut stream.as_slice()).unwrap();
+        assert_eq!(demuxed[&None].len(), 2);
+        assert!(demuxed[&None].iter().all(|x| x.encode() == expected));
+        // --------------------------------------------------
+        // unlike extract, decode does not skip anything
+        // --------------------------------------------------
+        assert!(Misb0601::decode(&mut stream.as_slice()).is_err());
+    }
+
+    #[test]
+    fn checksum_long_form_length() {
+        assert!(Misb0601::decode(&mut &PACKET[..]).is_ok());
+        // --------------------------------------------------
+        // re-write the short form length of PACKET in its
+        // long form, and re-compute the checksum
+        // --------------------------------------------------
+        let mut packet = UAS_DATALINK_LS_UL.to_vec();
+        packet.extend_from_slice(&[0x81, PACKET[16]]);
+        packet.extend_from_slice(&PACKET[17..]);
+        crate::checksum::patch(&mut packet).unwrap();
+        let expected = Misb0601::extract_lenient(&mut &PACKET[..]).unwrap().encode();
+        assert_eq!(Misb0601::decode(&mut packet.as_slice()).unwrap().encode(), expected);
+        assert_eq!(Misb0601::extract(&mut packet.as_slice()).unwrap().encode(), expected);
+    }
+
+    #[test]
+    fn imapb_special_values() {
+        assert_eq!(ops::from_alt_extended(f64::NAN), vec![0xD0, 0x00, 0x00]);
+        assert_eq!(ops::from_alt_extended(f64::NEG_INFINITY), vec![0xE8, 0x00, 0x00]);
+        assert_eq!(ops::from_alt_extended(-1000.0), vec![0xE0, 0x00, 0x00]);
+        assert_eq!(ops::from_alt_extended(1e9), vec![0xE1, 0x00, 0x00]);
+        assert_eq!(ops::from_course_angle(361.0), vec![0xE1, 0x00, 0x00]);
+    }
+
+    #[test]
+    fn stores_and_waypoints_roundtrip() {
+        let store = WeaponsStore {
+            station_id: 1,
+            hardpoint_id: 2,
+            carriage_id: 3,
+            store_id: 200,
+            general_status: WeaponGeneralStatus::ReadyAllUpRound,
+            engagement_status: WeaponEngagementStatus { fuze_enabled: true, laser_enabled: false, target_enabled: true, weapon_armed: true },
+            weapon_type: String::from("GBU-12"),
+        };
+        let encoded = store.encode();
+        let decoded = WeaponsStore::decode_series(encoded.len())(&mut encoded.as_slice()).unwrap();
+        assert_eq!(decoded, vec![store]);
+        assert_eq!(decoded[0].encode(), encoded);
+
+        let waypoints = vec![
+            Waypoint { id: 1, order: 1, mode: WaypointMode::Automated, source: WaypointSource::PrePlanned, location: None },
+            Waypoint {
+                id: 2,
+                order: -1,
+                mode: WaypointMode::Manual,
+                source: WaypointSource::AdHoc,
+                location: Some(WaypointLocation { latitude: 38.8895, longitude: -77.0353, hae: 120.0 }),
+            },
+        ];
+        let encoded = waypoints.iter().flat_map(|x| x.encode()).collect::<Vec<u8>>();
+        let decoded = Waypoint::decode_series(encoded.len())(&mut encoded.as_slice()).unwrap();
+        assert_eq!(decoded.len(), 2);
+        assert_eq!(decoded[0], waypoints[0]);
+        assert_eq!((decoded[1].id, decoded[1].order, decoded[1].mode, decoded[1].source), (2, -1, WaypointMode::Manual, WaypointSource::AdHoc));
+        let location = decoded[1].location.unwrap();
+        assert!((location.latitude - 38.8895).abs() < 1e-6);
+        assert!((location.longitude + 77.0353).abs() < 1e-6);
+        assert!((location.hae - 120.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn payload_list() {
+        let payloads = vec![
+            Payload { id: 0, payload_type: PayloadType::ElectroOptical, name: String::from("EO Nose") },
+            Payload { id: 1, payload_type: PayloadType::Lidar, name: String::from("LIDAR") },
+        ];
+        let encoded = Payload::encode_list(&payloads);
+        assert_eq!(Payload::decode_list(encoded.len())(&mut encoded.as_slice()), Ok(payloads));
+        // --------------------------------------------------
+        // a count larger than the value fails, rather than
+        // allocating for every claimed record
+        // --------------------------------------------------
+        let hostile: &[u8] = &[0x8f, 0xff, 0xff, 0xff, 0x7f, 0x03, 0x00, 0x00, 0x00];
+        assert!(Payload::decode_list(hostile.len())(&mut &hostile[..]).is_err());
+    }
+
+    #[test]
+    fn active_payloads() {
+        let encoded = ops::from_active_payloads(&[0, 9]);
+        assert_eq!(encoded, vec![0x02, 0x01]);
+        assert_eq!(ops::to_active_payloads(encoded.len())(&mut encoded.as_slice()), Ok(vec![0, 9]));
+        // --------------------------------------------------
+        // IDs beyond the bitmap are skipped, rather than
+        // allocating a bitmap for them
+        // --------------------------------------------------
+        assert_eq!(ops::from_active_payloads(&[u64::MAX, 9]), vec![0x02, 0x00]);
+        let max = ops::from_active_payloads(&[ops::ACTIVE_PAYLOAD_ID_MAX]);
+        assert_eq!(max.len(), ops::ACTIVE_PAYLOADS_MAX_LEN);
+        assert_eq!(ops::to_active_payloads(max.len())(&mut max.as_slice()), Ok(vec![ops::ACTIVE_PAYLOAD_ID_MAX]));
+        let hostile = vec![0x01; ops::ACTIVE_PAYLOADS_MAX_LEN + 1];
+        assert!(ops::to_active_payloads(hostile.len())(&mut hostile.as_slice()).is_err());
+    }
+
+    #[test]
+    fn time_scales() {
+        let mut decoded = Misb0601::extract(&mut &PACKET[..]).unwrap();
+        let misp = decoded.precision_timestamp;
+        // --------------------------------------------------
+        // defaults: no correction, 18 leap seconds
+        // --------------------------------------------------
+        assert_eq!(decoded.corrected_precision_timestamp(), misp);
+        assert_eq!(decoded.gps_time(), misp);
+        assert_eq!(decoded.utc_time(), misp - chrono::Duration::seconds(18));
+        assert_eq!(decoded.tai_time(), misp + chrono::Duration::seconds(19));
+        // --------------------------------------------------
+        // explicit leap seconds and correction offset
+        // --------------------------------------------------
+        decoded.leap_seconds = Some(17);
+        decoded.correction_offset = Some(-1_500_000);
+        let corrected = misp - chrono::Duration::microseconds(1_500_000);
+        assert_eq!(decoded.corrected_precision_timestamp(), corrected);
+        assert_eq!(decoded.utc_time(), corrected - chrono::Duration::seconds(17));
+        assert_eq!(decoded.tai_time(), corrected + chrono::Duration::seconds(19));
+        // --------------------------------------------------
+        // both survive encoding
+        // --------------------------------------------------
+        let redecoded = Misb0601::extract(&mut decoded.encode().as_slice()).unwrap();
+        assert_eq!(redecoded.leap_seconds, Some(17));
+        assert_eq!(redecoded.correction_offset, Some(-1_500_000));
+    }
+
+    #[test]
+    fn embedded_vmti() {
+        // --------------------------------------------------
+        // VMTI LS value, without UL or checksum:
+        // system name "VMTI", version 6, 2 targets
+        // reported, 1920 x 1080 frame
+        // --------------------------------------------------
+        let vmti: &[u8] = &[
+            0x03, 0x04, 0x56, 0x4d, 0x54, 0x49, 0x04, 0x01, 0x06, 0x06, 0x01, 0x02,
+            0x08, 0x02, 0x07, 0x80, 0x09, 0x02, 0x04, 0x38,
+        ];
+        let mut decoded = Misb0601::extract(&mut &PACKET[..]).unwrap();
+        decoded.vmti_local_set = Some(crate::misb0903::Misb0903::decode(&mut &vmti[..]).unwrap());
+        // --------------------------------------------------
+        // an item following the VMTI LS must not be consumed
+        // by the VMTI LS decoder
+        // --------------------------------------------------
+        decoded.stream_designator = Some(String::from("STREAM"));
+        let redecoded = Misb0601::extract(&mut decoded.encode().as_slice()).unwrap();
+        assert_eq!(redecoded.stream_designator.as_deref(), Some("STREAM"));
+        let redecoded = redecoded.vmti_local_set.unwrap();
+        assert_eq!(redecoded.checksum, None);
+        assert_eq!(redecoded.vmti_system_name.as_deref(), Some("VMTI"));
+        assert_eq!(redecoded.vmti_ls_version, Some(6));
+        assert_eq!(redecoded.num_targets_reported, 2);
+        assert_eq!((redecoded.frame_width, redecoded.frame_height), (Some(1920), Some(1080)));
+    }
+
+    #[test]
+    fn embedded_rvt() {
+        // --------------------------------------------------
+        // RVT LS value, without UL or checksum: frame code
+        // 7, and two PoIs with MGRS zone 18 between them
+        // --------------------------------------------------
+        let poi: &[u8] = &[
+            0x01, 0x02, 0x00, 0x2A, 0x02, 0x04, 0x3D, 0x4F, 0x5A, 0x6E, 0x03, 0x04, 0xC9, 0x3E, 0x3C, 0x3A,
+            0x05, 0x01, 0x01,
+        ];
+        let mut rvt = vec![0x07, 0x04, 0x00, 0x00, 0x00, 0x07];
+        crate::encode_item(&mut rvt, 0x0C, poi);
+        crate::encode_item(&mut rvt, 0x0E, &[0x12]);
+        crate::encode_item(&mut rvt, 0x0C, poi);
+        let mut decoded = Misb0601::extract(&mut &PACKET[..]).unwrap();
+        decoded.rvt_local_set = Some(crate::misb0806::Misb0806::decode_value(&mut rvt.as_slice()).unwrap());
+        // --------------------------------------------------
+        // an item following the RVT LS must not be consumed
+        // by the RVT LS decoder
+        // --------------------------------------------------
+        decoded.stream_designator = Some(String::from("STREAM"));
+        let redecoded = Misb0601::extract(&mut decoded.encode().as_slice()).unwrap();
+        assert_eq!(redecoded.stream_designator.as_deref(), Some("STREAM"));
+        let redecoded = redecoded.rvt_local_set.unwrap();
+        assert_eq!(redecoded.frame_code, Some(7));
+        assert_eq!(redecoded.mgrs_zone, Some(18));
+        assert_eq!(redecoded.poi_local_sets.len(), 2);
+    }
+
+    /// Re-encodes [`PACKET`] after `set`, followed by a metadata substream
+    /// ID (tag 143), which is only decoded if every local set embedded by
+    /// `set` is bounded by the length of its item
+    fn embed(set: impl FnOnce(&mut Misb0601)) -> Misb0601 {
+        let mut decoded = Misb0601::extract(&mut &PACKET[..]).unwrap();
+        set(&mut decoded);
+        decoded.metadata_substream_id = Some(MetadataSubstreamId::new(7));
+        let redecoded = Misb0601::extract(&mut decoded.encode().as_slice()).unwrap();
+        assert_eq!(redecoded.metadata_substream_id.as_ref().map(|x| x.id), Some(7));
+        redecoded
+    }
+
+    #[test]
+    fn embedded_sarmi() {
+        let mut sarmi = Vec::new();
+        crate::encode_item(&mut sarmi, 0x01, &crate::misb1206::ops::from_grazing_angle(30.0));
+        crate::encode_item(&mut sarmi, 0x02, &crate::misb1206::ops::from_squint_angle(-12.5));
+        crate::encode_item(&mut sarmi, 0x03, &[0x01]);
+        crate::encode_item(&mut sarmi, 0x04, &[0x01]);
+        for key in 0x05..=0x08 { crate::encode_item(&mut sarmi, key, &crate::misb1206::ops::from_resolution(0.25)) }
+        crate::encode_item(&mut sarmi, 0x09, &1080u32.to_be_bytes());
+        crate::encode_item(&mut sarmi, 0x0A, &1920u32.to_be_bytes());
+        let redecoded = embed(|x| x.sar_motion_imagery_local_set = Some(crate::misb1206::Misb1206::decode(&mut sarmi.as_slice()).unwrap()));
+        let redecoded = redecoded.sar_motion_imagery_local_set.unwrap();
+        assert_eq!((redecoded.image_rows, redecoded.image_columns), (1080, 1920));
+        assert_eq!(redecoded.encode_value(), sarmi);
+    }
+
+    #[test]
+    fn embedded_range_image() {
+        use crate::misb1002::{DepthMap, Misb1002, SectionDataPack};
+        let section = SectionDataPack {
+            section_x: 1,
+            section_y: 1,
+            ranges: Some(DepthMap::new(vec![1, 2], vec![10.0, 10.5]).unwrap()),
+            uncertainties: None,
+            plane_x_scale: None,
+            plane_y_scale: None,
+            plane_constant: None,
+        };
+        let range_image = Misb1002 {
+            document_version: Some(2),
+            section_data_packs: vec![section.clone()],
+            ..Default::default()
+        };
+        let redecoded = embed(|x| x.range_image_local_set = Some(range_image));
+        let redecoded = redecoded.range_image_local_set.unwrap();
+        assert_eq!(redecoded.document_version, Some(2));
+        assert_eq!(redecoded.section_data_packs, vec![section]);
+    }
+
+    #[test]
+    fn embedded_geo_registration() {
+        use crate::misb1303::Mdap;
+        let mut georeg = Vec::new();
+        crate::encode_item(&mut georeg, 0x01, &[0x02]);
+        crate::encode_item(&mut georeg, 0x02, b"ORB-RANSAC");
+        crate::encode_item(&mut georeg, 0x04, &crate::misb1601::ops::from_points(&Mdap::new(vec![1, 2], vec![10.0, 20.0]).unwrap()));
+        crate::encode_item(&mut georeg, 0x05, &crate::misb1601::ops::from_points(&Mdap::new(vec![1, 2], vec![38.8, -77.1]).unwrap()));
+        let redecoded = embed(|x| x.geo_registration_local_set = Some(crate::misb1601::Misb1601::decode(&mut georeg.as_slice()).unwrap()));
+        let redecoded = redecoded.geo_registration_local_set.unwrap();
+        assert_eq!(redecoded.algorithm_name.as_deref(), Some("ORB-RANSAC"));
+        assert_eq!(redecoded.correspondence_points().len(), 1);
+        assert_eq!(redecoded.encode_value(), georeg);
+    }
+
+    #[test]
+    fn embedded_composite_imaging() {
+        let miis = "0170:F592-F023-7336-4AF8-AA91-62C0-0F2E-B2DA/16B7-4341-0008-41A0-BE36-5B5A-B5D5-5B8D:35AD"
+            .parse::<crate::misb1204::Misb1204Miis>()
+            .unwrap();
+        let mut composite = Vec::new();
+        crate::encode_item(&mut composite, 0x02, &[0x01]);
+        crate::encode_item(&mut composite, 0x03, &1080u32.to_be_bytes());
+        crate::encode_item(&mut composite, 0x04, &1920u32.to_be_bytes());
+        crate::encode_item(&mut composite, 0x13, &miis.encode());
+        let redecoded = embed(|x| x.composite_imaging_local_set = Some(crate::misb1602::Misb1602::decode(&mut composite.as_slice()).unwrap()));
+        let redecoded = redecoded.composite_imaging_local_set.unwrap();
+        assert_eq!((redecoded.source_image_rows, redecoded.source_image_columns), (Some(1080), Some(1920)));
+        assert_eq!(redecoded.miis_core_identifier, Some(miis));
+        assert_eq!(redecoded.encode_value(), composite);
+    }
+
+    #[test]
+    fn embedded_miis() {
+        let text = "0170:F592-F023-7336-4AF8-AA91-62C0-0F2E-B2DA/16B7-4341-0008-41A0-BE36-5B5A-B5D5-5B8D:35AD";
+        let miis = text.parse::<crate::misb1204::Misb1204Miis>().unwrap();
+        let mut decoded = Misb0601::extract(&mut &PACKET[..]).unwrap();
+        decoded.miis_core_identifier = Some(miis.clone());
+        decoded.stream_designator = Some(String::from("STREAM"));
+        let redecoded = Misb0601::extract(&mut decoded.encode().as_slice()).unwrap();
+        assert_eq!(redecoded.miis_core_identifier, Some(miis));
+        assert_eq!(redecoded.miis_core_identifier.unwrap().to_string(), text);
+        assert_eq!(redecoded.stream_designator.as_deref(), Some("STREAM"));
+    }
+
+    #[test]
+    fn embedded_sdcc() {
+        use crate::misb1010::{KeyedSdcc, Misb1010};
+        let sdcc = KeyedSdcc {
+            keys: vec![0x0f, 0x0d, 0x0e],
+            sdcc: Misb1010::new(vec![4.0, 2.0, 3.0], vec![-0.25, 0.0, 0.5]).unwrap(),
+        };
+        let mut decoded = Misb0601::extract(&mut &PACKET[..]).unwrap();
+        decoded.sdcc_flp = Some(sdcc.clone());
+        decoded.stream_designator = Some(String::from("STREAM"));
+        let redecoded = Misb0601::extract(&mut decoded.encode().as_slice()).unwrap();
+        assert_eq!(redecoded.sdcc_flp, Some(sdcc.clone()));
+        assert_eq!(redecoded.stream_designator.as_deref(), Some("STREAM"));
+        // --------------------------------------------------
+        // re-ordered to latitude, longitude, altitude
+        // --------------------------------------------------
+        assert_eq!(redecoded.sensor_position_covariance(), Some(vec![
+            vec![4.0, 3.0, -2.0],
+            vec![3.0, 9.0, 0.0],
+            vec![-2.0, 0.0, 16.0],
+        ]));
+        // --------------------------------------------------
+        // a coefficient outside of the IMAP skips the item
+        // --------------------------------------------------
+        let invalid = Misb1010 { correlation_coefficients: vec![2.0, 0.0, 0.5], ..sdcc.sdcc.with_imap(2) };
+        decoded.sdcc_flp = Some(KeyedSdcc { keys: sdcc.keys, sdcc: invalid });
+        let redecoded = Misb0601::extract(&mut decoded.encode().as_slice()).unwrap();
+        assert_eq!(redecoded.sdcc_flp, None);
+        assert_eq!(redecoded.stream_designator.as_deref(), Some("STREAM"));
+    }
+
+    #[test]
+    fn demux_substreams() {
+        let mut decoded = Misb0601::extract(&mut &PACKET[..]).unwrap();
+        let mut stream = PACKET.to_vec();
+        for id in [2, 1, 2] {
+            decoded.metadata_substream_id = Some(MetadataSubstreamId::new(id));
+            stream.extend(decoded.encode());
+        }
+        let demuxed = Misb0601::demux(&mut stream.as_slice()).unwrap();
+        assert_eq!(demuxed.keys().collect::<Vec<_>>(), vec![&None, &Some(1), &Some(2)]);
+        assert_eq!(demuxed[&None].len(), 1);
+        assert_eq!(demuxed[&Some(1)].len(), 1);
+        assert_eq!(demuxed[&Some(2)].len(), 2);
+        // --------------------------------------------------
+        // elements of the pack after the ID are kept
+        // --------------------------------------------------
+        let id = MetadataSubstreamId { id: 3, elements: vec![0x01, 0x02] };
+        decoded.metadata_substream_id = Some(id.clone());
+        let redecoded = Misb0601::extract(&mut decoded.encode().as_slice()).unwrap();
+        assert_eq!(redecoded.metadata_substream_id, Some(id));
+    }
+
+    #[test]
+    fn segments_and_amend() {
+        use crate::misb1607::Misb1607;
+        let mut decoded = Misb0601::extract(&mut &PACKET[..]).unwrap();
+        // --------------------------------------------------
+        // amend the mission ID, and remove the image source
+        // sensor
+        // --------------------------------------------------
+        decoded.amend_local_set = Some(Misb1607 { items: vec![
+            (0x03, b"AMENDED".to_vec()),
+            (0x0b, vec![]),
+        ] });
+        // --------------------------------------------------
+        // two sensor segments, where only the second
+        // overrides the sensor latitude
+        // --------------------------------------------------
+        decoded.segment_local_sets = vec![
+            Misb1607 { items: vec![(0x0b, b"EO".to_vec())] },
+            Misb1607 { items: vec![(0x0b, b"IR".to_vec()), (0x0d, ops::from_lat(45.0).to_vec())] },
+        ];
+        let redecoded = Misb0601::extract(&mut decoded.encode().as_slice()).unwrap();
+        assert_eq!(redecoded.segment_local_sets, decoded.segment_local_sets);
+        assert_eq!(redecoded.amend_local_set, decoded.amend_local_set);
+        assert_eq!(redecoded.mission_id, decoded.mission_id);
+        // --------------------------------------------------
+        // amended packet
+        // --------------------------------------------------
+        let amended = redecoded.amended().unwrap();
+        assert_eq!(amended.mission_id.as_deref(), Some("AMENDED"));
+        assert_eq!(amended.image_source_sensor, None);
+        assert_eq!(amended.sensor_latitude, redecoded.sensor_latitude);
+        assert!(amended.segment_local_sets.is_empty() && amended.amend_local_set.is_none());
+        // --------------------------------------------------
+        // segments inherit the amended packet
+        // --------------------------------------------------
+        let segments = redecoded.segments().unwrap();
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[0].image_source_sensor.as_deref(), Some("EO"));
+        assert_eq!(segments[1].image_source_sensor.as_deref(), Some("IR"));
+        assert_eq!(segments.iter().map(|x| x.mission_id.as_deref()).collect::<Vec<_>>(), vec![Some("AMENDED"); 2]);
+        assert_eq!(segments[0].sensor_latitude, redecoded.sensor_latitude);
+        assert!((segments[1].sensor_latitude.unwrap() - 45.0).abs() < 1e-6);
+        assert_eq!(segments[1].precision_timestamp, redecoded.precision_timestamp);
+        assert!(Misb0601::extract(&mut &PACKET[..]).unwrap().segments().unwrap().is_empty());
+        // --------------------------------------------------
+        // items which are not implemented are kept in their
+        // original position, and the checksum is computed
+        // over the amended items
+        // --------------------------------------------------
+        let mut items = redecoded.raw_items.clone();
+        items.insert(1, (0x48, vec![0x01, 0x02]));
+        let amended = Misb0601::decode_items(&items).unwrap().amended().unwrap();
+        assert_eq!(amended.raw_items[1], (0x48, vec![0x01, 0x02]));
+        assert_eq!(amended.mission_id.as_deref(), Some("AMENDED"));
+        let mut value = Vec::new();
+        for (key, x) in &amended.raw_items { crate::encode_item(&mut value, *key, x) }
+        let mut packet = UAS_DATALINK_LS_UL.to_vec();
+        packet.extend(tinyklv::codecs::ber::enc::ber_length(&value.len()));
+        packet.extend(value);
+        assert_eq!(Misb0601::verify_checksum(&packet), Ok(()));
     }
 }
\ No newline at end of file
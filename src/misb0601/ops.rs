@@ -29,6 +29,11 @@ pub const SFT_2_WIND_SPEED: f32 = 255.0 / 100.0;
 pub const SFT_2_MBAR_PRESSURE: f32 = 65535.0 / 5000.0;
 pub const SFT_2_ERROR_ESTIMATE: f32 = 65535.0 / 4095.0;
 pub const SFT_2_PLATFORM_VERT_SPEED: f32 = 65534.0 / 360.0;
+pub const SFT_2_RELATIVE_HUMIDITY: f32 = 255.0 / 100.0;
+pub const SFT_2_FUEL_REMAINING: f32 = 65535.0 / 10_000.0;
+pub const SFT_2_PLATFORM_ANGLE_FULL: f64 = 4294967294.0 / 180.0;
+pub const SFT_2_PLATFORM_SIDESLIP_FULL: f64 = 4294967294.0 / 360.0;
+pub const SFT_2_SENSOR_VELOCITY: f32 = 65534.0 / 654.0;
 // --------------------------------------------------
 // decoding values
 // --------------------------------------------------
@@ -49,10 +54,132 @@ pub const KLV_2_WIND_SPEED: f32 = 100.0 / 255.0;
 pub const KLV_2_MBAR_PRESSURE: f32 = 5000.0 / 65535.0;
 pub const KLV_2_ERROR_ESTIMATE: f32 = 4095.0 / 65535.0;
 pub const KLV_2_PLATFORM_VERT_SPEED: f32 = 360.0 / 65534.0;
+pub const KLV_2_RELATIVE_HUMIDITY: f32 = 100.0 / 255.0;
+pub const KLV_2_FUEL_REMAINING: f32 = 10_000.0 / 65535.0;
+pub const KLV_2_PLATFORM_ANGLE_FULL: f64 = 180.0 / 4294967294.0;
+pub const KLV_2_PLATFORM_SIDESLIP_FULL: f64 = 360.0 / 4294967294.0;
+pub const KLV_2_SENSOR_VELOCITY: f32 = 654.0 / 65534.0;
 // --------------------------------------------------
 // both encoding and decoding / misc
 // --------------------------------------------------
 pub const SENSOR_TRUE_ALT_OFFSET_P2: f32 = 900.0;
+pub const ALT_EXTENDED_MIN: f64 = -900.0;
+pub const ALT_EXTENDED_MAX: f64 = 40_000.0;
+/// Number of leap seconds between GPS time (i.e., MISP time) and UTC,
+/// as of 2017-01-01. Used when no leap seconds are provided
+pub const DEFAULT_LEAP_SECONDS: i32 = 18;
+/// Offset between TAI and GPS time (i.e., MISP time), which is constant
+pub const TAI_MINUS_GPS_SECONDS: i64 = 19;
+/// Length, in bytes, used when encoding extended heights (IMAP)
+pub const ALT_EXTENDED_LEN: usize = 3;
+pub const RANGE_TO_RECOVERY_MAX: f64 = 21_000.0;
+/// Length, in bytes, used when encoding the range to recovery location (IMAP)
+pub const RANGE_TO_RECOVERY_LEN: usize = 3;
+pub const COURSE_ANGLE_MAX: f64 = 360.0;
+/// Length, in bytes, used when encoding the platform course angle (IMAP)
+pub const COURSE_ANGLE_LEN: usize = 3;
+pub const SENSOR_RATE_MIN: f64 = -1000.0;
+pub const SENSOR_RATE_MAX: f64 = 1000.0;
+/// Length, in bytes, used when encoding sensor angular rates (IMAP)
+pub const SENSOR_RATE_LEN: usize = 3;
+pub const WAVELENGTH_MAX: f64 = 1e9;
+/// Length, in bytes, of the wavelength bounds within a [`crate::misb0601::Wavelength`] (IMAP)
+pub const WAVELENGTH_LEN: usize = 4;
+/// Length, in bytes, of the height within a [`crate::misb0601::WaypointLocation`] (IMAP)
+pub const WAYPOINT_HAE_LEN: usize = 3;
+pub const PERCENTAGE_MAX: f64 = 100.0;
+/// Length, in bytes, used when encoding percentages (IMAP)
+pub const PERCENTAGE_LEN: usize = 2;
+/// Length, in bytes, of each start and range within a [`crate::misb0601::ViewDomainInterval`] (IMAP)
+pub const VIEW_DOMAIN_LEN: usize = 3;
+/// Maximum length, in bytes, of the active payloads bitmap
+pub const ACTIVE_PAYLOADS_MAX_LEN: usize = 128;
+/// Maximum payload ID which the active payloads bitmap can represent
+pub const ACTIVE_PAYLOAD_ID_MAX: u64 = (ACTIVE_PAYLOADS_MAX_LEN * 8 - 1) as u64;
+
+// --------------------------------------------------
+// static
+// --------------------------------------------------
+/// 3 byte-precision in range [-900, 40_000]
+/// 
+/// Used for extended altitudes / heights (MSL / HAE)
+/// 
+/// Units: Meters (m)
+pub(crate) static IMAPB_N900_40K_3_F64: std::sync::LazyLock<crate::misb1201::ImapB<f64>> = std::sync::LazyLock::new(|| {
+    crate::misb1201::ImapB::new(ALT_EXTENDED_MIN, ALT_EXTENDED_MAX, ALT_EXTENDED_LEN).unwrap()
+});
+
+/// 3 byte-precision in range [0, 21_000]
+/// 
+/// Used for the range to recovery location
+/// 
+/// Units: Kilometers (km)
+pub(crate) static IMAPB_0_21K_3_F64: std::sync::LazyLock<crate::misb1201::ImapB<f64>> = std::sync::LazyLock::new(|| {
+    crate::misb1201::ImapB::new(0.0, RANGE_TO_RECOVERY_MAX, RANGE_TO_RECOVERY_LEN).unwrap()
+});
+
+/// 3 byte-precision in range [0, 360]
+/// 
+/// Used for the platform course angle
+/// 
+/// Units: Degrees (°)
+pub(crate) static IMAPB_0_360_3_F64: std::sync::LazyLock<crate::misb1201::ImapB<f64>> = std::sync::LazyLock::new(|| {
+    crate::misb1201::ImapB::new(0.0, COURSE_ANGLE_MAX, COURSE_ANGLE_LEN).unwrap()
+});
+
+/// 3 byte-precision in range [-1000, 1000]
+/// 
+/// Used for sensor angular rates
+/// 
+/// Units: Degrees per second (°/s)
+pub(crate) static IMAPB_N1K_1K_3_F64: std::sync::LazyLock<crate::misb1201::ImapB<f64>> = std::sync::LazyLock::new(|| {
+    crate::misb1201::ImapB::new(SENSOR_RATE_MIN, SENSOR_RATE_MAX, SENSOR_RATE_LEN).unwrap()
+});
+
+/// 4 byte-precision in range [0, 1e9]
+/// 
+/// Used for wavelength bounds
+/// 
+/// Units: Nanometers (nm)
+pub(crate) static IMAPB_0_1E9_4_F64: std::sync::LazyLock<crate::misb1201::ImapB<f64>> = std::sync::LazyLock::new(|| {
+    crate::misb1201::ImapB::new(0.0, WAVELENGTH_MAX, WAVELENGTH_LEN).unwrap()
+});
+
+/// 3 byte-precision in range [-900, 19_000]
+/// 
+/// Used for waypoint HAE (height above ellipsoid)
+/// 
+/// Units: Meters (m)
+pub(crate) static IMAPB_N900_19K_3_F64: std::sync::LazyLock<crate::misb1201::ImapB<f64>> = std::sync::LazyLock::new(|| {
+    crate::misb1201::ImapB::new(-900.0, 19_000.0, WAYPOINT_HAE_LEN).unwrap()
+});
+
+/// 2 byte-precision in range [0, 100]
+/// 
+/// Used for percentages
+/// 
+/// Units: Percent (%)
+pub(crate) static IMAPB_0_100_2_F64: std::sync::LazyLock<crate::misb1201::ImapB<f64>> = std::sync::LazyLock::new(|| {
+    crate::misb1201::ImapB::new(0.0, PERCENTAGE_MAX, PERCENTAGE_LEN).unwrap()
+});
+
+/// 3 byte-precision in range [0, 360]
+/// 
+/// Used for view domain starts and ranges
+/// 
+/// Units: Degrees (°)
+pub(crate) static IMAPB_0_360_VIEW_F64: std::sync::LazyLock<crate::misb1201::ImapB<f64>> = std::sync::LazyLock::new(|| {
+    crate::misb1201::ImapB::new(0.0, 360.0, VIEW_DOMAIN_LEN).unwrap()
+});
+
+/// 3 byte-precision in range [-180, 180]
+/// 
+/// Used for the view domain elevation start
+/// 
+/// Units: Degrees (°)
+pub(crate) static IMAPB_N180_180_VIEW_F64: std::sync::LazyLock<crate::misb1201::ImapB<f64>> = std::sync::LazyLock::new(|| {
+    crate::misb1201::ImapB::new(-180.0, 180.0, VIEW_DOMAIN_LEN).unwrap()
+});
 
 #[inline(always)]
 #[cfg(feature = "misb0601-19")]
@@ -113,6 +240,30 @@ pub const fn from_precision_timestamp(input: chrono::DateTime<chrono::Utc>) -> [
     (seconds * 1_000_000 + nanoseconds / 1_000).to_be_bytes()
 }
 
+#[cfg(feature = "misb0601-19")]
+/// Same as [`to_precision_timestamp`], but for timestamps of
+/// length `len`, with a maximum of 8 bytes
+/// 
+/// See [`crate::misb0601::Misb0601::take_off_time`]
+pub fn to_timestamp_lengthed(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<chrono::DateTime<chrono::Utc>> {
+    move |input: &mut &[u8]| {
+        let checkpoint = input.checkpoint();
+        // time in microseconds
+        let ts = tinyklv::codecs::binary::dec::be_u64_lengthed(len)(input)?;
+        // time in seconds, time in nanoseconds
+        let (ts, tns) = (ts / 1_000_000, (ts % 1_000_000) * 1_000);
+        // convert to UTC
+        match chrono::DateTime::from_timestamp(ts as i64, tns as u32) {
+            Some(dt) => Ok(dt),
+            None => Err(tinyklv::err!().add_context(
+                input,
+                &checkpoint,
+                winnow::error::StrContext::Label("Invalid timestamp")
+            )),
+        }
+    }
+}
+
 #[inline(always)]
 #[cfg(feature = "misb0601-19")]
 /// See [`crate::misb0601::Misb0601`]
@@ -135,7 +286,7 @@ pub fn to_lat(input: &mut &[u8]) -> winnow::PResult<f64> {
 /// This encoder cannot be `const fn` due to floating point arithmetic
 pub fn from_lat(input: f64) -> [u8; 4] {
     let output = input * SFT_2_LAT;
-    (output as i32).to_be_bytes()
+    (output.round() as i32).to_be_bytes()
 }
 
 #[inline(always)]
@@ -160,7 +311,7 @@ pub fn to_lon(input: &mut &[u8]) -> winnow::PResult<f64> {
 /// This encoder cannot be `const fn` due to floating point arithmetic
 pub fn from_lon(input: f64) -> [u8; 4] {
     let output = input * SFT_2_LON;
-    (output as i32).to_be_bytes()
+    (output.round() as i32).to_be_bytes()
 }
 
 #[inline(always)]
@@ -184,7 +335,7 @@ pub fn to_alt(input: &mut &[u8]) -> winnow::PResult<f32> {
 /// This encoder cannot be `const fn` due to floating point arithmetic
 pub fn from_alt(input: f32) -> [u8; 2] {
     let output = (input + SENSOR_TRUE_ALT_OFFSET_P2) / KLV_2_SENSOR_TRUE_ALT_P1;
-    (output as u16).to_be_bytes()
+    (output.round() as u16).to_be_bytes()
 }
 
 #[cfg(feature = "misb0601-19")]
@@ -202,7 +353,7 @@ pub const to_platform_heading_angle: fn(&mut &[u8]) -> winnow::PResult<f32> = ti
 /// This encoder cannot be `const fn` due to floating point arithmetic
 pub fn from_platform_heading_angle(input: f32) -> [u8; 2] {
     let output = input * SFT_2_PLATFORM_HEADING;
-    (output as u16).to_be_bytes()
+    (output.round() as u16).to_be_bytes()
 }
 
 #[inline(always)]
@@ -221,7 +372,7 @@ pub fn to_platform_pitch_angle(input: &mut &[u8]) -> winnow::PResult<f32> {
 /// This encoder cannot be `const fn` due to floating point arithmetic
 pub fn from_platform_pitch_angle(input: f32) -> [u8; 2] {
     let output = input * SFT_2_PLATFORM_PITCH;
-    (output as i16).to_be_bytes()
+    (output.round() as i16).to_be_bytes()
 }
 
 #[inline(always)]
@@ -240,7 +391,7 @@ pub fn to_platform_roll_angle(input: &mut &[u8]) -> winnow::PResult<f32> {
 /// This encoder cannot be `const fn` due to floating point arithmetic
 pub fn from_platform_roll_angle(input: f32) -> [u8; 2] {
     let output = input * SFT_2_PLATFORM_ROLL;
-    (output as i16).to_be_bytes()
+    (output.round() as i16).to_be_bytes()
 }
 
 #[cfg(feature = "misb0601-19")]
@@ -264,7 +415,7 @@ pub const to_sensor_hvfov: fn(&mut &[u8]) -> winnow::PResult<f32> = tinyklv::sca
 /// This encoder cannot be `const fn` due to floating point arithmetic
 pub fn from_sensor_hvfov(input: f32) -> [u8; 2] {
     let output = input * SFT_2_SENSOR_HVFOV;
-    (output as u16).to_be_bytes()
+    (output.round() as u16).to_be_bytes()
 }
 
 #[cfg(feature = "misb0601-19")]
@@ -284,7 +435,7 @@ pub const to_sensor_relative_azimuth_angle: fn(&mut &[u8]) -> winnow::PResult<f6
 /// This encoder cannot be `const fn` due to floating point arithmetic
 pub fn from_sensor_relative_azimuth_angle(input: f64) -> [u8; 4] {
     let output = input * SFT_2_SENSOR_REL_AZM_RLL_ANGLE;
-    (output as u32).to_be_bytes()
+    (output.round() as u32).to_be_bytes()
 }
 
 #[cfg(feature = "misb0601-19")]
@@ -302,7 +453,7 @@ pub const to_sensor_relative_elevation_angle: fn(&mut &[u8]) -> winnow::PResult<
 /// This encoder cannot be `const fn` due to floating point arithmetic
 pub fn from_sensor_relative_elevation_angle(input: f64) -> [u8; 4] {
     let output = input * SFT_2_SENSOR_REL_ELV_ANGLE;
-    (output as i32).to_be_bytes()
+    (output.round() as i32).to_be_bytes()
 }
 
 #[cfg(feature = "misb0601-19")]
@@ -322,7 +473,7 @@ pub const to_sensor_relative_roll_angle: fn(&mut &[u8]) -> winnow::PResult<f64>
 /// This encoder cannot be `const fn` due to floating point arithmetic
 pub fn from_sensor_relative_roll_angle(input: f64) -> [u8; 4] {
     let output = input * SFT_2_SENSOR_REL_AZM_RLL_ANGLE;
-    (output as u32).to_be_bytes()
+    (output.round() as u32).to_be_bytes()
 }
 
 #[cfg(feature = "misb0601-19")]
@@ -340,7 +491,7 @@ pub const to_slant_range: fn(&mut &[u8]) -> winnow::PResult<f64> = tinyklv::scal
 /// This encoder cannot be `const fn` due to floating point arithmetic
 pub fn from_slant_range(input: f64) -> [u8; 4] {
     let output = input * SFT_2_SLANT_RANGE;
-    (output as u32).to_be_bytes()
+    (output.round() as u32).to_be_bytes()
 }
 
 #[cfg(feature = "misb0601-19")]
@@ -358,7 +509,7 @@ pub const to_target_width: fn(&mut &[u8]) -> winnow::PResult<f32> = tinyklv::sca
 /// This encoder cannot be `const fn` due to floating point arithmetic
 pub fn from_target_width(input: f32) -> [u8; 2] {
     let output = input * SFT_2_TARGET_WIDTH;
-    (output as u16).to_be_bytes()
+    (output.round() as u16).to_be_bytes()
 }
 
 #[cfg(feature = "misb0601-19")]
@@ -394,7 +545,7 @@ pub const to_offset_ll: fn(&mut &[u8]) -> winnow::PResult<f32> = tinyklv::scale!
 /// This encoder cannot be `const fn` due to floating point arithmetic
 pub fn from_offset_ll(input: f32) -> [u8; 2] {
     let output = input * SFT_2_OFFSET_LL;
-    (output as i16).to_be_bytes()
+    (output.round() as i16).to_be_bytes()
 }
 
 #[inline(always)]
@@ -437,7 +588,7 @@ pub const to_wind_direction: fn(&mut &[u8]) -> winnow::PResult<f32> = tinyklv::s
 /// This encoder cannot be `const fn` due to floating point arithmetic
 pub fn from_wind_direction(input: f32) -> [u8; 2] {
     let output = input * SFT_2_WIND_DIRECTION;
-    (output as i16).to_be_bytes()
+    (output.round() as u16).to_be_bytes()
 }
 
 #[cfg(feature = "misb0601-19")]
@@ -453,9 +604,9 @@ pub const to_wind_speed: fn(&mut &[u8]) -> winnow::PResult<f32> = tinyklv::scale
 /// See [`crate::misb0601::Misb0601::wind_speed`]
 /// 
 /// This encoder cannot be `const fn` due to floating point arithmetic
-pub fn from_wind_speed(input: f32) -> [u8; 2] {
+pub fn from_wind_speed(input: f32) -> [u8; 1] {
     let output = input * SFT_2_WIND_SPEED;
-    (output as i16).to_be_bytes()
+    (output.round() as u8).to_be_bytes()
 }
 
 #[cfg(feature = "misb0601-19")]
@@ -463,6 +614,7 @@ pub fn from_wind_speed(input: f32) -> [u8; 2] {
 /// 
 /// * [`crate::misb0601::Misb0601::static_pressure`]
 /// * [`crate::misb0601::Misb0601::differential_pressure`]
+/// * [`crate::misb0601::Misb0601::airfield_barometric_pressure`]
 pub const to_mbar_pressure: fn(&mut &[u8]) -> winnow::PResult<f32> = tinyklv::scale!(
     tinyklv::codecs::binary::dec::be_u16,
     f32,
@@ -475,11 +627,12 @@ pub const to_mbar_pressure: fn(&mut &[u8]) -> winnow::PResult<f32> = tinyklv::sc
 /// 
 /// * [`crate::misb0601::Misb0601::static_pressure`]
 /// * [`crate::misb0601::Misb0601::differential_pressure`]
+/// * [`crate::misb0601::Misb0601::airfield_barometric_pressure`]
 /// 
 /// This encoder cannot be `const fn` due to floating point arithmetic
 pub fn from_mbar_pressure(input: f32) -> [u8; 2] {
     let output = input * SFT_2_MBAR_PRESSURE;
-    (output as i16).to_be_bytes()
+    (output.round() as u16).to_be_bytes()
 }
 
 #[cfg(feature = "misb0601-19")]
@@ -499,8 +652,8 @@ pub const to_target_track_gate_hw: fn(&mut &[u8]) -> winnow::PResult<u16> = tiny
 /// 
 /// * [`crate::misb0601::Misb0601::target_track_gate_width`]
 /// * [`crate::misb0601::Misb0601::target_track_gate_height`]
-pub const fn from_target_track_gate_hw(input: u16) -> [u8; 2] {
-    (input >> 1).to_be_bytes()
+pub const fn from_target_track_gate_hw(input: u16) -> [u8; 1] {
+    ((input >> 1) as u8).to_be_bytes()
 }
 
 #[cfg(feature = "misb0601-19")]
@@ -524,7 +677,7 @@ pub const to_error_estimate: fn(&mut &[u8]) -> winnow::PResult<f32> = tinyklv::s
 /// This encoder cannot be `const fn` due to floating point arithmetic
 pub fn from_error_estimate(input: f32) -> [u8; 2] {
     let output = input * SFT_2_ERROR_ESTIMATE;
-    (output as u16).to_be_bytes()
+    (output.round() as u16).to_be_bytes()
 }
 
 #[inline(always)]
@@ -543,5 +696,335 @@ pub fn to_platform_vertical_speed(input: &mut &[u8]) -> winnow::PResult<f32> {
 /// This encoder cannot be `const fn` due to floating point arithmetic
 pub fn from_platform_vertical_speed(input: f32) -> [u8; 2] {
     let output = input * SFT_2_PLATFORM_VERT_SPEED;
-    (output as i16).to_be_bytes()
-}
\ No newline at end of file
+    (output.round() as i16).to_be_bytes()
+}
+
+#[cfg(feature = "misb0601-19")]
+/// See [`crate::misb0601::Misb0601::relative_humidity`]
+pub const to_relative_humidity: fn(&mut &[u8]) -> winnow::PResult<f32> = tinyklv::scale!(
+    tinyklv::codecs::binary::dec::be_u8,
+    f32,
+    KLV_2_RELATIVE_HUMIDITY,
+);
+
+#[inline(always)]
+#[cfg(feature = "misb0601-19")]
+/// See [`crate::misb0601::Misb0601::relative_humidity`]
+/// 
+/// This encoder cannot be `const fn` due to floating point arithmetic
+pub fn from_relative_humidity(input: f32) -> [u8; 1] {
+    let output = input * SFT_2_RELATIVE_HUMIDITY;
+    (output.round() as u8).to_be_bytes()
+}
+
+#[cfg(feature = "misb0601-19")]
+/// See [`crate::misb0601::Misb0601::platform_fuel_remaining`]
+pub const to_fuel_remaining: fn(&mut &[u8]) -> winnow::PResult<f32> = tinyklv::scale!(
+    tinyklv::codecs::binary::dec::be_u16,
+    f32,
+    KLV_2_FUEL_REMAINING,
+);
+
+#[inline(always)]
+#[cfg(feature = "misb0601-19")]
+/// See [`crate::misb0601::Misb0601::platform_fuel_remaining`]
+/// 
+/// This encoder cannot be `const fn` due to floating point arithmetic
+pub fn from_fuel_remaining(input: f32) -> [u8; 2] {
+    let output = input * SFT_2_FUEL_REMAINING;
+    (output.round() as u16).to_be_bytes()
+}
+
+#[inline(always)]
+#[cfg(feature = "misb0601-19")]
+/// See [`crate::misb0601::Misb0601`]
+/// 
+/// * [`crate::misb0601::Misb0601::platform_pitch_angle_full`]
+/// * [`crate::misb0601::Misb0601::platform_roll_angle_full`]
+/// * [`crate::misb0601::Misb0601::platform_angle_of_attack_full`]
+pub fn to_platform_angle_full(input: &mut &[u8]) -> winnow::PResult<f64> {
+    let value = tinyklv::codecs::binary::dec::be_i32.parse_next(input)?;
+    if value as u32 == 0x8000_0000 { return Err(tinyklv::err!()) } // "Out of Range" - keep for backwards compatibility
+    Ok((value as f64) * KLV_2_PLATFORM_ANGLE_FULL)
+}
+
+#[inline(always)]
+#[cfg(feature = "misb0601-19")]
+/// See [`crate::misb0601::Misb0601`]
+/// 
+/// * [`crate::misb0601::Misb0601::platform_pitch_angle_full`]
+/// * [`crate::misb0601::Misb0601::platform_roll_angle_full`]
+/// * [`crate::misb0601::Misb0601::platform_angle_of_attack_full`]
+/// 
+/// This encoder cannot be `const fn` due to floating point arithmetic
+pub fn from_platform_angle_full(input: f64) -> [u8; 4] {
+    let output = input * SFT_2_PLATFORM_ANGLE_FULL;
+    (output.round() as i32).to_be_bytes()
+}
+
+#[inline(always)]
+#[cfg(feature = "misb0601-19")]
+/// See [`crate::misb0601::Misb0601::platform_sideslip_angle_full`]
+pub fn to_platform_sideslip_angle_full(input: &mut &[u8]) -> winnow::PResult<f64> {
+    let value = tinyklv::codecs::binary::dec::be_i32.parse_next(input)?;
+    if value as u32 == 0x8000_0000 { return Err(tinyklv::err!()) } // "Out of Range" - keep for backwards compatibility
+    Ok((value as f64) * KLV_2_PLATFORM_SIDESLIP_FULL)
+}
+
+#[inline(always)]
+#[cfg(feature = "misb0601-19")]
+/// See [`crate::misb0601::Misb0601::platform_sideslip_angle_full`]
+/// 
+/// This encoder cannot be `const fn` due to floating point arithmetic
+pub fn from_platform_sideslip_angle_full(input: f64) -> [u8; 4] {
+    let output = input * SFT_2_PLATFORM_SIDESLIP_FULL;
+    (output.round() as i32).to_be_bytes()
+}
+
+#[inline(always)]
+#[cfg(feature = "misb0601-19")]
+/// See [`crate::misb0601::Misb0601`]
+/// 
+/// * [`crate::misb0601::Misb0601::sensor_north_velocity`]
+/// * [`crate::misb0601::Misb0601::sensor_east_velocity`]
+pub fn to_sensor_velocity(input: &mut &[u8]) -> winnow::PResult<f32> {
+    let value = tinyklv::codecs::binary::dec::be_i16.parse_next(input)?;
+    if value == i16::MIN { return Err(tinyklv::err!()) } // "Out of Range"
+    Ok((value as f32) * KLV_2_SENSOR_VELOCITY)
+}
+
+#[inline(always)]
+#[cfg(feature = "misb0601-19")]
+/// See [`crate::misb0601::Misb0601`]
+/// 
+/// * [`crate::misb0601::Misb0601::sensor_north_velocity`]
+/// * [`crate::misb0601::Misb0601::sensor_east_velocity`]
+/// 
+/// This encoder cannot be `const fn` due to floating point arithmetic
+pub fn from_sensor_velocity(input: f32) -> [u8; 2] {
+    let output = input * SFT_2_SENSOR_VELOCITY;
+    (output.round() as i16).to_be_bytes()
+}
+
+/// A signed integer parser for values of length `len`, with a
+/// maximum of 8 bytes, sign-extended from the most significant bit
+/// 
+/// See [`crate::misb0601::Misb0601`]
+/// 
+/// * [`crate::misb0601::Misb0601::leap_seconds`]
+/// * [`crate::misb0601::Misb0601::correction_offset`]
+/// 
+/// # Example
+/// 
+/// ```
+/// use misb::misb0601::ops::to_i64_lengthed;
+/// assert_eq!(to_i64_lengthed(1)(&mut &[0x12][..]), Ok(18));
+/// assert_eq!(to_i64_lengthed(2)(&mut &[0xFF, 0xEE][..]), Ok(-18));
+/// assert_eq!(to_i64_lengthed(3)(&mut &[0x0F, 0x42, 0x40][..]), Ok(1_000_000));
+/// ```
+pub fn to_i64_lengthed(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<i64> {
+    move |input: &mut &[u8]| {
+        if len == 0 || len > 8 { return Err(tinyklv::err!()) }
+        let value = winnow::token::take(len).parse_next(input)?;
+        let value = value.iter().fold(0u64, |acc, byte| (acc << 8) | *byte as u64);
+        let shift = 64 - 8 * len as u32;
+        Ok(((value << shift) as i64) >> shift)
+    }
+}
+
+#[cfg(feature = "misb0601-19")]
+/// See [`crate::misb0601::Misb0601::leap_seconds`]
+pub fn to_leap_seconds(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<i32> {
+    move |input: &mut &[u8]| {
+        let value = to_i64_lengthed(len)(input)?;
+        i32::try_from(value).map_err(|_| tinyklv::err!())
+    }
+}
+
+/// A dynamically sized [`crate::misb1201::ImapB`] parser for values
+/// of length `len`, in the range [`min`, `max`]
+/// 
+/// Used for items whose length is chosen by the encoder
+pub fn to_imapb(min: f64, max: f64, len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<f64> {
+    move |input: &mut &[u8]| {
+        let checkpoint = input.checkpoint();
+        let imap = match crate::misb1201::ImapB::new(min, max, len) {
+            Ok(x) => x,
+            Err(e) => return Err(tinyklv::err!().add_context(input, &checkpoint, e.into())),
+        };
+        let value = winnow::token::take(len).parse_next(input)?;
+        imap.from_imap(value).map_err(|e| tinyklv::err!().add_context(input, &checkpoint, e.into()))
+    }
+}
+
+/// Encodes a value using a [`crate::misb1201::ImapB`]
+/// 
+/// NaN, infinite, and out of range values are encoded as MISB ST 1201
+/// special values, so the output is never empty
+/// 
+/// See [`crate::misb1201::ImapB::to_imap_or_special`]
+pub(crate) fn from_imapb(imap: &crate::misb1201::ImapB<f64>, input: f64) -> Vec<u8> {
+    imap.to_imap_or_special(input)
+}
+
+#[cfg(feature = "misb0601-19")]
+/// See [`crate::misb0601::Misb0601`]
+/// 
+/// * [`crate::misb0601::Misb0601::density_altitude_extended`]
+/// * [`crate::misb0601::Misb0601::sensor_ellipsoid_height_extended`]
+/// * [`crate::misb0601::Misb0601::alternate_platform_ellipsoid_height_extended`]
+/// * [`crate::misb0601::Misb0601::altitude_agl`]
+/// * [`crate::misb0601::Misb0601::radar_altimeter`]
+pub fn to_alt_extended(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<f64> {
+    to_imapb(ALT_EXTENDED_MIN, ALT_EXTENDED_MAX, len)
+}
+
+#[inline(always)]
+#[cfg(feature = "misb0601-19")]
+/// See [`crate::misb0601::Misb0601`]
+/// 
+/// * [`crate::misb0601::Misb0601::density_altitude_extended`]
+/// * [`crate::misb0601::Misb0601::sensor_ellipsoid_height_extended`]
+/// * [`crate::misb0601::Misb0601::alternate_platform_ellipsoid_height_extended`]
+/// * [`crate::misb0601::Misb0601::altitude_agl`]
+/// * [`crate::misb0601::Misb0601::radar_altimeter`]
+/// 
+/// Always encoded using [`ALT_EXTENDED_LEN`] bytes
+pub fn from_alt_extended(input: f64) -> Vec<u8> {
+    from_imapb(&IMAPB_N900_40K_3_F64, input)
+}
+
+#[cfg(feature = "misb0601-19")]
+/// See [`crate::misb0601::Misb0601::range_to_recovery_location`]
+pub fn to_range_to_recovery(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<f64> {
+    to_imapb(0.0, RANGE_TO_RECOVERY_MAX, len)
+}
+
+#[inline(always)]
+#[cfg(feature = "misb0601-19")]
+/// See [`crate::misb0601::Misb0601::range_to_recovery_location`]
+/// 
+/// Always encoded using [`RANGE_TO_RECOVERY_LEN`] bytes
+pub fn from_range_to_recovery(input: f64) -> Vec<u8> {
+    from_imapb(&IMAPB_0_21K_3_F64, input)
+}
+
+#[cfg(feature = "misb0601-19")]
+/// See [`crate::misb0601::Misb0601::platform_course_angle`]
+pub fn to_course_angle(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<f64> {
+    to_imapb(0.0, COURSE_ANGLE_MAX, len)
+}
+
+#[inline(always)]
+#[cfg(feature = "misb0601-19")]
+/// See [`crate::misb0601::Misb0601::platform_course_angle`]
+/// 
+/// Always encoded using [`COURSE_ANGLE_LEN`] bytes
+pub fn from_course_angle(input: f64) -> Vec<u8> {
+    from_imapb(&IMAPB_0_360_3_F64, input)
+}
+
+#[cfg(feature = "misb0601-19")]
+/// See [`crate::misb0601::Misb0601`]
+/// 
+/// * [`crate::misb0601::Misb0601::control_command_verification_list`]
+/// * [`crate::misb0601::Misb0601::active_wavelength_list`]
+/// 
+/// A list of BER-OID encoded IDs, of total length `len`
+pub fn to_ber_oid_list(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<Vec<u64>> {
+    move |input: &mut &[u8]| {
+        let mut value = winnow::token::take(len).parse_next(input)?;
+        winnow::combinator::repeat(0.., tinyklv::codecs::ber::dec::ber_oid::<u64>).parse_next(&mut value)
+    }
+}
+
+#[inline(always)]
+#[cfg(feature = "misb0601-19")]
+/// See [`crate::misb0601::Misb0601`]
+/// 
+/// * [`crate::misb0601::Misb0601::control_command_verification_list`]
+/// * [`crate::misb0601::Misb0601::active_wavelength_list`]
+pub fn from_ber_oid_list(input: &[u64]) -> Vec<u8> {
+    input.iter().flat_map(tinyklv::codecs::ber::enc::ber_oid).collect()
+}
+
+#[cfg(feature = "misb0601-19")]
+/// See [`crate::misb0601::Misb0601`]
+/// 
+/// * [`crate::misb0601::Misb0601::sensor_azimuth_rate`]
+/// * [`crate::misb0601::Misb0601::sensor_elevation_rate`]
+/// * [`crate::misb0601::Misb0601::sensor_roll_rate`]
+pub fn to_sensor_rate(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<f64> {
+    to_imapb(SENSOR_RATE_MIN, SENSOR_RATE_MAX, len)
+}
+
+#[inline(always)]
+#[cfg(feature = "misb0601-19")]
+/// See [`crate::misb0601::Misb0601`]
+/// 
+/// * [`crate::misb0601::Misb0601::sensor_azimuth_rate`]
+/// * [`crate::misb0601::Misb0601::sensor_elevation_rate`]
+/// * [`crate::misb0601::Misb0601::sensor_roll_rate`]
+/// 
+/// Always encoded using [`SENSOR_RATE_LEN`] bytes
+pub fn from_sensor_rate(input: f64) -> Vec<u8> {
+    from_imapb(&IMAPB_N1K_1K_3_F64, input)
+}
+
+#[cfg(feature = "misb0601-19")]
+/// See [`crate::misb0601::Misb0601::active_payloads`]
+/// 
+/// A bitmap of total length `len`, where the least significant bit
+/// of the last byte corresponds to payload ID 0. Decoded into the
+/// list of active payload IDs, in ascending order
+/// 
+/// Fails if `len` is greater than [`ACTIVE_PAYLOADS_MAX_LEN`]
+pub fn to_active_payloads(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<Vec<u64>> {
+    move |input: &mut &[u8]| {
+        if len > ACTIVE_PAYLOADS_MAX_LEN { return Err(tinyklv::err!()) }
+        let value = winnow::token::take(len).parse_next(input)?;
+        Ok(value
+            .iter()
+            .rev()
+            .enumerate()
+            .flat_map(|(i, byte)| (0..8).filter(move |bit| (byte >> bit) & 1 == 1).map(move |bit| (i * 8 + bit) as u64))
+            .collect())
+    }
+}
+
+#[cfg(feature = "misb0601-19")]
+/// See [`crate::misb0601::Misb0601::active_payloads`]
+/// 
+/// Encoded using the minimum number of bytes, with at least one byte.
+/// IDs greater than [`ACTIVE_PAYLOAD_ID_MAX`] cannot be represented,
+/// and are skipped
+pub fn from_active_payloads(input: &[u64]) -> Vec<u8> {
+    let ids = input.iter().filter(|id| **id <= ACTIVE_PAYLOAD_ID_MAX).map(|id| *id as usize);
+    let len = ids.clone().max().map_or(1, |max| (max / 8) + 1);
+    let mut output = vec![0u8; len];
+    for id in ids {
+        output[len - 1 - id / 8] |= 1 << (id % 8);
+    }
+    output
+}
+
+#[cfg(feature = "misb0601-19")]
+/// See [`crate::misb0601::Misb0601`]
+/// 
+/// * [`crate::misb0601::Misb0601::onboard_storage_percent_full`]
+/// * [`crate::misb0601::Misb0601::zoom_percentage`]
+pub fn to_percentage(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<f64> {
+    to_imapb(0.0, PERCENTAGE_MAX, len)
+}
+
+#[inline(always)]
+#[cfg(feature = "misb0601-19")]
+/// See [`crate::misb0601::Misb0601`]
+/// 
+/// * [`crate::misb0601::Misb0601::onboard_storage_percent_full`]
+/// * [`crate::misb0601::Misb0601::zoom_percentage`]
+/// 
+/// Always encoded using [`PERCENTAGE_LEN`] bytes
+pub fn from_percentage(input: f64) -> Vec<u8> {
+    from_imapb(&IMAPB_0_100_2_F64, input)
+}
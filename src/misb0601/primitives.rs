@@ -0,0 +1,1086 @@
+// --------------------------------------------------
+// tinyklv
+// --------------------------------------------------
+use tinyklv::prelude::*;
+
+// --------------------------------------------------
+// external
+// --------------------------------------------------
+use thisenum::Const;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Current weapons stored on aircraft, broken into
+/// station, substation, type, and variant
+/// 
+/// Each is a 4-bit value, packed into two bytes in the
+/// order: station, substation, type, variant
+/// 
+/// See [`crate::misb0601::Misb0601::weapon_load`]
+pub struct WeaponLoad {
+    /// Wing or aircraft body station number
+    pub station: u8,
+    /// Substation number, within the station
+    pub substation: u8,
+    /// Weapon type
+    pub weapon_type: u8,
+    /// Weapon variant
+    pub weapon_variant: u8,
+}
+/// [`WeaponLoad`] implementation of [`tinyklv::prelude::Decode`]
+impl tinyklv::prelude::Decode<&[u8]> for WeaponLoad {
+    fn decode(input: &mut &[u8]) -> winnow::PResult<Self> {
+        let value = tinyklv::codecs::binary::dec::be_u16.parse_next(input)?;
+        Ok(Self {
+            station: ((value >> 12) & 0x0F) as u8,
+            substation: ((value >> 8) & 0x0F) as u8,
+            weapon_type: ((value >> 4) & 0x0F) as u8,
+            weapon_variant: (value & 0x0F) as u8,
+        })
+    }
+}
+/// [`WeaponLoad`] implementation of [`tinyklv::prelude::Encode`]
+impl tinyklv::prelude::Encode<Vec<u8>> for WeaponLoad {
+    fn encode(&self) -> Vec<u8> {
+        vec![
+            (self.station & 0x0F) << 4 | (self.substation & 0x0F),
+            (self.weapon_type & 0x0F) << 4 | (self.weapon_variant & 0x0F),
+        ]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Indication when a particular weapon is released, broken
+/// into station and substation
+/// 
+/// Each is a 4-bit value, packed into a single byte in the
+/// order: station, substation
+/// 
+/// See [`crate::misb0601::Misb0601::weapon_fired`]
+pub struct WeaponFired {
+    /// Wing or aircraft body station number
+    pub station: u8,
+    /// Substation number, within the station
+    pub substation: u8,
+}
+/// [`WeaponFired`] implementation of [`tinyklv::prelude::Decode`]
+impl tinyklv::prelude::Decode<&[u8]> for WeaponFired {
+    fn decode(input: &mut &[u8]) -> winnow::PResult<Self> {
+        let value = tinyklv::codecs::binary::dec::be_u8.parse_next(input)?;
+        Ok(Self {
+            station: (value >> 4) & 0x0F,
+            substation: value & 0x0F,
+        })
+    }
+}
+/// [`WeaponFired`] implementation of [`tinyklv::prelude::Encode`]
+impl tinyklv::prelude::Encode<Vec<u8>> for WeaponFired {
+    fn encode(&self) -> Vec<u8> {
+        vec![(self.station & 0x0F) << 4 | (self.substation & 0x0F)]
+    }
+}
+
+#[derive(Const)]
+#[armtype(u8)]
+/// Sensor Field of View Name
+/// 
+/// See [`crate::misb0601::Misb0601::sensor_fov_name`]
+pub enum SensorFovName {
+    #[value = 0]
+    Ultranarrow,
+    #[value = 1]
+    Narrow,
+    #[value = 2]
+    Medium,
+    #[value = 3]
+    Wide,
+    #[value = 4]
+    Ultrawide,
+    #[value = 5]
+    NarrowMedium,
+    #[value = 6]
+    /// 2x Ultranarrow
+    Ultranarrow2x,
+    #[value = 7]
+    /// 4x Ultranarrow
+    Ultranarrow4x,
+    #[value = 8]
+    ContinuousZoom,
+}
+/// [`SensorFovName`] implementation of [`tinyklv::prelude::Decode`]
+impl tinyklv::prelude::Decode<&[u8]> for SensorFovName {
+    fn decode(input: &mut &[u8]) -> winnow::PResult<Self> {
+        Self::try_from(tinyklv::codecs::binary::dec::be_u8.parse_next(input)?).map_err(|_| tinyklv::err!())
+    }
+}
+/// [`SensorFovName`] implementation of [`tinyklv::prelude::Encode`]
+impl tinyklv::prelude::Encode<Vec<u8>> for SensorFovName {
+    fn encode(&self) -> Vec<u8> {
+        vec![*self.value()]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A command sent to the platform, as a Variable-Length Pack
+/// (VLP) of an ID, the command string, and an optional time
+/// 
+/// See [`crate::misb0601::Misb0601::control_command`]
+pub struct ControlCommand {
+    /// Unique identifier of the command, used by the
+    /// command verification list
+    pub id: u64,
+    /// The command itself
+    pub command: String,
+    /// Time the command was issued
+    pub timestamp: Option<chrono::DateTime<chrono::Utc>>,
+}
+/// [`ControlCommand`] implementation
+impl ControlCommand {
+    /// Decodes a control command from a stream, where `len` is
+    /// the length of the entire pack
+    pub fn decode(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<Self> {
+        move |input| {
+            let mut value = winnow::token::take(len).parse_next(input)?;
+            let id_len = tinyklv::codecs::ber::dec::ber_length.parse_next(&mut value)?;
+            let mut id = winnow::token::take(id_len).parse_next(&mut value)?;
+            let id = tinyklv::codecs::ber::dec::ber_oid::<u64>.parse_next(&mut id)?;
+            let command_len = tinyklv::codecs::ber::dec::ber_length.parse_next(&mut value)?;
+            let command = tinyklv::codecs::binary::dec::to_string_utf8(command_len).parse_next(&mut value)?;
+            let timestamp = match value.is_empty() {
+                true => None,
+                false => {
+                    let timestamp_len = tinyklv::codecs::ber::dec::ber_length.parse_next(&mut value)?;
+                    Some(crate::misb0601::ops::to_timestamp_lengthed(timestamp_len).parse_next(&mut value)?)
+                },
+            };
+            Ok(Self { id, command, timestamp })
+        }
+    }
+}
+/// [`ControlCommand`] implementation of [`tinyklv::prelude::Encode`]
+impl tinyklv::prelude::Encode<Vec<u8>> for ControlCommand {
+    fn encode(&self) -> Vec<u8> {
+        let mut output = Vec::new();
+        let id = tinyklv::codecs::ber::enc::ber_oid(&self.id);
+        output.extend(tinyklv::codecs::ber::enc::ber_length(&id.len()));
+        output.extend(id);
+        output.extend(tinyklv::codecs::ber::enc::ber_length(&self.command.len()));
+        output.extend_from_slice(self.command.as_bytes());
+        if let Some(timestamp) = self.timestamp {
+            output.extend(tinyklv::codecs::ber::enc::ber_length(&8));
+            output.extend(crate::misb0601::ops::from_precision_timestamp(timestamp));
+        }
+        output
+    }
+}
+
+#[derive(Const)]
+#[armtype(u8)]
+/// Sensor Control Mode
+/// 
+/// See [`crate::misb0601::Misb0601::sensor_control_mode`]
+pub enum SensorControlMode {
+    #[value = 0]
+    Off,
+    #[value = 1]
+    HomePosition,
+    #[value = 2]
+    Uncontrolled,
+    #[value = 3]
+    ManualControl,
+    #[value = 4]
+    Calibrating,
+    #[value = 5]
+    AutoHoldingPosition,
+    #[value = 6]
+    AutoTracking,
+}
+/// [`SensorControlMode`] implementation of [`tinyklv::prelude::Decode`]
+impl tinyklv::prelude::Decode<&[u8]> for SensorControlMode {
+    fn decode(input: &mut &[u8]) -> winnow::PResult<Self> {
+        Self::try_from(tinyklv::codecs::binary::dec::be_u8.parse_next(input)?).map_err(|_| tinyklv::err!())
+    }
+}
+/// [`SensorControlMode`] implementation of [`tinyklv::prelude::Encode`]
+impl tinyklv::prelude::Encode<Vec<u8>> for SensorControlMode {
+    fn encode(&self) -> Vec<u8> {
+        vec![*self.value()]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A wavelength record, describing a band of the
+/// electromagnetic spectrum used by a sensor
+/// 
+/// Encoded as a length-prefixed pack of an ID (BER-OID), the
+/// minimum and maximum wavelengths (IMAP), and a name
+/// 
+/// IDs 1 through 6 are reserved for the default wavelengths
+/// (VIS, IR, NIR, MIR, LIR, FIR), and do not need to be listed
+/// 
+/// See [`crate::misb0601::Misb0601::wavelengths_list`]
+pub struct Wavelength {
+    /// Unique identifier of the wavelength
+    pub id: u64,
+    /// Minimum wavelength
+    /// 
+    /// Units: Nanometers (nm)
+    pub min: f64,
+    /// Maximum wavelength
+    /// 
+    /// Units: Nanometers (nm)
+    pub max: f64,
+    /// Name of the wavelength
+    pub name: String,
+}
+/// [`Wavelength`] implementation
+impl Wavelength {
+    /// Decodes a series of [`Wavelength`] records, of total length `len`
+    pub fn decode_series(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<Vec<Self>> {
+        move |input| {
+            let mut value = winnow::token::take(len).parse_next(input)?;
+            winnow::combinator::repeat(0.., Self::decode).parse_next(&mut value)
+        }
+    }
+}
+/// [`Wavelength`] implementation of [`tinyklv::prelude::Decode`]
+impl tinyklv::prelude::Decode<&[u8]> for Wavelength {
+    fn decode(input: &mut &[u8]) -> winnow::PResult<Self> {
+        let len = tinyklv::codecs::ber::dec::ber_length.parse_next(input)?;
+        let mut value = winnow::token::take(len).parse_next(input)?;
+        let (id, min, max) = (
+            tinyklv::codecs::ber::dec::ber_oid::<u64>,
+            crate::misb0601::ops::to_imapb(0.0, crate::misb0601::ops::WAVELENGTH_MAX, crate::misb0601::ops::WAVELENGTH_LEN),
+            crate::misb0601::ops::to_imapb(0.0, crate::misb0601::ops::WAVELENGTH_MAX, crate::misb0601::ops::WAVELENGTH_LEN),
+        ).parse_next(&mut value)?;
+        let name = tinyklv::codecs::binary::dec::to_string_utf8(value.len()).parse_next(&mut value)?;
+        Ok(Self { id, min, max, name })
+    }
+}
+/// [`Wavelength`] implementation of [`tinyklv::prelude::Encode`]
+impl tinyklv::prelude::Encode<Vec<u8>> for Wavelength {
+    fn encode(&self) -> Vec<u8> {
+        let mut value = tinyklv::codecs::ber::enc::ber_oid(&self.id);
+        value.extend(crate::misb0601::ops::from_imapb(&crate::misb0601::ops::IMAPB_0_1E9_4_F64, self.min));
+        value.extend(crate::misb0601::ops::from_imapb(&crate::misb0601::ops::IMAPB_0_1E9_4_F64, self.max));
+        value.extend_from_slice(self.name.as_bytes());
+        let mut output = tinyklv::codecs::ber::enc::ber_length(&value.len());
+        output.extend(value);
+        output
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A payload record, describing a single payload on the platform
+/// 
+/// Encoded as a length-prefixed pack of an ID (BER-OID), the
+/// type (BER-OID), and a name
+/// 
+/// See [`crate::misb0601::Misb0601::payload_list`]
+pub struct Payload {
+    /// Unique identifier of the payload, used by
+    /// [`crate::misb0601::Misb0601::active_payloads`]
+    pub id: u64,
+    /// Type of the payload
+    pub payload_type: PayloadType,
+    /// Name of the payload
+    pub name: String,
+}
+/// [`Payload`] implementation
+impl Payload {
+    /// Decodes a payload list of total length `len`, which is the
+    /// number of payloads (BER-OID) followed by the [`Payload`] records
+    pub fn decode_list(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<Vec<Self>> {
+        move |input| {
+            let mut value = winnow::token::take(len).parse_next(input)?;
+            let count = tinyklv::codecs::ber::dec::ber_oid::<u64>.parse_next(&mut value)?;
+            // --------------------------------------------------
+            // each record is at least 3 bytes (length, ID, and
+            // type), so the count is bounded by the value
+            // --------------------------------------------------
+            let count = usize::try_from(count)
+                .ok()
+                .filter(|x| x.saturating_mul(3) <= value.len())
+                .ok_or_else(|| tinyklv::err!())?;
+            winnow::combinator::repeat(count, Self::decode).parse_next(&mut value)
+        }
+    }
+
+    /// Encodes a payload list, which is the number of payloads (BER-OID)
+    /// followed by the [`Payload`] records
+    pub fn encode_list(input: &[Self]) -> Vec<u8> {
+        let mut output = tinyklv::codecs::ber::enc::ber_oid(&(input.len() as u64));
+        input.iter().for_each(|payload| output.extend(payload.encode()));
+        output
+    }
+}
+/// [`Payload`] implementation of [`tinyklv::prelude::Decode`]
+impl tinyklv::prelude::Decode<&[u8]> for Payload {
+    fn decode(input: &mut &[u8]) -> winnow::PResult<Self> {
+        let len = tinyklv::codecs::ber::dec::ber_length.parse_next(input)?;
+        let mut value = winnow::token::take(len).parse_next(input)?;
+        let id = tinyklv::codecs::ber::dec::ber_oid::<u64>.parse_next(&mut value)?;
+        let payload_type = u8::try_from(tinyklv::codecs::ber::dec::ber_oid::<u64>.parse_next(&mut value)?)
+            .ok()
+            .and_then(|x| PayloadType::try_from(x).ok())
+            .ok_or_else(|| tinyklv::err!())?;
+        let name = tinyklv::codecs::binary::dec::to_string_utf8(value.len()).parse_next(&mut value)?;
+        Ok(Self { id, payload_type, name })
+    }
+}
+/// [`Payload`] implementation of [`tinyklv::prelude::Encode`]
+impl tinyklv::prelude::Encode<Vec<u8>> for Payload {
+    fn encode(&self) -> Vec<u8> {
+        let mut value = tinyklv::codecs::ber::enc::ber_oid(&self.id);
+        value.extend(tinyklv::codecs::ber::enc::ber_oid(&(*self.payload_type.value() as u64)));
+        value.extend_from_slice(self.name.as_bytes());
+        let mut output = tinyklv::codecs::ber::enc::ber_length(&value.len());
+        output.extend(value);
+        output
+    }
+}
+
+#[derive(Const, Clone, Copy, PartialEq)]
+#[armtype(u8)]
+/// Payload Type
+/// 
+/// See [`Payload`]
+pub enum PayloadType {
+    #[value = 0]
+    /// Electro Optical Motion Imagery sensor, such as
+    /// visible or infrared (see [`Wavelength`])
+    ElectroOptical,
+    #[value = 1]
+    Lidar,
+    #[value = 2]
+    Radar,
+    #[value = 3]
+    Sigint,
+    #[value = 4]
+    /// Synthetic Aperture Radar
+    Sar,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A weapons store record, describing the state of a single
+/// store (i.e., weapon) on the platform
+/// 
+/// Encoded as a length-prefixed pack of the station, hardpoint,
+/// carriage, and store IDs (BER-OID), the general status (BER-OID),
+/// the engagement status (bitfield), and the weapon type
+/// 
+/// See [`crate::misb0601::Misb0601::weapons_stores`]
+pub struct WeaponsStore {
+    /// Wing or aircraft body station number
+    pub station_id: u64,
+    /// Hardpoint number, within the station
+    pub hardpoint_id: u64,
+    /// Carriage number, within the hardpoint
+    pub carriage_id: u64,
+    /// Store number, within the carriage
+    pub store_id: u64,
+    /// General status of the store
+    pub general_status: WeaponGeneralStatus,
+    /// Engagement status of the store
+    pub engagement_status: WeaponEngagementStatus,
+    /// Type of the store (e.g., the weapon name)
+    pub weapon_type: String,
+}
+/// [`WeaponsStore`] implementation
+impl WeaponsStore {
+    /// Decodes a series of [`WeaponsStore`] records, of total length `len`
+    pub fn decode_series(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<Vec<Self>> {
+        move |input| {
+            let mut value = winnow::token::take(len).parse_next(input)?;
+            winnow::combinator::repeat(0.., Self::decode).parse_next(&mut value)
+        }
+    }
+}
+/// [`WeaponsStore`] implementation of [`tinyklv::prelude::Decode`]
+impl tinyklv::prelude::Decode<&[u8]> for WeaponsStore {
+    fn decode(input: &mut &[u8]) -> winnow::PResult<Self> {
+        let len = tinyklv::codecs::ber::dec::ber_length.parse_next(input)?;
+        let mut value = winnow::token::take(len).parse_next(input)?;
+        let (station_id, hardpoint_id, carriage_id, store_id) = (
+            tinyklv::codecs::ber::dec::ber_oid::<u64>,
+            tinyklv::codecs::ber::dec::ber_oid::<u64>,
+            tinyklv::codecs::ber::dec::ber_oid::<u64>,
+            tinyklv::codecs::ber::dec::ber_oid::<u64>,
+        ).parse_next(&mut value)?;
+        let general_status = u8::try_from(tinyklv::codecs::ber::dec::ber_oid::<u64>.parse_next(&mut value)?)
+            .ok()
+            .and_then(|x| WeaponGeneralStatus::try_from(x).ok())
+            .ok_or_else(|| tinyklv::err!())?;
+        let engagement_status = WeaponEngagementStatus::decode(&mut value)?;
+        let weapon_type = tinyklv::codecs::binary::dec::to_string_utf8(value.len()).parse_next(&mut value)?;
+        Ok(Self { station_id, hardpoint_id, carriage_id, store_id, general_status, engagement_status, weapon_type })
+    }
+}
+/// [`WeaponsStore`] implementation of [`tinyklv::prelude::Encode`]
+impl tinyklv::prelude::Encode<Vec<u8>> for WeaponsStore {
+    fn encode(&self) -> Vec<u8> {
+        let mut value = Vec::new();
+        value.extend(tinyklv::codecs::ber::enc::ber_oid(&self.station_id));
+        value.extend(tinyklv::codecs::ber::enc::ber_oid(&self.hardpoint_id));
+        value.extend(tinyklv::codecs::ber::enc::ber_oid(&self.carriage_id));
+        value.extend(tinyklv::codecs::ber::enc::ber_oid(&self.store_id));
+        value.extend(tinyklv::codecs::ber::enc::ber_oid(&(*self.general_status.value() as u64)));
+        value.extend(self.engagement_status.encode());
+        value.extend_from_slice(self.weapon_type.as_bytes());
+        let mut output = tinyklv::codecs::ber::enc::ber_length(&value.len());
+        output.extend(value);
+        output
+    }
+}
+
+#[derive(Const, Clone, Copy, PartialEq)]
+#[armtype(u8)]
+/// General status of a [`WeaponsStore`]
+pub enum WeaponGeneralStatus {
+    #[value = 0]
+    Off,
+    #[value = 1]
+    Initialization,
+    #[value = 2]
+    ReadyDegraded,
+    #[value = 3]
+    ReadyAllUpRound,
+    #[value = 4]
+    Launch,
+    #[value = 5]
+    FreeFlight,
+    #[value = 6]
+    Abort,
+    #[value = 7]
+    MissFire,
+    #[value = 8]
+    HangFire,
+    #[value = 9]
+    Jettisoned,
+    #[value = 10]
+    SteppedOver,
+    #[value = 11]
+    NoStatusAvailable,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Engagement status of a [`WeaponsStore`]
+pub struct WeaponEngagementStatus {
+    /// Indicates whether or not the fuze is enabled
+    pub fuze_enabled: bool,
+    /// Indicates whether or not the laser is enabled
+    pub laser_enabled: bool,
+    /// Indicates whether or not the target is enabled
+    pub target_enabled: bool,
+    /// Indicates whether or not the weapon is armed
+    pub weapon_armed: bool,
+}
+/// [`WeaponEngagementStatus`] implementation of [`tinyklv::prelude::Decode`]
+impl tinyklv::prelude::Decode<&[u8]> for WeaponEngagementStatus {
+    fn decode(input: &mut &[u8]) -> winnow::PResult<Self> {
+        let value = tinyklv::codecs::binary::dec::be_u8.parse_next(input)?;
+        Ok(Self {
+            fuze_enabled: (value >> 0) & 1 == 1,
+            laser_enabled: (value >> 1) & 1 == 1,
+            target_enabled: (value >> 2) & 1 == 1,
+            weapon_armed: (value >> 3) & 1 == 1,
+        })
+    }
+}
+/// [`WeaponEngagementStatus`] implementation of [`tinyklv::prelude::Encode`]
+impl tinyklv::prelude::Encode<Vec<u8>> for WeaponEngagementStatus {
+    fn encode(&self) -> Vec<u8> {
+        vec![(self.fuze_enabled as u8)
+            | (self.laser_enabled as u8) << 1
+            | (self.target_enabled as u8) << 2
+            | (self.weapon_armed as u8) << 3]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A waypoint record, describing a single point of the
+/// planned route
+/// 
+/// Encoded as a length-prefixed pack of the ID (BER-OID), the
+/// prosecution order (int16), the waypoint info (BER-OID
+/// bitfield), and an optional location (IMAP)
+/// 
+/// See [`crate::misb0601::Misb0601::waypoint_list`]
+pub struct Waypoint {
+    /// Unique identifier of the waypoint
+    pub id: u64,
+    /// Order in which the waypoint is to be prosecuted. A
+    /// negative value indicates the waypoint is not part of the
+    /// current route
+    pub order: i16,
+    /// Indicates how the waypoint is flown
+    pub mode: WaypointMode,
+    /// Indicates where the waypoint came from
+    pub source: WaypointSource,
+    /// Location of the waypoint
+    pub location: Option<WaypointLocation>,
+}
+/// [`Waypoint`] implementation
+impl Waypoint {
+    /// Decodes a series of [`Waypoint`] records, of total length `len`
+    pub fn decode_series(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<Vec<Self>> {
+        move |input| {
+            let mut value = winnow::token::take(len).parse_next(input)?;
+            winnow::combinator::repeat(0.., Self::decode).parse_next(&mut value)
+        }
+    }
+}
+/// [`Waypoint`] implementation of [`tinyklv::prelude::Decode`]
+impl tinyklv::prelude::Decode<&[u8]> for Waypoint {
+    fn decode(input: &mut &[u8]) -> winnow::PResult<Self> {
+        let len = tinyklv::codecs::ber::dec::ber_length.parse_next(input)?;
+        let mut value = winnow::token::take(len).parse_next(input)?;
+        let (id, order, info) = (
+            tinyklv::codecs::ber::dec::ber_oid::<u64>,
+            tinyklv::codecs::binary::dec::be_i16,
+            tinyklv::codecs::ber::dec::ber_oid::<u64>,
+        ).parse_next(&mut value)?;
+        let location = match value.is_empty() {
+            true => None,
+            false => Some(WaypointLocation::decode(&mut value)?),
+        };
+        Ok(Self {
+            id,
+            order,
+            mode: match (info >> 0) & 1 == 1 {
+                false => WaypointMode::Automated,
+                true => WaypointMode::Manual,
+            },
+            source: match (info >> 1) & 1 == 1 {
+                false => WaypointSource::PrePlanned,
+                true => WaypointSource::AdHoc,
+            },
+            location,
+        })
+    }
+}
+/// [`Waypoint`] implementation of [`tinyklv::prelude::Encode`]
+impl tinyklv::prelude::Encode<Vec<u8>> for Waypoint {
+    fn encode(&self) -> Vec<u8> {
+        let info = ((self.mode == WaypointMode::Manual) as u64)
+            | ((self.source == WaypointSource::AdHoc) as u64) << 1;
+        let mut value = tinyklv::codecs::ber::enc::ber_oid(&self.id);
+        value.extend(self.order.to_be_bytes());
+        value.extend(tinyklv::codecs::ber::enc::ber_oid(&info));
+        if let Some(location) = &self.location { value.extend(location.encode()) }
+        let mut output = tinyklv::codecs::ber::enc::ber_length(&value.len());
+        output.extend(value);
+        output
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Waypoint is flown automatically or manually
+/// 
+/// See [`Waypoint`]
+pub enum WaypointMode {
+    Automated,
+    Manual,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Waypoint is part of the pre-planned route, or was
+/// added ad-hoc during the mission
+/// 
+/// See [`Waypoint`]
+pub enum WaypointSource {
+    PrePlanned,
+    AdHoc,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Location of a [`Waypoint`]
+pub struct WaypointLocation {
+    /// Units: Degrees (°)
+    pub latitude: f64,
+    /// Units: Degrees (°)
+    pub longitude: f64,
+    /// Height above ellipsoid
+    /// 
+    /// Units: Meters (m)
+    pub hae: f64,
+}
+/// [`WaypointLocation`] implementation of [`tinyklv::prelude::Decode`]
+impl tinyklv::prelude::Decode<&[u8]> for WaypointLocation {
+    fn decode(input: &mut &[u8]) -> winnow::PResult<Self> {
+        let (latitude, longitude, hae) = (
+            crate::misb0903::ops::imapb_parser(&crate::misb0903::ops::IMAPB_N90_90_4_F64, 4),
+            crate::misb0903::ops::imapb_parser(&crate::misb0903::ops::IMAPB_N180_180_4_F64, 4),
+            crate::misb0903::ops::imapb_parser(&crate::misb0601::ops::IMAPB_N900_19K_3_F64, crate::misb0601::ops::WAYPOINT_HAE_LEN),
+        ).parse_next(input)?;
+        Ok(Self { latitude, longitude, hae })
+    }
+}
+/// [`WaypointLocation`] implementation of [`tinyklv::prelude::Encode`]
+impl tinyklv::prelude::Encode<Vec<u8>> for WaypointLocation {
+    fn encode(&self) -> Vec<u8> {
+        let mut output = crate::misb0601::ops::from_imapb(&crate::misb0903::ops::IMAPB_N90_90_4_F64, self.latitude);
+        output.extend(crate::misb0601::ops::from_imapb(&crate::misb0903::ops::IMAPB_N180_180_4_F64, self.longitude));
+        output.extend(crate::misb0601::ops::from_imapb(&crate::misb0601::ops::IMAPB_N900_19K_3_F64, self.hae));
+        output
+    }
+}
+
+#[derive(Debug)]
+/// Country codes of the platform, as a Variable-Length Pack (VLP)
+/// 
+/// Omitted countries are encoded with a length of zero
+/// 
+/// See [`crate::misb0601::Misb0601::country_codes`]
+pub struct CountryCodes {
+    /// Method used to code the countries
+    pub coding_method: crate::misb0102::CountryCodingMethod,
+    /// Country the platform is flying over
+    pub overflight_country: String,
+    /// Country of the platform operator
+    pub operator_country: Option<String>,
+    /// Country the platform was manufactured in
+    pub country_of_manufacture: Option<String>,
+}
+/// [`CountryCodes`] implementation
+impl CountryCodes {
+    /// Decodes country codes from a stream, where `len` is
+    /// the length of the entire pack
+    pub fn decode(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<Self> {
+        move |input| {
+            let mut value = winnow::token::take(len).parse_next(input)?;
+            let coding_method_len = tinyklv::codecs::ber::dec::ber_length.parse_next(&mut value)?;
+            let mut coding_method = winnow::token::take(coding_method_len).parse_next(&mut value)?;
+            let coding_method = u8::try_from(tinyklv::codecs::ber::dec::ber_oid::<u64>.parse_next(&mut coding_method)?)
+                .ok()
+                .and_then(|x| crate::misb0102::CountryCodingMethod::try_from(x).ok())
+                .ok_or_else(|| tinyklv::err!())?;
+            let mut countries = winnow::combinator::repeat(0..=3, |input: &mut &[u8]| {
+                let len = tinyklv::codecs::ber::dec::ber_length.parse_next(input)?;
+                tinyklv::codecs::binary::dec::to_string_utf8(len).parse_next(input)
+            }).parse_next(&mut value).map(|x: Vec<String>| x.into_iter())?;
+            let overflight_country = countries.next().ok_or_else(|| tinyklv::err!())?;
+            let operator_country = countries.next().filter(|x| !x.is_empty());
+            let country_of_manufacture = countries.next().filter(|x| !x.is_empty());
+            Ok(Self { coding_method, overflight_country, operator_country, country_of_manufacture })
+        }
+    }
+}
+/// [`CountryCodes`] implementation of [`tinyklv::prelude::Encode`]
+impl tinyklv::prelude::Encode<Vec<u8>> for CountryCodes {
+    fn encode(&self) -> Vec<u8> {
+        let mut output = Vec::new();
+        let coding_method = tinyklv::codecs::ber::enc::ber_oid(&(*self.coding_method.value() as u64));
+        output.extend(tinyklv::codecs::ber::enc::ber_length(&coding_method.len()));
+        output.extend(coding_method);
+        let mut countries = vec![Some(&self.overflight_country), self.operator_country.as_ref(), self.country_of_manufacture.as_ref()];
+        // --------------------------------------------------
+        // trailing omitted countries are not encoded
+        // --------------------------------------------------
+        while countries.last().is_some_and(|x| x.is_none()) { countries.pop(); }
+        for country in countries {
+            let country = country.map_or("", |x| x.as_str());
+            output.extend(tinyklv::codecs::ber::enc::ber_length(&country.len()));
+            output.extend_from_slice(country.as_bytes());
+        }
+        output
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Source of the navigation positioning information, where
+/// each flag indicates a contributing source
+/// 
+/// See [`crate::misb0601::Misb0601::positioning_method_source`]
+pub struct PositioningMethodSource {
+    /// On-board Inertial Navigation System (INS)
+    pub ins: bool,
+    /// Global Positioning System (GPS)
+    pub gps: bool,
+    /// Galileo
+    pub galileo: bool,
+    /// Quasi-Zenith Satellite System (QZSS)
+    pub qzss: bool,
+    /// Navigation with Indian Constellation (NavIC)
+    pub navic: bool,
+    /// Global Navigation Satellite System (GLONASS)
+    pub glonass: bool,
+    /// BeiDou-1
+    pub beidou_1: bool,
+    /// BeiDou-2
+    pub beidou_2: bool,
+}
+/// [`PositioningMethodSource`] implementation of [`tinyklv::prelude::Decode`]
+impl tinyklv::prelude::Decode<&[u8]> for PositioningMethodSource {
+    fn decode(input: &mut &[u8]) -> winnow::PResult<Self> {
+        let value = tinyklv::codecs::binary::dec::be_u8.parse_next(input)?;
+        Ok(Self {
+            ins: (value >> 0) & 1 == 1,
+            gps: (value >> 1) & 1 == 1,
+            galileo: (value >> 2) & 1 == 1,
+            qzss: (value >> 3) & 1 == 1,
+            navic: (value >> 4) & 1 == 1,
+            glonass: (value >> 5) & 1 == 1,
+            beidou_1: (value >> 6) & 1 == 1,
+            beidou_2: (value >> 7) & 1 == 1,
+        })
+    }
+}
+/// [`PositioningMethodSource`] implementation of [`tinyklv::prelude::Encode`]
+impl tinyklv::prelude::Encode<Vec<u8>> for PositioningMethodSource {
+    fn encode(&self) -> Vec<u8> {
+        vec![(self.ins as u8)
+            | (self.gps as u8) << 1
+            | (self.galileo as u8) << 2
+            | (self.qzss as u8) << 3
+            | (self.navic as u8) << 4
+            | (self.glonass as u8) << 5
+            | (self.beidou_1 as u8) << 6
+            | (self.beidou_2 as u8) << 7]
+    }
+}
+
+#[derive(Const)]
+#[armtype(u8)]
+/// Platform Status
+/// 
+/// See [`crate::misb0601::Misb0601::platform_status`]
+pub enum PlatformStatus {
+    #[value = 0]
+    Active,
+    #[value = 1]
+    PreFlight,
+    #[value = 2]
+    PreFlightTaxiing,
+    #[value = 3]
+    RunUp,
+    #[value = 4]
+    TakeOff,
+    #[value = 5]
+    Ingress,
+    #[value = 6]
+    ManualOperation,
+    #[value = 7]
+    AutomatedOrbit,
+    #[value = 8]
+    Transitioning,
+    #[value = 9]
+    Egress,
+    #[value = 10]
+    Landing,
+    #[value = 11]
+    LandedTaxiing,
+    #[value = 12]
+    LandedParked,
+}
+/// [`PlatformStatus`] implementation of [`tinyklv::prelude::Decode`]
+impl tinyklv::prelude::Decode<&[u8]> for PlatformStatus {
+    fn decode(input: &mut &[u8]) -> winnow::PResult<Self> {
+        Self::try_from(tinyklv::codecs::binary::dec::be_u8.parse_next(input)?).map_err(|_| tinyklv::err!())
+    }
+}
+/// [`PlatformStatus`] implementation of [`tinyklv::prelude::Encode`]
+impl tinyklv::prelude::Encode<Vec<u8>> for PlatformStatus {
+    fn encode(&self) -> Vec<u8> {
+        vec![*self.value()]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Frame rate of the sensor, as a rational number
+/// 
+/// Encoded as a pack of the numerator (BER-OID), and an optional
+/// denominator (BER-OID), which defaults to 1
+/// 
+/// See [`crate::misb0601::Misb0601::sensor_frame_rate`]
+pub struct SensorFrameRate {
+    pub numerator: u64,
+    pub denominator: u64,
+}
+/// [`SensorFrameRate`] implementation
+impl SensorFrameRate {
+    /// Decodes a frame rate from a stream, where `len` is
+    /// the length of the entire pack
+    pub fn decode(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<Self> {
+        move |input| {
+            let mut value = winnow::token::take(len).parse_next(input)?;
+            let numerator = tinyklv::codecs::ber::dec::ber_oid::<u64>.parse_next(&mut value)?;
+            let denominator = match value.is_empty() {
+                true => 1,
+                false => tinyklv::codecs::ber::dec::ber_oid::<u64>.parse_next(&mut value)?,
+            };
+            Ok(Self { numerator, denominator })
+        }
+    }
+
+    /// Frame rate, in frames per second (Hz)
+    pub fn as_f64(&self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+}
+/// [`SensorFrameRate`] implementation of [`tinyklv::prelude::Encode`]
+impl tinyklv::prelude::Encode<Vec<u8>> for SensorFrameRate {
+    fn encode(&self) -> Vec<u8> {
+        let mut output = tinyklv::codecs::ber::enc::ber_oid(&self.numerator);
+        if self.denominator != 1 { output.extend(tinyklv::codecs::ber::enc::ber_oid(&self.denominator)) }
+        output
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Location of the horizon within the image, as a line from
+/// a start point to an end point
+/// 
+/// Points are percentages of the image width (x) and height
+/// (y), measured from the top-left corner of the image. The
+/// corresponding geographic locations are optional
+/// 
+/// See [`crate::misb0601::Misb0601::image_horizon`]
+pub struct ImageHorizon {
+    /// Start point x, as a percentage of the image width
+    pub start_x: u8,
+    /// Start point y, as a percentage of the image height
+    pub start_y: u8,
+    /// End point x, as a percentage of the image width
+    pub end_x: u8,
+    /// End point y, as a percentage of the image height
+    pub end_y: u8,
+    /// Geographic locations of the start and end points
+    pub locations: Option<ImageHorizonLocations>,
+}
+/// [`ImageHorizon`] implementation
+impl ImageHorizon {
+    /// Decodes an image horizon pixel pack from a stream, where
+    /// `len` is the length of the entire pack
+    pub fn decode(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<Self> {
+        move |input| {
+            let mut value = winnow::token::take(len).parse_next(input)?;
+            let (start_x, start_y, end_x, end_y) = (
+                tinyklv::codecs::binary::dec::be_u8,
+                tinyklv::codecs::binary::dec::be_u8,
+                tinyklv::codecs::binary::dec::be_u8,
+                tinyklv::codecs::binary::dec::be_u8,
+            ).parse_next(&mut value)?;
+            let locations = match value.is_empty() {
+                true => None,
+                false => Some(ImageHorizonLocations::decode(&mut value)?),
+            };
+            Ok(Self { start_x, start_y, end_x, end_y, locations })
+        }
+    }
+
+    /// Horizon start point in pixel coordinates (column, row), for an
+    /// image of `width` by `height` pixels
+    pub fn start_pixel(&self, width: u32, height: u32) -> (f64, f64) {
+        Self::to_pixel(self.start_x, self.start_y, width, height)
+    }
+
+    /// Horizon end point in pixel coordinates (column, row), for an
+    /// image of `width` by `height` pixels
+    pub fn end_pixel(&self, width: u32, height: u32) -> (f64, f64) {
+        Self::to_pixel(self.end_x, self.end_y, width, height)
+    }
+
+    /// Horizon line in pixel coordinates, as the start and end points
+    /// (column, row), for an image of `width` by `height` pixels
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use misb::misb0601::ImageHorizon;
+    /// let horizon = ImageHorizon { start_x: 0, start_y: 25, end_x: 100, end_y: 50, locations: None };
+    /// assert_eq!(horizon.line(1280, 720), ((0.0, 180.0), (1280.0, 360.0)));
+    /// ```
+    pub fn line(&self, width: u32, height: u32) -> ((f64, f64), (f64, f64)) {
+        (self.start_pixel(width, height), self.end_pixel(width, height))
+    }
+
+    #[inline(always)]
+    /// Converts a percentage point to a pixel point
+    fn to_pixel(x: u8, y: u8, width: u32, height: u32) -> (f64, f64) {
+        (x as f64 / 100.0 * width as f64, y as f64 / 100.0 * height as f64)
+    }
+}
+/// [`ImageHorizon`] implementation of [`tinyklv::prelude::Encode`]
+impl tinyklv::prelude::Encode<Vec<u8>> for ImageHorizon {
+    fn encode(&self) -> Vec<u8> {
+        let mut output = vec![self.start_x, self.start_y, self.end_x, self.end_y];
+        if let Some(locations) = &self.locations { output.extend(locations.encode()) }
+        output
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Geographic locations of the start and end points of
+/// an [`ImageHorizon`]
+pub struct ImageHorizonLocations {
+    /// Units: Degrees (°)
+    pub start_latitude: f64,
+    /// Units: Degrees (°)
+    pub start_longitude: f64,
+    /// Units: Degrees (°)
+    pub end_latitude: f64,
+    /// Units: Degrees (°)
+    pub end_longitude: f64,
+}
+/// [`ImageHorizonLocations`] implementation of [`tinyklv::prelude::Decode`]
+impl tinyklv::prelude::Decode<&[u8]> for ImageHorizonLocations {
+    fn decode(input: &mut &[u8]) -> winnow::PResult<Self> {
+        let (start_latitude, start_longitude, end_latitude, end_longitude) = (
+            crate::misb0601::ops::to_lat,
+            crate::misb0601::ops::to_lon,
+            crate::misb0601::ops::to_lat,
+            crate::misb0601::ops::to_lon,
+        ).parse_next(input)?;
+        Ok(Self { start_latitude, start_longitude, end_latitude, end_longitude })
+    }
+}
+/// [`ImageHorizonLocations`] implementation of [`tinyklv::prelude::Encode`]
+impl tinyklv::prelude::Encode<Vec<u8>> for ImageHorizonLocations {
+    fn encode(&self) -> Vec<u8> {
+        let mut output = crate::misb0601::ops::from_lat(self.start_latitude).to_vec();
+        output.extend(crate::misb0601::ops::from_lon(self.start_longitude));
+        output.extend(crate::misb0601::ops::from_lat(self.end_latitude));
+        output.extend(crate::misb0601::ops::from_lon(self.end_longitude));
+        output
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Range of angles the sensor can view, relative to the
+/// platform, for each of azimuth, elevation, and roll
+/// 
+/// Encoded as a Variable-Length Pack (VLP) of up to three
+/// intervals, where omitted intervals have a length of zero
+/// 
+/// See [`crate::misb0601::Misb0601::view_domain`]
+pub struct ViewDomain {
+    /// Azimuth interval, with a start in range [0, 360]
+    pub azimuth: Option<ViewDomainInterval>,
+    /// Elevation interval, with a start in range [-180, 180]
+    pub elevation: Option<ViewDomainInterval>,
+    /// Roll interval, with a start in range [0, 360]
+    pub roll: Option<ViewDomainInterval>,
+}
+/// [`ViewDomain`] implementation
+impl ViewDomain {
+    /// Decodes a view domain from a stream, where `len` is
+    /// the length of the entire pack
+    pub fn decode(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<Self> {
+        move |input| {
+            let mut value = winnow::token::take(len).parse_next(input)?;
+            let azimuth = match value.is_empty() {
+                true => None,
+                false => ViewDomainInterval::decode_with_start(0.0, 360.0).parse_next(&mut value)?,
+            };
+            let elevation = match value.is_empty() {
+                true => None,
+                false => ViewDomainInterval::decode_with_start(-180.0, 180.0).parse_next(&mut value)?,
+            };
+            let roll = match value.is_empty() {
+                true => None,
+                false => ViewDomainInterval::decode_with_start(0.0, 360.0).parse_next(&mut value)?,
+            };
+            Ok(Self { azimuth, elevation, roll })
+        }
+    }
+}
+/// [`ViewDomain`] implementation of [`tinyklv::prelude::Encode`]
+impl tinyklv::prelude::Encode<Vec<u8>> for ViewDomain {
+    fn encode(&self) -> Vec<u8> {
+        let mut intervals = vec![
+            self.azimuth.map(|x| x.encode_with_start(&crate::misb0601::ops::IMAPB_0_360_VIEW_F64)),
+            self.elevation.map(|x| x.encode_with_start(&crate::misb0601::ops::IMAPB_N180_180_VIEW_F64)),
+            self.roll.map(|x| x.encode_with_start(&crate::misb0601::ops::IMAPB_0_360_VIEW_F64)),
+        ];
+        // --------------------------------------------------
+        // trailing omitted intervals are not encoded
+        // --------------------------------------------------
+        while intervals.len() > 1 && intervals.last().is_some_and(|x| x.is_none()) { intervals.pop(); }
+        let mut output = Vec::new();
+        for interval in intervals {
+            let interval = interval.unwrap_or_default();
+            output.extend(tinyklv::codecs::ber::enc::ber_length(&interval.len()));
+            output.extend(interval);
+        }
+        output
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// A single interval of a [`ViewDomain`], as a start
+/// angle and the range of angles from the start
+pub struct ViewDomainInterval {
+    /// Units: Degrees (°)
+    pub start: f64,
+    /// Range in [0, 360]
+    /// 
+    /// Units: Degrees (°)
+    pub range: f64,
+}
+/// [`ViewDomainInterval`] implementation
+impl ViewDomainInterval {
+    /// Decodes a length-prefixed interval, where the start is in
+    /// range [`min`, `max`], and the start and range evenly split
+    /// the length. A length of zero indicates an omitted interval
+    fn decode_with_start(min: f64, max: f64) -> impl Fn(&mut &[u8]) -> winnow::PResult<Option<Self>> {
+        move |input| {
+            let len = tinyklv::codecs::ber::dec::ber_length.parse_next(input)?;
+            if len == 0 { return Ok(None) }
+            if len % 2 != 0 { return Err(tinyklv::err!()) }
+            let (start, range) = (
+                crate::misb0601::ops::to_imapb(min, max, len / 2),
+                crate::misb0601::ops::to_imapb(0.0, 360.0, len / 2),
+            ).parse_next(input)?;
+            Ok(Some(Self { start, range }))
+        }
+    }
+
+    /// Encodes the interval, without the length prefix
+    fn encode_with_start(&self, start: &crate::misb1201::ImapB<f64>) -> Vec<u8> {
+        let mut output = crate::misb0601::ops::from_imapb(start, self.start);
+        output.extend(crate::misb0601::ops::from_imapb(&crate::misb0601::ops::IMAPB_0_360_VIEW_F64, self.range));
+        output
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Identifies the metadata substream a packet belongs to, when
+/// multiple metadata substreams (e.g., one per sensor) are
+/// interleaved within a single stream
+/// 
+/// The pack begins with the substream ID (BER-OID). Any remaining
+/// elements of the pack are kept as-is, and written back when encoding
+/// 
+/// See [`crate::misb0601::Misb0601::metadata_substream_id`]
+pub struct MetadataSubstreamId {
+    /// Unique identifier of the substream
+    pub id: u64,
+    /// Remaining elements of the pack following the [`MetadataSubstreamId::id`],
+    /// which are not interpreted
+    pub elements: Vec<u8>,
+}
+/// [`MetadataSubstreamId`] implementation
+impl MetadataSubstreamId {
+    /// Creates a new [`MetadataSubstreamId`], without any remaining elements
+    pub fn new(id: u64) -> Self {
+        Self { id, elements: Vec::new() }
+    }
+
+    /// Decodes a metadata substream ID pack from a stream, where
+    /// `len` is the length of the entire pack
+    pub fn decode(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<Self> {
+        move |input| {
+            let mut value = winnow::token::take(len).parse_next(input)?;
+            let id = tinyklv::codecs::ber::dec::ber_oid::<u64>.parse_next(&mut value)?;
+            Ok(Self { id, elements: value.to_vec() })
+        }
+    }
+}
+/// [`MetadataSubstreamId`] implementation of [`tinyklv::prelude::Encode`]
+impl tinyklv::prelude::Encode<Vec<u8>> for MetadataSubstreamId {
+    fn encode(&self) -> Vec<u8> {
+        let mut output = tinyklv::codecs::ber::enc::ber_oid(&self.id);
+        output.extend_from_slice(&self.elements);
+        output
+    }
+}
@@ -0,0 +1,153 @@
+// --------------------------------------------------
+// tinyklv
+// --------------------------------------------------
+use tinyklv::Klv;
+use tinyklv::prelude::*;
+
+// --------------------------------------------------
+// local
+// --------------------------------------------------
+use crate::misb0806::primitives::PoiAoiType;
+
+#[cfg(any(
+    feature = "misb0806-4",
+))]
+#[derive(Klv, Debug)]
+#[klv(
+    // ------------------------------------------------
+    // There is no UL - Local Set only
+    // ------------------------------------------------
+    stream = &[u8],
+    key(enc = tinyklv::codecs::ber::enc::ber_oid,
+        dec = tinyklv::codecs::ber::dec::ber_oid::<u64>),
+    len(enc = tinyklv::codecs::ber::enc::ber_length,
+        dec = tinyklv::codecs::ber::dec::ber_length),
+    default(ty = u16, dyn = true, dec = tinyklv::codecs::binary::dec::be_u16_lengthed),
+    default(ty = String, dyn = true, dec = tinyklv::codecs::binary::dec::to_string_utf8),
+)]
+/// MISB 0806 RVT Area of Interest (AoI) LS (Local Set)
+/// 
+/// `aoiLocalSet` -> [`crate::misb0806::Misb0806::aoi_local_sets`]
+/// 
+/// Identifies a rectangular geographic area of interest, defined by
+/// its upper left (point 1) and lower right (point 3) corners, along
+/// with descriptive information about the area.
+/// 
+/// For more information, see [Motion Imagery Standards Board (MISB)](https://nsgreg.nga.mil/misb.jsp)
+pub struct Misb0806Aoi {
+    #[klv(key = 0x01)]
+    /// (Mandatory) Number assigned to the AoI, unique amongst
+    /// PoI and AoI
+    /// 
+    /// Len: 2
+    /// 
+    /// Units: None
+    pub poi_aoi_number: u16,
+
+    #[klv(key = 0x02, dec = crate::misb0601::ops::to_lat)]
+    /// (Optional) Latitude of the upper left corner of the AoI,
+    /// based on WGS84 ellipsoid
+    /// 
+    /// Len: 4
+    /// 
+    /// Units: Degrees (°)
+    /// 
+    /// Resolution: ~42 nano degrees
+    pub corner_latitude_p1: Option<f64>,
+
+    #[klv(key = 0x03, dec = crate::misb0601::ops::to_lon)]
+    /// (Optional) Longitude of the upper left corner of the AoI,
+    /// based on WGS84 ellipsoid
+    /// 
+    /// Len: 4
+    /// 
+    /// Units: Degrees (°)
+    /// 
+    /// Resolution: ~84 nano degrees
+    pub corner_longitude_p1: Option<f64>,
+
+    #[klv(key = 0x04, dec = crate::misb0601::ops::to_lat)]
+    /// (Optional) Latitude of the lower right corner of the AoI,
+    /// based on WGS84 ellipsoid
+    /// 
+    /// Len: 4
+    /// 
+    /// Units: Degrees (°)
+    /// 
+    /// Resolution: ~42 nano degrees
+    pub corner_latitude_p3: Option<f64>,
+
+    #[klv(key = 0x05, dec = crate::misb0601::ops::to_lon)]
+    /// (Optional) Longitude of the lower right corner of the AoI,
+    /// based on WGS84 ellipsoid
+    /// 
+    /// Len: 4
+    /// 
+    /// Units: Degrees (°)
+    /// 
+    /// Resolution: ~84 nano degrees
+    pub corner_longitude_p3: Option<f64>,
+
+    #[klv(key = 0x06, dec = PoiAoiType::decode)]
+    /// (Optional) Type of the AoI, see [`PoiAoiType`]
+    /// 
+    /// Len: 1
+    /// 
+    /// Units: None
+    pub poi_aoi_type: Option<PoiAoiType>,
+
+    #[klv(key = 0x07)]
+    /// (Optional) Free text description of the AoI
+    /// 
+    /// Len: Variable
+    /// 
+    /// Units: None
+    pub poi_aoi_text: Option<String>,
+
+    #[klv(key = 0x08)]
+    /// (Optional) Identifier of the source of the AoI, e.g. a
+    /// platform or call sign
+    /// 
+    /// Len: Variable
+    /// 
+    /// Units: None
+    pub poi_aoi_source_id: Option<String>,
+
+    #[klv(key = 0x09)]
+    /// (Optional) Short label to display alongside the AoI
+    /// 
+    /// Len: Variable
+    /// 
+    /// Units: None
+    pub poi_aoi_label: Option<String>,
+
+    #[klv(key = 0x0A)]
+    /// (Optional) Identifier of the operation the AoI belongs to
+    /// 
+    /// Len: Variable
+    /// 
+    /// Units: None
+    pub operation_id: Option<String>,
+}
+
+#[cfg(any(
+    feature = "misb0806-4",
+))]
+/// [`Misb0806Aoi`] implementation
+impl Misb0806Aoi {
+    /// Encodes every present item of the [`Misb0806Aoi`] in tag order
+    pub fn encode_value(&self) -> Vec<u8> {
+        let mut output = Vec::new();
+        crate::encode_item(&mut output, 0x01, &self.poi_aoi_number.to_be_bytes());
+        if let Some(x) = self.corner_latitude_p1 { crate::encode_item(&mut output, 0x02, &crate::misb0601::ops::from_lat(x)) }
+        if let Some(x) = self.corner_longitude_p1 { crate::encode_item(&mut output, 0x03, &crate::misb0601::ops::from_lon(x)) }
+        if let Some(x) = self.corner_latitude_p3 { crate::encode_item(&mut output, 0x04, &crate::misb0601::ops::from_lat(x)) }
+        if let Some(x) = self.corner_longitude_p3 { crate::encode_item(&mut output, 0x05, &crate::misb0601::ops::from_lon(x)) }
+        if let Some(x) = &self.poi_aoi_type { crate::encode_item(&mut output, 0x06, &x.encode()) }
+        if let Some(x) = &self.poi_aoi_text { crate::encode_item(&mut output, 0x07, x.as_bytes()) }
+        if let Some(x) = &self.poi_aoi_source_id { crate::encode_item(&mut output, 0x08, x.as_bytes()) }
+        if let Some(x) = &self.poi_aoi_label { crate::encode_item(&mut output, 0x09, x.as_bytes()) }
+        if let Some(x) = &self.operation_id { crate::encode_item(&mut output, 0x0A, x.as_bytes()) }
+        output
+    }
+}
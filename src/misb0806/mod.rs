@@ -0,0 +1,456 @@
+// --------------------------------------------------
+// tinyklv
+// --------------------------------------------------
+use tinyklv::Klv;
+use tinyklv::prelude::*;
+
+// --------------------------------------------------
+// local
+// --------------------------------------------------
+pub mod ops;
+pub mod primitives;
+pub use poi::Misb0806Poi;
+pub use aoi::Misb0806Aoi;
+pub use user_defined::Misb0806UserDefined;
+pub use primitives::{PoiAoiType, UserDataType};
+
+// --------------------------------------------------
+// relative
+// --------------------------------------------------
+mod poi;
+mod aoi;
+mod user_defined;
+
+/// MISB 0806 RVT LS Universal Label (UL)
+pub const RVT_LS_UL: &[u8; 16] = b"\x06\x0E\x2B\x34\x02\x0B\x01\x01\x0E\x01\x03\x01\x02\x00\x00\x00";
+
+#[cfg(any(
+    feature = "misb0806-4",
+))]
+#[derive(Klv, Debug)]
+#[klv(
+    // ------------------------------------------------
+    // confirmed Misb0806 RVT UL
+    //             06 .0E .2B .34 .02 .0B .01 .01 .0E .01 .03 .01 .02 .00 .00 .00
+    sentinel = b"\x06\x0E\x2B\x34\x02\x0B\x01\x01\x0E\x01\x03\x01\x02\x00\x00\x00",
+    // ------------------------------------------------
+    stream = &[u8],
+    key(enc = tinyklv::codecs::ber::enc::ber_oid,
+        dec = tinyklv::codecs::ber::dec::ber_oid::<u64>),
+    len(enc = tinyklv::codecs::ber::enc::ber_length,
+        dec = tinyklv::codecs::ber::dec::ber_length),
+    default(ty = u8, dyn = true, dec = tinyklv::codecs::binary::dec::be_u8_lengthed),
+    default(ty = u16, dyn = true, dec = tinyklv::codecs::binary::dec::be_u16_lengthed),
+    default(ty = u32, dyn = true, dec = tinyklv::codecs::binary::dec::be_u32_lengthed),
+    default(ty = String, dyn = true, dec = tinyklv::codecs::binary::dec::to_string_utf8),
+)]
+/// Remote Video Terminal Metadata
+/// 
+/// MISB Standard 0806
+/// 
+/// For more information, see [Motion Imagery Standards Board (MISB)](https://nsgreg.nga.mil/misb.jsp)
+pub struct Misb0806 {
+    #[cfg(any(
+        feature = "misb0806-4",
+    ))]
+    #[klv(key = 0x01, dyn = false, dec = tinyklv::codecs::binary::dec::be_u16)]
+    /// (Contextual) Detects errors within a standalone RVT LS
+    /// 
+    /// Unlike MISB ST 0601, the RVT LS checksum is a CRC-16-CCITT, see
+    /// [`crate::checksum::crc16_ccitt`]. Performed over the entire LS,
+    /// including the 16-byte UL key, length, and the checksum's key and length.
+    /// 
+    /// Len: 2
+    /// 
+    /// Units: None
+    pub checksum: Option<u16>,
+
+    #[cfg(any(
+        feature = "misb0806-4",
+    ))]
+    #[klv(key = 0x02, dec = crate::misb0601::ops::to_precision_timestamp)]
+    /// (Mandatory) Microsecond count from Epoch of 1970
+    /// See MISP Time System - MISB ST 0603
+    /// 
+    /// Is "pseudo mandatory"; RVT LS embedded within MISB 0601 (see
+    /// [`crate::misb0601::Misb0601::rvt_local_set`]) commonly omits it,
+    /// relying on the parent timestamp instead.
+    /// 
+    /// Len: 8
+    /// 
+    /// Units: Microseconds (μs)
+    pub precision_timestamp: Option<chrono::DateTime<chrono::Utc>>,
+
+    #[cfg(any(
+        feature = "misb0806-4",
+    ))]
+    #[klv(key = 0x03)]
+    /// (Optional) True airspeed of the platform
+    /// 
+    /// Len: 2
+    /// 
+    /// Units: Meters per second (m/s)
+    pub platform_true_airspeed: Option<u16>,
+
+    #[cfg(any(
+        feature = "misb0806-4",
+    ))]
+    #[klv(key = 0x04)]
+    /// (Optional) Indicated airspeed of the platform
+    /// 
+    /// Len: 2
+    /// 
+    /// Units: Meters per second (m/s)
+    pub platform_indicated_airspeed: Option<u16>,
+
+    #[cfg(any(
+        feature = "misb0806-4",
+    ))]
+    #[klv(key = 0x05)]
+    /// (Optional) Accuracy indicator of the telemetry (i.e., platform
+    /// and sensor) data
+    /// 
+    /// Len: 1
+    /// 
+    /// Units: None
+    pub telemetry_accuracy_indicator: Option<u8>,
+
+    #[cfg(any(
+        feature = "misb0806-4",
+    ))]
+    #[klv(key = 0x06)]
+    /// (Optional) Size of the fragmentation circle of the selected munition
+    /// 
+    /// Len: 2
+    /// 
+    /// Units: Meters (m)
+    pub frag_circle_radius: Option<u16>,
+
+    #[cfg(any(
+        feature = "misb0806-4",
+    ))]
+    #[klv(key = 0x07)]
+    /// (Optional) Counter used to align the metadata with the Motion
+    /// Imagery frame it corresponds to
+    /// 
+    /// Len: 4
+    /// 
+    /// Units: None
+    pub frame_code: Option<u32>,
+
+    #[cfg(any(
+        feature = "misb0806-4",
+    ))]
+    #[klv(key = 0x08)]
+    /// (Optional) Version number of the UAS Datalink LS document
+    /// used to generate the RVT metadata
+    /// 
+    /// See [`crate::misb0601::Misb0601::uas_ls_version`]
+    /// 
+    /// Len: 1
+    /// 
+    /// Units: None
+    pub uas_ls_version: Option<u8>,
+
+    #[cfg(any(
+        feature = "misb0806-4",
+    ))]
+    #[klv(key = 0x09)]
+    /// (Optional) Data rate of the Motion Imagery
+    /// 
+    /// Len: 4
+    /// 
+    /// Units: Bits per second (bps)
+    pub video_data_rate: Option<u32>,
+
+    #[cfg(any(
+        feature = "misb0806-4",
+    ))]
+    #[klv(key = 0x0A)]
+    /// (Optional) Format of the digital video file, e.g. `"MPEG2"` or `"H.264"`
+    /// 
+    /// Len: Variable
+    /// 
+    /// Units: None
+    pub digital_video_file_format: Option<String>,
+
+    #[cfg(any(
+        feature = "misb0806-4",
+    ))]
+    #[klv(key = 0x0B, dyn = true, dec = ops::to_single::<Misb0806UserDefined>)]
+    /// (Optional) User defined data, see [`Misb0806UserDefined`]
+    /// 
+    /// May be repeated, so every local set is collected after decoding,
+    /// see [`Misb0806::decode_value`].
+    /// 
+    /// Is "pseudo optional"; if not present, defaults to an empty vector.
+    /// 
+    /// Len: Variable
+    /// 
+    /// Units: None
+    pub user_defined_local_sets: Vec<Misb0806UserDefined>,
+
+    #[cfg(any(
+        feature = "misb0806-4",
+    ))]
+    #[klv(key = 0x0C, dyn = true, dec = ops::to_single::<Misb0806Poi>)]
+    /// (Optional) Points of Interest, see [`Misb0806Poi`]
+    /// 
+    /// May be repeated, so every local set is collected after decoding,
+    /// see [`Misb0806::decode_value`].
+    /// 
+    /// Is "pseudo optional"; if not present, defaults to an empty vector.
+    /// 
+    /// Len: Variable
+    /// 
+    /// Units: None
+    pub poi_local_sets: Vec<Misb0806Poi>,
+
+    #[cfg(any(
+        feature = "misb0806-4",
+    ))]
+    #[klv(key = 0x0D, dyn = true, dec = ops::to_single::<Misb0806Aoi>)]
+    /// (Optional) Areas of Interest, see [`Misb0806Aoi`]
+    /// 
+    /// May be repeated, so every local set is collected after decoding,
+    /// see [`Misb0806::decode_value`].
+    /// 
+    /// Is "pseudo optional"; if not present, defaults to an empty vector.
+    /// 
+    /// Len: Variable
+    /// 
+    /// Units: None
+    pub aoi_local_sets: Vec<Misb0806Aoi>,
+
+    #[cfg(any(
+        feature = "misb0806-4",
+    ))]
+    #[klv(key = 0x0E)]
+    /// (Optional) MGRS zone of the platform
+    /// 
+    /// Valid values: 1 to 60
+    /// 
+    /// Len: 1
+    /// 
+    /// Units: None
+    pub mgrs_zone: Option<u8>,
+
+    #[cfg(any(
+        feature = "misb0806-4",
+    ))]
+    #[klv(key = 0x0F)]
+    /// (Optional) MGRS latitude band and 100km grid square of the platform,
+    /// e.g. `"SUU"`
+    /// 
+    /// Len: 3
+    /// 
+    /// Units: None
+    pub mgrs_latitude_band_grid_square: Option<String>,
+
+    #[cfg(any(
+        feature = "misb0806-4",
+    ))]
+    #[klv(key = 0x10)]
+    /// (Optional) MGRS easting of the platform
+    /// 
+    /// Len: 3
+    /// 
+    /// Units: Meters (m)
+    pub mgrs_easting: Option<u32>,
+
+    #[cfg(any(
+        feature = "misb0806-4",
+    ))]
+    #[klv(key = 0x11)]
+    /// (Optional) MGRS northing of the platform
+    /// 
+    /// Len: 3
+    /// 
+    /// Units: Meters (m)
+    pub mgrs_northing: Option<u32>,
+
+    #[cfg(any(
+        feature = "misb0806-4",
+    ))]
+    #[klv(key = 0x12)]
+    /// (Optional) MGRS zone of the frame center
+    /// 
+    /// Valid values: 1 to 60
+    /// 
+    /// Len: 1
+    /// 
+    /// Units: None
+    pub frame_center_mgrs_zone: Option<u8>,
+
+    #[cfg(any(
+        feature = "misb0806-4",
+    ))]
+    #[klv(key = 0x13)]
+    /// (Optional) MGRS latitude band and 100km grid square of the
+    /// frame center, e.g. `"SUU"`
+    /// 
+    /// Len: 3
+    /// 
+    /// Units: None
+    pub frame_center_mgrs_latitude_band_grid_square: Option<String>,
+
+    #[cfg(any(
+        feature = "misb0806-4",
+    ))]
+    #[klv(key = 0x14)]
+    /// (Optional) MGRS easting of the frame center
+    /// 
+    /// Len: 3
+    /// 
+    /// Units: Meters (m)
+    pub frame_center_mgrs_easting: Option<u32>,
+
+    #[cfg(any(
+        feature = "misb0806-4",
+    ))]
+    #[klv(key = 0x15)]
+    /// (Optional) MGRS northing of the frame center
+    /// 
+    /// Len: 3
+    /// 
+    /// Units: Meters (m)
+    pub frame_center_mgrs_northing: Option<u32>,
+}
+
+#[cfg(feature = "misb0806-4")]
+/// [`Misb0806`] implementation
+impl Misb0806 {
+    /// Decodes the value of an RVT LS (i.e., without the UL and length)
+    /// using the derived [`tinyklv`] decoder, then collects every
+    /// [`Misb0806::user_defined_local_sets`], [`Misb0806::poi_local_sets`],
+    /// and [`Misb0806::aoi_local_sets`], since each may be repeated
+    /// 
+    /// For a standalone packet, seek past the UL and length first, e.g.
+    /// using [`tinyklv::prelude::Seek`].
+    pub fn decode_value(input: &mut &[u8]) -> winnow::PResult<Self> {
+        let start = *input;
+        let mut output = <Self as tinyklv::prelude::Decode<&[u8]>>::decode(input)?;
+        let consumed = &start[..start.len() - input.len()];
+        output.user_defined_local_sets = ops::to_repeated(consumed, 0x0B)?;
+        output.poi_local_sets = ops::to_repeated(consumed, 0x0C)?;
+        output.aoi_local_sets = ops::to_repeated(consumed, 0x0D)?;
+        Ok(output)
+    }
+
+    /// Verifies the checksum of a complete standalone RVT LS packet, from
+    /// the first byte of the UL to the last byte of the checksum value
+    /// 
+    /// Only applicable to standalone RVT, where [`Misb0806::checksum`] is
+    /// the final item in the packet. RVT embedded within MISB 0601 relies
+    /// on the checksum of the parent packet instead.
+    /// 
+    /// See [`crate::checksum::verify_with`] and [`crate::checksum::crc16_ccitt`]
+    pub fn verify_checksum(packet: &[u8]) -> Result<(), crate::checksum::ChecksumError> {
+        crate::checksum::verify_with(packet, crate::checksum::crc16_ccitt)
+    }
+
+    /// Encodes the [`Misb0806`] into a complete, standalone RVT LS packet
+    /// 
+    /// The packet consists of the 16-byte UL ([`RVT_LS_UL`]), the BER encoded
+    /// length, and every present item in tag order. The [`Misb0806::checksum`]
+    /// is always the final item, and is re-computed over the encoded packet.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut value = self.encode_value();
+        value.extend([crate::checksum::CHECKSUM_KEY, crate::checksum::CHECKSUM_LEN]);
+        let mut output = RVT_LS_UL.to_vec();
+        output.extend(tinyklv::codecs::ber::enc::ber_length(&(value.len() + 2)));
+        output.extend(value);
+        let checksum = crate::checksum::crc16_ccitt(&output);
+        output.extend(checksum.to_be_bytes());
+        output
+    }
+
+    /// Encodes every present item of the [`Misb0806`] in tag order,
+    /// excluding the [`Misb0806::checksum`]
+    /// 
+    /// This is the value of the RVT LS when embedded within MISB 0601,
+    /// see [`crate::misb0601::Misb0601::rvt_local_set`]
+    pub fn encode_value(&self) -> Vec<u8> {
+        let mut output = Vec::new();
+        if let Some(x) = self.precision_timestamp { crate::encode_item(&mut output, 0x02, &crate::misb0601::ops::from_precision_timestamp(x)) }
+        if let Some(x) = self.platform_true_airspeed { crate::encode_item(&mut output, 0x03, &x.to_be_bytes()) }
+        if let Some(x) = self.platform_indicated_airspeed { crate::encode_item(&mut output, 0x04, &x.to_be_bytes()) }
+        if let Some(x) = self.telemetry_accuracy_indicator { crate::encode_item(&mut output, 0x05, &[x]) }
+        if let Some(x) = self.frag_circle_radius { crate::encode_item(&mut output, 0x06, &x.to_be_bytes()) }
+        if let Some(x) = self.frame_code { crate::encode_item(&mut output, 0x07, &x.to_be_bytes()) }
+        if let Some(x) = self.uas_ls_version { crate::encode_item(&mut output, 0x08, &[x]) }
+        if let Some(x) = self.video_data_rate { crate::encode_item(&mut output, 0x09, &x.to_be_bytes()) }
+        if let Some(x) = &self.digital_video_file_format { crate::encode_item(&mut output, 0x0A, x.as_bytes()) }
+        for x in &self.user_defined_local_sets { crate::encode_item(&mut output, 0x0B, &x.encode_value()) }
+        for x in &self.poi_local_sets { crate::encode_item(&mut output, 0x0C, &x.encode_value()) }
+        for x in &self.aoi_local_sets { crate::encode_item(&mut output, 0x0D, &x.encode_value()) }
+        if let Some(x) = self.mgrs_zone { crate::encode_item(&mut output, 0x0E, &[x]) }
+        if let Some(x) = &self.mgrs_latitude_band_grid_square { crate::encode_item(&mut output, 0x0F, x.as_bytes()) }
+        if let Some(x) = self.mgrs_easting { crate::encode_item(&mut output, 0x10, &ops::from_mgrs_distance(x)) }
+        if let Some(x) = self.mgrs_northing { crate::encode_item(&mut output, 0x11, &ops::from_mgrs_distance(x)) }
+        if let Some(x) = self.frame_center_mgrs_zone { crate::encode_item(&mut output, 0x12, &[x]) }
+        if let Some(x) = &self.frame_center_mgrs_latitude_band_grid_square { crate::encode_item(&mut output, 0x13, x.as_bytes()) }
+        if let Some(x) = self.frame_center_mgrs_easting { crate::encode_item(&mut output, 0x14, &ops::from_mgrs_distance(x)) }
+        if let Some(x) = self.frame_center_mgrs_northing { crate::encode_item(&mut output, 0x15, &ops::from_mgrs_distance(x)) }
+        output
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "misb0806-4")]
+mod test {
+    use super::*;
+    use tinyklv::prelude::*;
+
+    #[test]
+    fn encode_roundtrip() {
+        // --------------------------------------------------
+        // RVT LS value: frame code 7, MGRS zone 18,
+        // and a friendly PoI
+        // --------------------------------------------------
+        let value: &[u8] = &[
+            0x07, 0x04, 0x00, 0x00, 0x00, 0x07, 0x0C, 0x13, 0x01, 0x02, 0x00, 0x2A, 0x02, 0x04, 0x3D, 0x4F,
+            0x5A, 0x6E, 0x03, 0x04, 0xC9, 0x3E, 0x3C, 0x3A, 0x05, 0x01, 0x01, 0x0E, 0x01, 0x12,
+        ];
+        let decoded = Misb0806::decode_value(&mut &value[..]).unwrap();
+        assert_eq!(decoded.frame_code, Some(7));
+        assert_eq!(decoded.mgrs_zone, Some(18));
+        assert_eq!(decoded.poi_local_sets.len(), 1);
+        let poi = &decoded.poi_local_sets[0];
+        assert_eq!(poi.poi_aoi_number, 42);
+        assert!(matches!(poi.poi_aoi_type, Some(PoiAoiType::Friendly)));
+        assert_eq!(decoded.encode_value(), value);
+        // --------------------------------------------------
+        // standalone packets use a CRC-16-CCITT
+        // --------------------------------------------------
+        let packet = decoded.encode();
+        assert_eq!(&packet[..16], RVT_LS_UL);
+        assert_eq!(Misb0806::verify_checksum(&packet), Ok(()));
+        assert!(crate::checksum::verify(&packet).is_err());
+    }
+
+    #[test]
+    fn repeated_local_sets() {
+        let poi: &[u8] = &[
+            0x01, 0x02, 0x00, 0x2A, 0x02, 0x04, 0x3D, 0x4F, 0x5A, 0x6E, 0x03, 0x04, 0xC9, 0x3E, 0x3C, 0x3A,
+            0x05, 0x01, 0x01,
+        ];
+        let mut other = poi.to_vec();
+        other[3] = 0x2B;
+        let mut value = Vec::new();
+        crate::encode_item(&mut value, 0x0C, poi);
+        crate::encode_item(&mut value, 0x0E, &[0x12]);
+        crate::encode_item(&mut value, 0x0C, &other);
+        let decoded = Misb0806::decode_value(&mut value.as_slice()).unwrap();
+        assert_eq!(decoded.poi_local_sets.iter().map(|x| x.poi_aoi_number).collect::<Vec<_>>(), vec![42, 43]);
+        assert_eq!(decoded.mgrs_zone, Some(18));
+        let redecoded = Misb0806::decode_value(&mut decoded.encode_value().as_slice()).unwrap();
+        assert_eq!(redecoded.poi_local_sets.iter().map(|x| x.poi_aoi_number).collect::<Vec<_>>(), vec![42, 43]);
+    }
+
+    #[test]
+    fn mgrs_distance_clamped() {
+        assert_eq!(ops::from_mgrs_distance(99_999), [0x01, 0x86, 0x9F]);
+        assert_eq!(ops::from_mgrs_distance(0x0100_0000), [0xFF, 0xFF, 0xFF]);
+    }
+}
@@ -0,0 +1,80 @@
+// --------------------------------------------------
+// tinyklv
+// --------------------------------------------------
+use tinyklv::prelude::*;
+
+/// Maximum MGRS distance which can be encoded using 3 bytes
+/// 
+/// See [`from_mgrs_distance`]
+pub const MGRS_DISTANCE_MAX: u32 = 0xFF_FFFF;
+
+/// Raw bytes of length `len`
+/// 
+/// See [`crate::misb0806::Misb0806UserDefined::user_data`]
+pub fn to_bytes(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<Vec<u8>> {
+    move |input: &mut &[u8]| Ok(winnow::token::take(len).parse_next(input)?.to_vec())
+}
+
+#[inline(always)]
+/// See [`crate::misb0806::Misb0806`]
+/// 
+/// * [`crate::misb0806::Misb0806::mgrs_easting`]
+/// * [`crate::misb0806::Misb0806::mgrs_northing`]
+/// * [`crate::misb0806::Misb0806::frame_center_mgrs_easting`]
+/// * [`crate::misb0806::Misb0806::frame_center_mgrs_northing`]
+/// 
+/// Always encoded using 3 bytes, meaning values above `0xFFFFFF`
+/// are clamped to `0xFFFFFF`
+pub const fn from_mgrs_distance(input: u32) -> [u8; 3] {
+    let input = if input > MGRS_DISTANCE_MAX { MGRS_DISTANCE_MAX } else { input };
+    let [_, a, b, c] = input.to_be_bytes();
+    [a, b, c]
+}
+
+/// See [`crate::misb0601::Misb0601::rvt_local_set`]
+/// 
+/// A [`crate::misb0806::Misb0806`] of length `len`, see
+/// [`crate::misb0806::Misb0806::decode_value`]
+pub fn to_rvt(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<crate::misb0806::Misb0806> {
+    move |input: &mut &[u8]| {
+        let mut value = winnow::token::take(len).parse_next(input)?;
+        crate::misb0806::Misb0806::decode_value(&mut value)
+    }
+}
+
+/// A single local set of length `len`, embedded as the value of an
+/// item which may be repeated
+/// 
+/// Since only the last is kept by the derived decoder, every local set
+/// is collected afterwards using [`to_repeated`].
+/// 
+/// * [`crate::misb0806::Misb0806::user_defined_local_sets`]
+/// * [`crate::misb0806::Misb0806::poi_local_sets`]
+/// * [`crate::misb0806::Misb0806::aoi_local_sets`]
+pub fn to_single<T>(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<Vec<T>>
+where
+    T: for<'a> tinyklv::prelude::Decode<&'a [u8]>,
+{
+    move |input: &mut &[u8]| Ok(vec![crate::decode_exact::<T>(len)(input)?])
+}
+
+/// Collects every local set embedded as the value of an item with `key`,
+/// within the value of an RVT LS (i.e., without the UL and length), in
+/// stream order
+/// 
+/// See [`to_single`]
+pub fn to_repeated<T>(mut input: &[u8], key: u64) -> winnow::PResult<Vec<T>>
+where
+    T: for<'a> tinyklv::prelude::Decode<&'a [u8]>,
+{
+    let mut output = Vec::new();
+    while !input.is_empty() {
+        let item_key = tinyklv::codecs::ber::dec::ber_oid::<u64>.parse_next(&mut input)?;
+        let len = tinyklv::codecs::ber::dec::ber_length.parse_next(&mut input)?;
+        match item_key == key {
+            true => output.push(crate::decode_exact::<T>(len)(&mut input)?),
+            false => { winnow::token::take(len).parse_next(&mut input)?; },
+        }
+    }
+    Ok(output)
+}
@@ -0,0 +1,146 @@
+// --------------------------------------------------
+// tinyklv
+// --------------------------------------------------
+use tinyklv::Klv;
+use tinyklv::prelude::*;
+
+// --------------------------------------------------
+// local
+// --------------------------------------------------
+use crate::misb0806::primitives::PoiAoiType;
+
+#[cfg(any(
+    feature = "misb0806-4",
+))]
+#[derive(Klv, Debug)]
+#[klv(
+    // ------------------------------------------------
+    // There is no UL - Local Set only
+    // ------------------------------------------------
+    stream = &[u8],
+    key(enc = tinyklv::codecs::ber::enc::ber_oid,
+        dec = tinyklv::codecs::ber::dec::ber_oid::<u64>),
+    len(enc = tinyklv::codecs::ber::enc::ber_length,
+        dec = tinyklv::codecs::ber::dec::ber_length),
+    default(ty = u16, dyn = true, dec = tinyklv::codecs::binary::dec::be_u16_lengthed),
+    default(ty = String, dyn = true, dec = tinyklv::codecs::binary::dec::to_string_utf8),
+)]
+/// MISB 0806 RVT Point of Interest (PoI) LS (Local Set)
+/// 
+/// `poiLocalSet` -> [`crate::misb0806::Misb0806::poi_local_sets`]
+/// 
+/// Identifies a single geographic point of interest, along with
+/// descriptive information about the point.
+/// 
+/// For more information, see [Motion Imagery Standards Board (MISB)](https://nsgreg.nga.mil/misb.jsp)
+pub struct Misb0806Poi {
+    #[klv(key = 0x01)]
+    /// (Mandatory) Number assigned to the PoI, unique amongst
+    /// PoI and AoI
+    /// 
+    /// Len: 2
+    /// 
+    /// Units: None
+    pub poi_aoi_number: u16,
+
+    #[klv(key = 0x02, dec = crate::misb0601::ops::to_lat)]
+    /// (Mandatory) Latitude of the PoI, based on WGS84 ellipsoid
+    /// 
+    /// Len: 4
+    /// 
+    /// Units: Degrees (°)
+    /// 
+    /// Resolution: ~42 nano degrees
+    pub poi_latitude: f64,
+
+    #[klv(key = 0x03, dec = crate::misb0601::ops::to_lon)]
+    /// (Mandatory) Longitude of the PoI, based on WGS84 ellipsoid
+    /// 
+    /// Len: 4
+    /// 
+    /// Units: Degrees (°)
+    /// 
+    /// Resolution: ~84 nano degrees
+    pub poi_longitude: f64,
+
+    #[klv(key = 0x04, dec = crate::misb0601::ops::to_alt)]
+    /// (Optional) Altitude of the PoI, measured from Mean Sea Level (MSL)
+    /// 
+    /// Len: 2
+    /// 
+    /// Units: Meters (m)
+    /// 
+    /// Resolution: ~0.3 meters
+    pub poi_altitude: Option<f32>,
+
+    #[klv(key = 0x05, dec = PoiAoiType::decode)]
+    /// (Optional) Type of the PoI, see [`PoiAoiType`]
+    /// 
+    /// Len: 1
+    /// 
+    /// Units: None
+    pub poi_aoi_type: Option<PoiAoiType>,
+
+    #[klv(key = 0x06)]
+    /// (Optional) Free text description of the PoI
+    /// 
+    /// Len: Variable
+    /// 
+    /// Units: None
+    pub poi_aoi_text: Option<String>,
+
+    #[klv(key = 0x07)]
+    /// (Optional) Icon used to display the PoI, per MIL-STD-2525
+    /// 
+    /// Len: Variable
+    /// 
+    /// Units: None
+    pub poi_source_icon: Option<String>,
+
+    #[klv(key = 0x08)]
+    /// (Optional) Identifier of the source of the PoI, e.g. a
+    /// platform or call sign
+    /// 
+    /// Len: Variable
+    /// 
+    /// Units: None
+    pub poi_aoi_source_id: Option<String>,
+
+    #[klv(key = 0x09)]
+    /// (Optional) Short label to display alongside the PoI
+    /// 
+    /// Len: Variable
+    /// 
+    /// Units: None
+    pub poi_aoi_label: Option<String>,
+
+    #[klv(key = 0x0A)]
+    /// (Optional) Identifier of the operation the PoI belongs to
+    /// 
+    /// Len: Variable
+    /// 
+    /// Units: None
+    pub operation_id: Option<String>,
+}
+
+#[cfg(any(
+    feature = "misb0806-4",
+))]
+/// [`Misb0806Poi`] implementation
+impl Misb0806Poi {
+    /// Encodes every present item of the [`Misb0806Poi`] in tag order
+    pub fn encode_value(&self) -> Vec<u8> {
+        let mut output = Vec::new();
+        crate::encode_item(&mut output, 0x01, &self.poi_aoi_number.to_be_bytes());
+        crate::encode_item(&mut output, 0x02, &crate::misb0601::ops::from_lat(self.poi_latitude));
+        crate::encode_item(&mut output, 0x03, &crate::misb0601::ops::from_lon(self.poi_longitude));
+        if let Some(x) = self.poi_altitude { crate::encode_item(&mut output, 0x04, &crate::misb0601::ops::from_alt(x)) }
+        if let Some(x) = &self.poi_aoi_type { crate::encode_item(&mut output, 0x05, &x.encode()) }
+        if let Some(x) = &self.poi_aoi_text { crate::encode_item(&mut output, 0x06, x.as_bytes()) }
+        if let Some(x) = &self.poi_source_icon { crate::encode_item(&mut output, 0x07, x.as_bytes()) }
+        if let Some(x) = &self.poi_aoi_source_id { crate::encode_item(&mut output, 0x08, x.as_bytes()) }
+        if let Some(x) = &self.poi_aoi_label { crate::encode_item(&mut output, 0x09, x.as_bytes()) }
+        if let Some(x) = &self.operation_id { crate::encode_item(&mut output, 0x0A, x.as_bytes()) }
+        output
+    }
+}
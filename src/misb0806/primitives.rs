@@ -0,0 +1,58 @@
+// --------------------------------------------------
+// tinyklv
+// --------------------------------------------------
+use tinyklv::prelude::*;
+
+// --------------------------------------------------
+// external
+// --------------------------------------------------
+use thisenum::Const;
+
+#[derive(Const)]
+#[armtype(u8)]
+/// PoI / AoI Type
+/// 
+/// See [`crate::misb0806::Misb0806Poi::poi_aoi_type`] and
+/// [`crate::misb0806::Misb0806Aoi::poi_aoi_type`]
+pub enum PoiAoiType {
+    #[value = 1]
+    Friendly,
+    #[value = 2]
+    Hostile,
+    #[value = 3]
+    Target,
+    #[value = 4]
+    Unknown,
+}
+/// [`PoiAoiType`] implementation of [`tinyklv::prelude::Decode`]
+impl tinyklv::prelude::Decode<&[u8]> for PoiAoiType {
+    fn decode(input: &mut &[u8]) -> winnow::PResult<Self> {
+        Self::try_from(tinyklv::codecs::binary::dec::be_u8.parse_next(input)?).map_err(|_| tinyklv::err!())
+    }
+}
+/// [`PoiAoiType`] implementation of [`tinyklv::prelude::Encode`]
+impl tinyklv::prelude::Encode<Vec<u8>> for PoiAoiType {
+    fn encode(&self) -> Vec<u8> {
+        vec![*self.value()]
+    }
+}
+
+#[derive(Const)]
+#[armtype(u8)]
+/// Data type of [`crate::misb0806::Misb0806UserDefined::user_data`],
+/// stored in the 2 most significant bits of the
+/// [`crate::misb0806::Misb0806UserDefined::data_type_id`]
+pub enum UserDataType {
+    #[value = 0]
+    /// UTF-8 string
+    String,
+    #[value = 1]
+    /// Big-endian signed integer
+    Signed,
+    #[value = 2]
+    /// Big-endian unsigned integer
+    Unsigned,
+    #[value = 3]
+    /// Experimental, user defined format
+    Experimental,
+}
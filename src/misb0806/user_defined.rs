@@ -0,0 +1,84 @@
+// --------------------------------------------------
+// tinyklv
+// --------------------------------------------------
+use tinyklv::Klv;
+use tinyklv::prelude::*;
+
+// --------------------------------------------------
+// local
+// --------------------------------------------------
+use crate::misb0806::ops;
+use crate::misb0806::primitives::UserDataType;
+
+#[cfg(any(
+    feature = "misb0806-4",
+))]
+#[derive(Klv, Debug)]
+#[klv(
+    // ------------------------------------------------
+    // There is no UL - Local Set only
+    // ------------------------------------------------
+    stream = &[u8],
+    key(enc = tinyklv::codecs::ber::enc::ber_oid,
+        dec = tinyklv::codecs::ber::dec::ber_oid::<u64>),
+    len(enc = tinyklv::codecs::ber::enc::ber_length,
+        dec = tinyklv::codecs::ber::dec::ber_length),
+    default(ty = u8, dec = tinyklv::codecs::binary::dec::be_u8),
+)]
+/// MISB 0806 RVT User Defined LS (Local Set)
+/// 
+/// `userDefinedLocalSet` -> [`crate::misb0806::Misb0806::user_defined_local_sets`]
+/// 
+/// Conveys user defined data, where the data type and a user assigned
+/// identifier are packed together within [`Misb0806UserDefined::data_type_id`]
+/// 
+/// For more information, see [Motion Imagery Standards Board (MISB)](https://nsgreg.nga.mil/misb.jsp)
+pub struct Misb0806UserDefined {
+    #[klv(key = 0x01)]
+    /// (Mandatory) Data type and identifier of the user data
+    /// 
+    /// The 2 most significant bits are the [`UserDataType`], and the
+    /// remaining 6 bits are the user assigned identifier
+    /// 
+    /// Len: 1
+    /// 
+    /// Units: None
+    pub data_type_id: u8,
+
+    #[klv(key = 0x02, dyn = true, dec = ops::to_bytes)]
+    /// (Mandatory) User data, of type [`Misb0806UserDefined::data_type`]
+    /// 
+    /// Len: Variable
+    /// 
+    /// Units: None
+    pub user_data: Vec<u8>,
+}
+
+#[cfg(any(
+    feature = "misb0806-4",
+))]
+/// [`Misb0806UserDefined`] implementation
+impl Misb0806UserDefined {
+    /// Data type of the [`Misb0806UserDefined::user_data`]
+    pub fn data_type(&self) -> UserDataType {
+        match self.data_type_id >> 6 {
+            0 => UserDataType::String,
+            1 => UserDataType::Signed,
+            2 => UserDataType::Unsigned,
+            _ => UserDataType::Experimental,
+        }
+    }
+
+    /// User assigned identifier of the [`Misb0806UserDefined::user_data`]
+    pub fn id(&self) -> u8 {
+        self.data_type_id & 0x3F
+    }
+
+    /// Encodes every present item of the [`Misb0806UserDefined`] in tag order
+    pub fn encode_value(&self) -> Vec<u8> {
+        let mut output = Vec::new();
+        crate::encode_item(&mut output, 0x01, &[self.data_type_id]);
+        crate::encode_item(&mut output, 0x02, &self.user_data);
+        output
+    }
+}
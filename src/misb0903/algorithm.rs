@@ -132,4 +132,20 @@ pub struct Misb0903Algorithm {
     /// assert_eq!(tinyklv::codecs::binary::dec::be_u64_lengthed(len)(&mut n_frames_hex).unwrap(), 10);
     /// ```
     pub n_frames: Option<u64>,
-}
\ No newline at end of file
+}
+#[cfg(any(
+    feature = "misb0903-6",
+))]
+/// [`Misb0903Algorithm`] implementation
+impl Misb0903Algorithm {
+    /// Encodes every present item of the [`Misb0903Algorithm`] in tag order
+    pub fn encode_value(&self) -> Vec<u8> {
+        let mut output = Vec::new();
+        crate::encode_item(&mut output, 0x01, &self.algorithm_id.to_be_bytes());
+        if let Some(x) = &self.name { crate::encode_item(&mut output, 0x02, x.as_bytes()) }
+        if let Some(x) = &self.version { crate::encode_item(&mut output, 0x03, x.as_bytes()) }
+        if let Some(x) = &self.class { crate::encode_item(&mut output, 0x04, x.as_bytes()) }
+        if let Some(x) = self.n_frames { crate::encode_item(&mut output, 0x05, &x.to_be_bytes()) }
+        output
+    }
+}
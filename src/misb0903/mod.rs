@@ -279,13 +279,13 @@ pub struct Misb0903 {
     /// Units: Degrees (°)
     pub vmti_vfov: Option<f64>,
 
-    // #[cfg(any(
-    //     feature = "misb0903-6",
-    // ))]
-    // #[klv(key = 0x0D)]
-    // /// (Assumed Optional) A Motion Imagery Identification System (MIIS)
-    // /// Core Identifier conformant with MISB ST 1204
-    // pub miis_id: Option<Misb1204Miis>,
+    #[cfg(any(
+        feature = "misb0903-6",
+    ))]
+    #[klv(key = 0x0D, dyn = true, dec = crate::decode_exact::<crate::misb1204::Misb1204Miis>)]
+    /// (Assumed Optional) A Motion Imagery Identification System (MIIS)
+    /// Core Identifier conformant with MISB ST 1204
+    pub miis_id: Option<crate::misb1204::Misb1204Miis>,
 
     #[cfg(any(
         feature = "misb0903-6",
@@ -313,4 +313,113 @@ pub struct Misb0903 {
     /// 
     /// Is "pseudo optional"; if not present, defaults to an empty vector.
     pub target_series: Vec<Misb0903Ontology>,
-}
\ No newline at end of file
+}
+
+#[cfg(feature = "misb0903-6")]
+/// [`Misb0903`] implementation
+impl Misb0903 {
+    /// Verifies the checksum of a complete standalone VMTI LS packet, from
+    /// the first byte of the UL to the last byte of the checksum value
+    /// 
+    /// Only applicable to standalone VMTI, where [`Misb0903::checksum`] is
+    /// the final item in the packet. VMTI embedded within MISB 0601 relies
+    /// on the checksum of the parent packet instead.
+    /// 
+    /// See [`crate::checksum::verify`]
+    pub fn verify_checksum(packet: &[u8]) -> Result<(), crate::checksum::ChecksumError> {
+        crate::checksum::verify(packet)
+    }
+
+    /// Encodes every present item of the [`Misb0903`] in tag order,
+    /// excluding the [`Misb0903::checksum`]
+    /// 
+    /// This is the value of the VMTI LS when embedded within MISB 0601,
+    /// see [`crate::misb0601::Misb0601::vmti_local_set`]
+    pub fn encode_value(&self) -> Vec<u8> {
+        let mut output = Vec::new();
+        if let Some(x) = self.precision_timestamp { crate::encode_item(&mut output, 0x02, &crate::misb0601::ops::from_precision_timestamp(x)) }
+        if let Some(x) = &self.vmti_system_name { crate::encode_item(&mut output, 0x03, x.as_bytes()) }
+        if let Some(x) = self.vmti_ls_version { crate::encode_item(&mut output, 0x04, &x.to_be_bytes()) }
+        if let Some(x) = self.total_num_targets_detected { crate::encode_item(&mut output, 0x05, &x.to_be_bytes()) }
+        crate::encode_item(&mut output, 0x06, &self.num_targets_reported.to_be_bytes());
+        if let Some(x) = self.frame_width { crate::encode_item(&mut output, 0x08, &x.to_be_bytes()) }
+        if let Some(x) = self.frame_height { crate::encode_item(&mut output, 0x09, &x.to_be_bytes()) }
+        if let Some(x) = &self.vmti_source_sensor { crate::encode_item(&mut output, 0x0A, x.as_bytes()) }
+        if let Some(x) = self.vmti_hfov { crate::encode_item(&mut output, 0x0B, &ops::from_hvfov(x)) }
+        if let Some(x) = self.vmti_vfov { crate::encode_item(&mut output, 0x0C, &ops::from_hvfov(x)) }
+        if let Some(x) = &self.miis_id { crate::encode_item(&mut output, 0x0D, &x.encode()) }
+        if !self.v_target_series.is_empty() {
+            crate::encode_item(&mut output, 0x65, &ops::from_series(self.v_target_series.iter().map(Misb0903Target::encode_vtarget)))
+        }
+        if !self.algorithm_series.is_empty() {
+            crate::encode_item(&mut output, 0x66, &ops::from_series(self.algorithm_series.iter().map(Misb0903Algorithm::encode_value)))
+        }
+        if !self.target_series.is_empty() {
+            crate::encode_item(&mut output, 0x67, &ops::from_series(self.target_series.iter().map(Misb0903Ontology::encode_value)))
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "misb0903-6")]
+mod test {
+    use super::*;
+    use tinyklv::prelude::*;
+    use crate::misb1204::{IdType, Misb1204Miis, Uuid, MIIS_VERSION};
+
+    #[test]
+    fn miis_id_roundtrip() {
+        let miis = Misb1204Miis {
+            version: MIIS_VERSION,
+            sensor_id: Some((IdType::Physical, Uuid([0x11; 16]))),
+            platform_id: Some((IdType::Managed, Uuid([0x22; 16]))),
+            window_id: None,
+            minor_id: None,
+        };
+        // --------------------------------------------------
+        // VMTI LS value: system name "VMTI", 2 targets
+        // reported, the MIIS ID, and then the frame width,
+        // which must not be consumed by the MIIS ID decoder
+        // --------------------------------------------------
+        let mut value = vec![0x03, 0x04, 0x56, 0x4d, 0x54, 0x49, 0x06, 0x01, 0x02];
+        crate::encode_item(&mut value, 0x0D, &miis.encode());
+        value.extend([0x08, 0x02, 0x07, 0x80]);
+        let decoded = Misb0903::decode(&mut value.as_slice()).unwrap();
+        assert_eq!(decoded.miis_id.as_ref(), Some(&miis));
+        assert_eq!(decoded.frame_width, Some(1920));
+        let redecoded = Misb0903::decode(&mut decoded.encode_value().as_slice()).unwrap();
+        assert_eq!(redecoded.miis_id, Some(miis));
+        assert_eq!(redecoded.frame_width, Some(1920));
+    }
+
+    #[test]
+    fn imapb_special_values() {
+        use primitives::{EnuMeasurements, Location};
+        assert_eq!(ops::from_hvfov(f64::NAN), vec![0xD0, 0x00]);
+        assert_eq!(ops::from_ll_offset(20.0), vec![0xE1, 0x00, 0x00]);
+        assert_eq!(ops::from_hae(-1000.0), vec![0xE0, 0x00]);
+        assert_eq!(ops::from_confidence(f64::INFINITY, 0), vec![0xC8]);
+        // --------------------------------------------------
+        // every field is still encoded, keeping the fixed
+        // length of the location
+        // --------------------------------------------------
+        let location = Location {
+            latitude: f64::NAN,
+            longitude: 200.0,
+            hae: 0.0,
+            measurements: EnuMeasurements {
+                sig_east: 1000.0,
+                sig_north: 0.0,
+                sig_up: 0.0,
+                rho_east_north: 0.0,
+                rho_east_up: 0.0,
+                rho_north_up: -2.0,
+            },
+        };
+        let encoded = location.encode();
+        assert_eq!(encoded.len(), <Location as crate::LengthBytes>::LENGTH_BYTES);
+        assert_eq!((encoded[0], encoded[4]), (0xD0, 0xE1));
+        assert_eq!((encoded[10], encoded[20]), (0xE1, 0xE0));
+    }
+}
@@ -114,4 +114,21 @@ pub struct Misb0903Ontology {
     /// or `skos:prefLabel`
     /// property of the entity
     pub label: Option<String>,
-}
\ No newline at end of file
+}
+#[cfg(any(
+    feature = "misb0903-6",
+))]
+/// [`Misb0903Ontology`] implementation
+impl Misb0903Ontology {
+    /// Encodes every present item of the [`Misb0903Ontology`] in tag order
+    pub fn encode_value(&self) -> Vec<u8> {
+        let mut output = Vec::new();
+        crate::encode_item(&mut output, 0x01, &self.ontology_id.to_be_bytes());
+        if let Some(x) = self.parent_id { crate::encode_item(&mut output, 0x02, &x.to_be_bytes()) }
+        crate::encode_item(&mut output, 0x03, self.onology_iri.as_bytes());
+        crate::encode_item(&mut output, 0x04, self.entity_iri.as_bytes());
+        if let Some(x) = &self.version_iri { crate::encode_item(&mut output, 0x05, x.as_bytes()) }
+        if let Some(x) = &self.label { crate::encode_item(&mut output, 0x06, x.as_bytes()) }
+        output
+    }
+}
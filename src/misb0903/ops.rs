@@ -135,4 +135,84 @@ pub fn to_confidence(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<f64>
         let res = imap.from_imap(value).map_err(|e| tinyklv::err!().add_context(input, &checkpoint, e.into()));
         res
     }
-}
\ No newline at end of file
+}
+#[inline(always)]
+/// See: [`crate::misb0903::Misb0903`]
+/// 
+/// * [`crate::misb0903::Misb0903::vmti_hfov`]
+/// * [`crate::misb0903::Misb0903::vmti_vfov`]
+/// 
+/// This encoder cannot be `const fn` due to floating point arithmetic
+pub fn from_hvfov(input: f64) -> Vec<u8> {
+    IMAPB_0_180_2_F64.to_imap_or_special(input)
+}
+
+#[inline(always)]
+/// See: [`crate::misb0903::Misb0903`]
+/// 
+/// * [`crate::misb0903::Misb0903Target::target_location_lat_offset`]
+/// * [`crate::misb0903::Misb0903Target::target_location_lon_offset`]
+/// * [`crate::misb0903::Misb0903Target::bbox_tl_lat_offset`]
+/// * [`crate::misb0903::Misb0903Target::bbox_tl_lon_offset`]
+/// * [`crate::misb0903::Misb0903Target::bbox_br_lat_offset`]
+/// * [`crate::misb0903::Misb0903Target::bbox_br_lon_offset`]
+/// 
+/// This encoder cannot be `const fn` due to floating point arithmetic
+pub fn from_ll_offset(input: f64) -> Vec<u8> {
+    IMAPB_N19P2_19P2_3_F64.to_imap_or_special(input)
+}
+
+#[inline(always)]
+/// See: [`crate::misb0903::Misb0903`]
+/// 
+/// * [`crate::misb0903::Misb0903Target::target_hae`]
+/// 
+/// This encoder cannot be `const fn` due to floating point arithmetic
+pub fn from_hae(input: f64) -> Vec<u8> {
+    IMAPB_N900_19K_2_F64.to_imap_or_special(input)
+}
+
+/// Number of bytes used when encoding confidence values
+/// 
+/// See [`from_confidence`]
+pub const CONFIDENCE_LEN: usize = 2;
+
+/// Encodes a confidence value in the range [0, 100], using `len` bytes,
+/// clamped to 1 to 8 bytes
+/// 
+/// See [`to_confidence`]
+pub fn from_confidence(input: f64, len: usize) -> Vec<u8> {
+    let len = len.clamp(1, 8);
+    match ImapB::new(0.0_f64, 100.0_f64, len) {
+        Ok(imap) => imap.to_imap_or_special(input),
+        // --------------------------------------------------
+        // unreachable, since [0, 100] fits within a single
+        // byte
+        // --------------------------------------------------
+        Err(_) => crate::misb1201::Value::Special(crate::misb1201::SpecialValue::ImapAboveMaximum).to_imap(len),
+    }
+}
+
+/// Encodes a BER-OID value of up to 128 bits
+/// 
+/// See [`crate::misb0903::Misb0903Target::target_id`]
+pub fn from_ber_oid_u128(input: u128) -> Vec<u8> {
+    let mut output = vec![(input & 0x7F) as u8];
+    let mut input = input >> 7;
+    while input > 0 {
+        output.push((input & 0x7F) as u8 | 0x80);
+        input >>= 7;
+    }
+    output.reverse();
+    output
+}
+
+/// Encodes a Series, where each element is preceded by its
+/// BER encoded length
+pub(crate) fn from_series(input: impl IntoIterator<Item = Vec<u8>>) -> Vec<u8> {
+    input.into_iter().fold(Vec::new(), |mut output, value| {
+        output.extend(tinyklv::codecs::ber::enc::ber_length(&value.len()));
+        output.extend(value);
+        output
+    })
+}
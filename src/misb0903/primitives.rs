@@ -96,6 +96,12 @@ impl tinyklv::prelude::Decode<&[u8]> for Color {
         Ok(Self { red, green, blue })
     }
 }
+/// [`Color`] implementation of [`tinyklv::prelude::Encode`]
+impl tinyklv::prelude::Encode<Vec<u8>> for Color {
+    fn encode(&self) -> Vec<u8> {
+        vec![self.red, self.green, self.blue]
+    }
+}
 
 #[derive(Debug)]
 /// A location
@@ -126,6 +132,17 @@ impl tinyklv::prelude::Decode<&[u8]> for Location {
         Ok(Self { latitude, longitude, hae, measurements })
     }
 }
+/// [`Location`] implementation of [`tinyklv::prelude::Encode`]
+impl tinyklv::prelude::Encode<Vec<u8>> for Location {
+    fn encode(&self) -> Vec<u8> {
+        let mut output = Vec::with_capacity(<Self as crate::LengthBytes>::LENGTH_BYTES);
+        output.extend(ops::IMAPB_N90_90_4_F64.to_imap_or_special(self.latitude));
+        output.extend(ops::IMAPB_N180_180_4_F64.to_imap_or_special(self.longitude));
+        output.extend(ops::from_hae(self.hae));
+        output.extend(self.measurements.encode());
+        output
+    }
+}
 
 #[derive(Debug)]
 /// Motion
@@ -230,6 +247,22 @@ impl tinyklv::prelude::Decode<&[u8]> for EnuMeasurements {
         Ok(Self { sig_east, sig_north, sig_up, rho_east_north, rho_east_up, rho_north_up })
     }
 }
+/// [`EnuMeasurements`] implementation of [`tinyklv::prelude::Encode`]
+impl tinyklv::prelude::Encode<Vec<u8>> for EnuMeasurements {
+    fn encode(&self) -> Vec<u8> {
+        [
+            (&ops::IMAPB_0_650_2_F64, self.sig_east),
+            (&ops::IMAPB_0_650_2_F64, self.sig_north),
+            (&ops::IMAPB_0_650_2_F64, self.sig_up),
+            (&ops::IMAPB_N1_1_2_F64, self.rho_east_north),
+            (&ops::IMAPB_N1_1_2_F64, self.rho_east_up),
+            (&ops::IMAPB_N1_1_2_F64, self.rho_north_up),
+        ]
+        .into_iter()
+        .flat_map(|(imap, value)| imap.to_imap_or_special(value))
+        .collect()
+    }
+}
 
 #[derive(Const)]
 #[armtype(u8)]
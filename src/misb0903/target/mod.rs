@@ -574,6 +574,50 @@ impl Misb0903Target {
     pub fn decode_vec_vtargets(input: &mut &[u8]) -> winnow::PResult<Vec<Self>> {
         winnow::combinator::repeat(0.., Self::decode_vtarget).parse_next(input)
     }
+
+    /// Encodes a VTarget Pack: the BER-OID encoded [`Misb0903Target::target_id`],
+    /// followed by [`Misb0903Target::encode_value`]
+    /// 
+    /// A missing target id is encoded as `0`
+    pub fn encode_vtarget(&self) -> Vec<u8> {
+        let mut output = ops::from_ber_oid_u128(self.target_id.unwrap_or_default());
+        output.extend(self.encode_value());
+        output
+    }
+
+    /// Encodes every present item of the [`Misb0903Target`] in tag order,
+    /// excluding the [`Misb0903Target::target_id`]
+    pub fn encode_value(&self) -> Vec<u8> {
+        let mut output = Vec::new();
+        if let Some(x) = &self.target_centroid { crate::encode_item(&mut output, 0x01, &x.num.to_be_bytes()) }
+        if let Some(x) = &self.bbox_tl { crate::encode_item(&mut output, 0x02, &x.num.to_be_bytes()) }
+        if let Some(x) = &self.bbox_br { crate::encode_item(&mut output, 0x03, &x.num.to_be_bytes()) }
+        if let Some(x) = self.target_priority { crate::encode_item(&mut output, 0x04, &[x]) }
+        if let Some(x) = self.target_confidence_level { crate::encode_item(&mut output, 0x05, &[x]) }
+        if let Some(x) = self.target_history { crate::encode_item(&mut output, 0x06, &x.to_be_bytes()) }
+        if let Some(x) = self.percentage_of_target_pixels { crate::encode_item(&mut output, 0x07, &[x]) }
+        if let Some(x) = &self.target_color { crate::encode_item(&mut output, 0x08, &x.encode()) }
+        if let Some(x) = self.target_intensity { crate::encode_item(&mut output, 0x09, &x.to_be_bytes()) }
+        if let Some(x) = self.target_location_lat_offset { crate::encode_item(&mut output, 0x0A, &ops::from_ll_offset(x)) }
+        if let Some(x) = self.target_location_lon_offset { crate::encode_item(&mut output, 0x0B, &ops::from_ll_offset(x)) }
+        if let Some(x) = self.target_hae { crate::encode_item(&mut output, 0x0C, &ops::from_hae(x)) }
+        if let Some(x) = self.bbox_tl_lat_offset { crate::encode_item(&mut output, 0x0D, &ops::from_ll_offset(x)) }
+        if let Some(x) = self.bbox_tl_lon_offset { crate::encode_item(&mut output, 0x0E, &ops::from_ll_offset(x)) }
+        if let Some(x) = self.bbox_br_lat_offset { crate::encode_item(&mut output, 0x0F, &ops::from_ll_offset(x)) }
+        if let Some(x) = self.bbox_br_lon_offset { crate::encode_item(&mut output, 0x10, &ops::from_ll_offset(x)) }
+        if let Some(x) = &self.target_location { crate::encode_item(&mut output, 0x11, &x.encode()) }
+        if let Some(x) = &self.geospatial_contour_series {
+            crate::encode_item(&mut output, 0x12, &ops::from_series(x.iter().map(Location::encode)))
+        }
+        if let Some(x) = self.centroid_rows { crate::encode_item(&mut output, 0x13, &x.to_be_bytes()) }
+        if let Some(x) = self.centroid_cols { crate::encode_item(&mut output, 0x14, &x.to_be_bytes()) }
+        if let Some(x) = self.algorithm_id { crate::encode_item(&mut output, 0x16, &x.to_be_bytes()) }
+        if let Some(x) = &self.detection_status { crate::encode_item(&mut output, 0x17, &x.encode()) }
+        if !self.v_object_series.is_empty() {
+            crate::encode_item(&mut output, 0x6B, &ops::from_series(self.v_object_series.iter().map(Misb0903Object::encode_value)))
+        }
+        output
+    }
 }
 
 // pub struct Misb0903Algorithm {}
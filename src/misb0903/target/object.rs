@@ -150,4 +150,34 @@ pub struct Misb0903Feature {
     /// 
     /// Units: Percent (%)
     pub confidence: f64,
-}
\ No newline at end of file
+}
+#[cfg(any(
+    feature = "misb0903-6",
+))]
+/// [`Misb0903Object`] implementation
+impl Misb0903Object {
+    /// Encodes every present item of the [`Misb0903Object`] in tag order
+    pub fn encode_value(&self) -> Vec<u8> {
+        let mut output = Vec::new();
+        crate::encode_item(&mut output, 0x03, &self.ontology_id.to_be_bytes());
+        crate::encode_item(&mut output, 0x04, &ops::from_confidence(self.confidence, ops::CONFIDENCE_LEN));
+        if !self.v_feature_series.is_empty() {
+            crate::encode_item(&mut output, 0x05, &ops::from_series(self.v_feature_series.iter().map(Misb0903Feature::encode_value)))
+        }
+        output
+    }
+}
+
+#[cfg(any(
+    feature = "misb0903-6",
+))]
+/// [`Misb0903Feature`] implementation
+impl Misb0903Feature {
+    /// Encodes every present item of the [`Misb0903Feature`] in tag order
+    pub fn encode_value(&self) -> Vec<u8> {
+        let mut output = Vec::new();
+        crate::encode_item(&mut output, 0x03, &self.ontology_id.to_be_bytes());
+        crate::encode_item(&mut output, 0x04, &ops::from_confidence(self.confidence, ops::CONFIDENCE_LEN));
+        output
+    }
+}
@@ -0,0 +1,311 @@
+// --------------------------------------------------
+// tinyklv
+// --------------------------------------------------
+use tinyklv::prelude::*;
+
+// --------------------------------------------------
+// local
+// --------------------------------------------------
+pub mod ops;
+pub mod primitives;
+pub use primitives::{DepthMap, SectionDataPack, RangeImageEnumerations};
+pub use primitives::{RangeImageCompressionMethod, RangeImageDataType, RangeImageSource};
+
+#[cfg(any(
+    feature = "misb1002-2",
+))]
+#[derive(Debug, Default)]
+/// Range Motion Imagery
+/// 
+/// MISB Standard 1002
+/// 
+/// Range images are depth maps, aligned with the Motion Imagery frame,
+/// split into one or more sections. Each section is conveyed within a
+/// [`SectionDataPack`].
+/// 
+/// ***Unlike the other local sets of this crate, this struct is decoded
+/// manually, since the Section Data Pack item (tag 20) is repeated once
+/// per section.***
+/// 
+/// See [`crate::misb0601::Misb0601::range_image_local_set`]
+/// 
+/// For more information, see [Motion Imagery Standards Board (MISB)](https://nsgreg.nga.mil/misb.jsp)
+pub struct Misb1002 {
+    #[cfg(any(
+        feature = "misb1002-2",
+    ))]
+    /// (Mandatory) Microsecond count from Epoch of 1970
+    /// See MISP Time System - MISB ST 0603
+    /// 
+    /// Key: 0x01
+    /// 
+    /// Len: 8
+    /// 
+    /// Units: Microseconds (μs)
+    pub precision_timestamp: Option<chrono::DateTime<chrono::Utc>>,
+
+    #[cfg(any(
+        feature = "misb1002-2",
+    ))]
+    /// (Mandatory) Version number of the MISB ST 1002 document used
+    /// to encode the range image
+    /// 
+    /// Key: 0x0B
+    /// 
+    /// Len: Variable
+    /// 
+    /// Units: None
+    pub document_version: Option<u64>,
+
+    #[cfg(any(
+        feature = "misb1002-2",
+    ))]
+    /// (Mandatory) Source, data type and compression method of the
+    /// range image, see [`RangeImageEnumerations`]
+    /// 
+    /// Key: 0x0C
+    /// 
+    /// Len: Variable
+    /// 
+    /// Units: None
+    pub range_image_enumerations: Option<RangeImageEnumerations>,
+
+    #[cfg(any(
+        feature = "misb1002-2",
+    ))]
+    /// (Optional) Range of a single point of the Motion Imagery frame
+    /// 
+    /// Key: 0x0D
+    /// 
+    /// Len: 4 or 8
+    /// 
+    /// Units: Meters (m)
+    pub single_point_range: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb1002-2",
+    ))]
+    /// (Optional) Uncertainty of the [`Misb1002::single_point_range`]
+    /// 
+    /// Key: 0x0E
+    /// 
+    /// Len: 4 or 8
+    /// 
+    /// Units: Meters (m)
+    pub single_point_range_uncertainty: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb1002-2",
+    ))]
+    /// (Optional) Row of the [`Misb1002::single_point_range`] within
+    /// the Motion Imagery frame
+    /// 
+    /// Key: 0x0F
+    /// 
+    /// Len: 4 or 8
+    /// 
+    /// Units: Pixels
+    pub single_point_range_row: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb1002-2",
+    ))]
+    /// (Optional) Column of the [`Misb1002::single_point_range`] within
+    /// the Motion Imagery frame
+    /// 
+    /// Key: 0x10
+    /// 
+    /// Len: 4 or 8
+    /// 
+    /// Units: Pixels
+    pub single_point_range_column: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb1002-2",
+    ))]
+    /// (Optional) Number of sections along the x-axis (columns)
+    /// 
+    /// Key: 0x11
+    /// 
+    /// Len: Variable
+    /// 
+    /// Units: None
+    pub number_of_sections_x: Option<u64>,
+
+    #[cfg(any(
+        feature = "misb1002-2",
+    ))]
+    /// (Optional) Number of sections along the y-axis (rows)
+    /// 
+    /// Key: 0x12
+    /// 
+    /// Len: Variable
+    /// 
+    /// Units: None
+    pub number_of_sections_y: Option<u64>,
+
+    #[cfg(any(
+        feature = "misb1002-2",
+    ))]
+    /// (Optional) MISB ST 1202 Generalized Transformation LS, relating
+    /// the range image to the Motion Imagery frame, kept as raw bytes
+    /// 
+    /// Key: 0x13
+    /// 
+    /// Len: Variable
+    /// 
+    /// Units: None
+    pub generalized_transformation: Option<Vec<u8>>,
+
+    #[cfg(any(
+        feature = "misb1002-2",
+    ))]
+    /// (Mandatory) Section Data Packs, one per section of the range image
+    /// 
+    /// Key: 0x14, repeated
+    /// 
+    /// Is "pseudo optional"; if not present, defaults to an empty vector.
+    pub section_data_packs: Vec<SectionDataPack>,
+}
+
+#[cfg(feature = "misb1002-2")]
+/// [`Misb1002`] implementation
+impl Misb1002 {
+    /// Returns the [`SectionDataPack`] at section `x` and `y`, if present
+    pub fn section(&self, x: u64, y: u64) -> Option<&SectionDataPack> {
+        self.section_data_packs.iter().find(|s| s.section_x == x && s.section_y == y)
+    }
+
+    /// Encodes every present item of the [`Misb1002`] in tag order
+    /// 
+    /// This is the value of the Range Image LS when embedded within
+    /// MISB 0601, see [`crate::misb0601::Misb0601::range_image_local_set`]
+    pub fn encode_value(&self) -> Vec<u8> {
+        let mut output = Vec::new();
+        if let Some(x) = self.precision_timestamp { crate::encode_item(&mut output, 0x01, &crate::misb0601::ops::from_precision_timestamp(x)) }
+        if let Some(x) = self.document_version { crate::encode_item(&mut output, 0x0B, &tinyklv::codecs::ber::enc::ber_oid(&x)) }
+        if let Some(x) = &self.range_image_enumerations { crate::encode_item(&mut output, 0x0C, &x.encode()) }
+        if let Some(x) = self.single_point_range { crate::encode_item(&mut output, 0x0D, &ops::from_float(x)) }
+        if let Some(x) = self.single_point_range_uncertainty { crate::encode_item(&mut output, 0x0E, &ops::from_float(x)) }
+        if let Some(x) = self.single_point_range_row { crate::encode_item(&mut output, 0x0F, &ops::from_float(x)) }
+        if let Some(x) = self.single_point_range_column { crate::encode_item(&mut output, 0x10, &ops::from_float(x)) }
+        if let Some(x) = self.number_of_sections_x { crate::encode_item(&mut output, 0x11, &tinyklv::codecs::ber::enc::ber_oid(&x)) }
+        if let Some(x) = self.number_of_sections_y { crate::encode_item(&mut output, 0x12, &tinyklv::codecs::ber::enc::ber_oid(&x)) }
+        if let Some(x) = &self.generalized_transformation { crate::encode_item(&mut output, 0x13, x) }
+        for x in &self.section_data_packs { crate::encode_item(&mut output, 0x14, &x.encode()) }
+        output
+    }
+}
+
+#[cfg(feature = "misb1002-2")]
+/// [`Misb1002`] implementation of [`tinyklv::prelude::Decode`]
+/// 
+/// Decodes every item until `input` is exhausted. Unknown keys are skipped.
+impl tinyklv::prelude::Decode<&[u8]> for Misb1002 {
+    fn decode(input: &mut &[u8]) -> winnow::PResult<Self> {
+        let mut output = Self::default();
+        while !input.is_empty() {
+            let key = tinyklv::codecs::ber::dec::ber_oid::<u64>.parse_next(input)?;
+            let len = tinyklv::codecs::ber::dec::ber_length.parse_next(input)?;
+            let mut value = winnow::token::take(len).parse_next(input)?;
+            let value = &mut value;
+            match key {
+                0x01 => output.precision_timestamp = Some(crate::misb0601::ops::to_precision_timestamp(value)?),
+                0x0B => output.document_version = Some(tinyklv::codecs::ber::dec::ber_oid::<u64>.parse_next(value)?),
+                0x0C => output.range_image_enumerations = Some(RangeImageEnumerations::decode(value)?),
+                0x0D => output.single_point_range = Some(ops::to_float(len)(value)?),
+                0x0E => output.single_point_range_uncertainty = Some(ops::to_float(len)(value)?),
+                0x0F => output.single_point_range_row = Some(ops::to_float(len)(value)?),
+                0x10 => output.single_point_range_column = Some(ops::to_float(len)(value)?),
+                0x11 => output.number_of_sections_x = Some(tinyklv::codecs::ber::dec::ber_oid::<u64>.parse_next(value)?),
+                0x12 => output.number_of_sections_y = Some(tinyklv::codecs::ber::dec::ber_oid::<u64>.parse_next(value)?),
+                0x13 => output.generalized_transformation = Some(value.to_vec()),
+                0x14 => output.section_data_packs.push(SectionDataPack::decode(len)(value)?),
+                _ => (),
+            }
+        }
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "misb1002-2")]
+mod test {
+    use super::*;
+    use tinyklv::prelude::*;
+
+    #[test]
+    fn encode_roundtrip() {
+        let range_image = Misb1002 {
+            document_version: Some(2),
+            range_image_enumerations: Some(RangeImageEnumerations {
+                compression_method: RangeImageCompressionMethod::PlanarFit,
+                data_type: RangeImageDataType::DepthRangeImage,
+                source: RangeImageSource::RangeSensor,
+            }),
+            number_of_sections_x: Some(2),
+            number_of_sections_y: Some(1),
+            section_data_packs: vec![
+                SectionDataPack {
+                    section_x: 1,
+                    section_y: 1,
+                    ranges: Some(DepthMap::new(vec![2, 3], vec![10.0, 10.5, 11.0, 12.0, 12.5, 13.0]).unwrap()),
+                    uncertainties: None,
+                    plane_x_scale: None,
+                    plane_y_scale: None,
+                    plane_constant: None,
+                },
+                SectionDataPack {
+                    section_x: 2,
+                    section_y: 1,
+                    ranges: None,
+                    uncertainties: None,
+                    plane_x_scale: Some(0.5),
+                    plane_y_scale: Some(2.0),
+                    plane_constant: Some(100.0),
+                },
+            ],
+            ..Default::default()
+        };
+        let encoded = range_image.encode_value();
+        let decoded = Misb1002::decode(&mut encoded.as_slice()).unwrap();
+        assert_eq!(decoded.document_version, Some(2));
+        assert!(matches!(
+            decoded.range_image_enumerations,
+            Some(RangeImageEnumerations { compression_method: RangeImageCompressionMethod::PlanarFit, source: RangeImageSource::RangeSensor, .. }),
+        ));
+        assert_eq!(decoded.section_data_packs, range_image.section_data_packs);
+        // --------------------------------------------------
+        // measured section
+        // --------------------------------------------------
+        let section = decoded.section(1, 1).unwrap();
+        assert_eq!(section.range_at(1, 2), Some(13.0));
+        assert_eq!(section.range_at(2, 0), None);
+        assert_eq!(section.ranges.as_ref().unwrap().rows().nth(1), Some(&[12.0, 12.5, 13.0][..]));
+        // --------------------------------------------------
+        // plane fit section
+        // --------------------------------------------------
+        assert_eq!(decoded.section(2, 1).unwrap().range_at(3, 4), Some(108.0));
+        assert_eq!(decoded.encode_value(), encoded);
+    }
+
+    #[test]
+    fn section_trailing_bytes() {
+        let section = SectionDataPack {
+            section_x: 2,
+            section_y: 1,
+            ranges: None,
+            uncertainties: None,
+            plane_x_scale: Some(0.5),
+            plane_y_scale: Some(2.0),
+            plane_constant: Some(100.0),
+        };
+        let mut encoded = section.encode();
+        assert_eq!(SectionDataPack::decode(encoded.len())(&mut encoded.as_slice()), Ok(section));
+        // --------------------------------------------------
+        // an eighth element is rejected
+        // --------------------------------------------------
+        encoded.extend([0x01, 0x00]);
+        assert!(SectionDataPack::decode(encoded.len())(&mut encoded.as_slice()).is_err());
+    }
+}
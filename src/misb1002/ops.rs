@@ -0,0 +1,82 @@
+// --------------------------------------------------
+// tinyklv
+// --------------------------------------------------
+use tinyklv::prelude::*;
+
+// --------------------------------------------------
+// local
+// --------------------------------------------------
+use crate::misb1002::primitives::DepthMap;
+
+/// See [`crate::misb1002::Misb1002`]
+/// 
+/// * [`crate::misb1002::Misb1002::single_point_range`]
+/// * [`crate::misb1002::Misb1002::single_point_range_uncertainty`]
+/// * [`crate::misb1002::Misb1002::single_point_range_row`]
+/// * [`crate::misb1002::Misb1002::single_point_range_column`]
+/// * [`crate::misb1002::SectionDataPack::plane_x_scale`]
+/// * [`crate::misb1002::SectionDataPack::plane_y_scale`]
+/// * [`crate::misb1002::SectionDataPack::plane_constant`]
+/// 
+/// An IEEE 754 float of length `len`, which is either 4 or 8 bytes
+pub fn to_float(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<f64> {
+    move |input: &mut &[u8]| {
+        let value = winnow::token::take(len).parse_next(input)?;
+        crate::misb1303::float_from_be(value).ok_or_else(|| tinyklv::err!())
+    }
+}
+
+#[inline(always)]
+/// See [`to_float`]
+/// 
+/// Always encoded using 8 bytes
+pub const fn from_float(input: f64) -> [u8; 8] {
+    input.to_be_bytes()
+}
+
+/// See [`crate::misb1002::SectionDataPack`]
+/// 
+/// * [`crate::misb1002::SectionDataPack::ranges`]
+/// * [`crate::misb1002::SectionDataPack::uncertainties`]
+/// 
+/// A 2-dimensional [`crate::misb1303::Mdap`] of length `len`, where
+/// the first dimension is rows and the second is columns
+/// 
+/// # Example
+/// 
+/// ```
+/// use misb::misb1002::ops::to_depth_map;
+/// // 1 row, 2 columns, 2-byte IMAPB elements in [0.0, 100.0]
+/// let mut input: &[u8] = &[
+///     0x02, 0x01, 0x02, 0x02, 0x02,
+///     0x00, 0x00, 0x00, 0x00, 0x42, 0xC8, 0x00, 0x00,
+///     0x00, 0x00, 0x01, 0x00,
+/// ];
+/// let depth = to_depth_map(input.len())(&mut input).unwrap();
+/// assert_eq!(depth.dimensions, vec![1, 2]);
+/// assert_eq!(depth.get(&[0, 1]), Some(&1.0));
+/// ```
+pub fn to_depth_map(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<DepthMap> {
+    move |input: &mut &[u8]| {
+        let output = DepthMap::decode(len)(input)?;
+        match output.dimensions.len() {
+            2 => Ok(output),
+            _ => Err(tinyklv::err!()),
+        }
+    }
+}
+
+#[inline(always)]
+/// See [`to_depth_map`]
+/// 
+/// Always encoded using [`crate::misb1303::Apa::Natural`], with 4-byte elements
+pub fn from_depth_map(input: &DepthMap) -> Vec<u8> {
+    input.encode_natural()
+}
+
+/// See [`crate::misb0601::Misb0601::range_image_local_set`]
+/// 
+/// A [`crate::misb1002::Misb1002`] of length `len`, see [`crate::decode_exact`]
+pub fn to_range_image(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<crate::misb1002::Misb1002> {
+    crate::decode_exact::<crate::misb1002::Misb1002>(len)
+}
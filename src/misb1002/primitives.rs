@@ -0,0 +1,202 @@
+// --------------------------------------------------
+// tinyklv
+// --------------------------------------------------
+use tinyklv::prelude::*;
+
+// --------------------------------------------------
+// external
+// --------------------------------------------------
+use thisenum::Const;
+
+// --------------------------------------------------
+// local
+// --------------------------------------------------
+use crate::misb1002::ops;
+
+/// A 2-dimensional buffer of range (or range uncertainty) values,
+/// where the first dimension is rows and the second is columns
+/// 
+/// Units: Meters (m)
+/// 
+/// See [`SectionDataPack`]
+pub type DepthMap = crate::misb1303::Mdap<f32>;
+
+#[derive(Debug, Clone, PartialEq)]
+/// Section Data Pack (SDP), containing the range measurements of a
+/// single section of the range image
+/// 
+/// Each element is preceded by its BER encoded length, and the pack
+/// may be truncated after any element. A zero-length element is
+/// considered omitted.
+/// 
+/// See [`crate::misb1002::Misb1002::section_data_packs`]
+pub struct SectionDataPack {
+    /// Section number along the x-axis (columns), starting at 1
+    pub section_x: u64,
+    /// Section number along the y-axis (rows), starting at 1
+    pub section_y: u64,
+    /// Range measurements of the section
+    /// 
+    /// Omitted when the section is fully described by its plane
+    /// fit coefficients, see [`SectionDataPack::range_at`]
+    pub ranges: Option<DepthMap>,
+    /// Uncertainty of each range measurement of the section
+    pub uncertainties: Option<DepthMap>,
+    /// Plane fit coefficient along the x-axis (columns)
+    pub plane_x_scale: Option<f64>,
+    /// Plane fit coefficient along the y-axis (rows)
+    pub plane_y_scale: Option<f64>,
+    /// Plane fit constant
+    pub plane_constant: Option<f64>,
+}
+/// [`SectionDataPack`] implementation
+impl SectionDataPack {
+    /// Decodes a [`SectionDataPack`], where `len` is the length of the
+    /// entire pack
+    pub fn decode(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<Self> {
+        move |input| {
+            let mut value = winnow::token::take(len).parse_next(input)?;
+            let mut elements = winnow::combinator::repeat(2..=7, Self::element)
+                .parse_next(&mut value)
+                .map(|x: Vec<&[u8]>| x.into_iter())?;
+            // --------------------------------------------------
+            // nothing may follow the seventh element
+            // --------------------------------------------------
+            if !value.is_empty() { return Err(tinyklv::err!()) }
+            let mut next = || elements.next().filter(|x| !x.is_empty());
+            let section_x = next().ok_or_else(|| tinyklv::err!())?;
+            let section_y = next().ok_or_else(|| tinyklv::err!())?;
+            let section_x = tinyklv::codecs::ber::dec::ber_oid::<u64>.parse_next(&mut &section_x[..])?;
+            let section_y = tinyklv::codecs::ber::dec::ber_oid::<u64>.parse_next(&mut &section_y[..])?;
+            let ranges = next().map(|x| ops::to_depth_map(x.len())(&mut &x[..])).transpose()?;
+            let uncertainties = next().map(|x| ops::to_depth_map(x.len())(&mut &x[..])).transpose()?;
+            let plane_x_scale = next().map(|x| ops::to_float(x.len())(&mut &x[..])).transpose()?;
+            let plane_y_scale = next().map(|x| ops::to_float(x.len())(&mut &x[..])).transpose()?;
+            let plane_constant = next().map(|x| ops::to_float(x.len())(&mut &x[..])).transpose()?;
+            Ok(Self { section_x, section_y, ranges, uncertainties, plane_x_scale, plane_y_scale, plane_constant })
+        }
+    }
+
+    /// Decodes a single BER length prefixed element of the pack
+    fn element<'a>(input: &mut &'a [u8]) -> winnow::PResult<&'a [u8]> {
+        let len = tinyklv::codecs::ber::dec::ber_length.parse_next(input)?;
+        winnow::token::take(len).parse_next(input)
+    }
+
+    /// Returns the range at `row` and `column` of the section
+    /// 
+    /// Uses the [`SectionDataPack::ranges`] when present, otherwise
+    /// evaluates the plane fit: `x_scale * column + y_scale * row + constant`
+    pub fn range_at(&self, row: usize, column: usize) -> Option<f32> {
+        match &self.ranges {
+            Some(ranges) => ranges.get(&[row, column]).copied(),
+            None => {
+                let constant = self.plane_constant?;
+                let x_scale = self.plane_x_scale.unwrap_or_default();
+                let y_scale = self.plane_y_scale.unwrap_or_default();
+                Some((x_scale * column as f64 + y_scale * row as f64 + constant) as f32)
+            }
+        }
+    }
+}
+/// [`SectionDataPack`] implementation of [`tinyklv::prelude::Encode`]
+impl tinyklv::prelude::Encode<Vec<u8>> for SectionDataPack {
+    fn encode(&self) -> Vec<u8> {
+        let mut elements = vec![
+            tinyklv::codecs::ber::enc::ber_oid(&self.section_x),
+            tinyklv::codecs::ber::enc::ber_oid(&self.section_y),
+            self.ranges.as_ref().map(ops::from_depth_map).unwrap_or_default(),
+            self.uncertainties.as_ref().map(ops::from_depth_map).unwrap_or_default(),
+            self.plane_x_scale.map(|x| ops::from_float(x).to_vec()).unwrap_or_default(),
+            self.plane_y_scale.map(|x| ops::from_float(x).to_vec()).unwrap_or_default(),
+            self.plane_constant.map(|x| ops::from_float(x).to_vec()).unwrap_or_default(),
+        ];
+        // --------------------------------------------------
+        // trailing omitted elements are not encoded
+        // --------------------------------------------------
+        while elements.len() > 2 && elements.last().is_some_and(|x| x.is_empty()) { elements.pop(); }
+        let mut output = Vec::new();
+        for element in elements {
+            output.extend(tinyklv::codecs::ber::enc::ber_length(&element.len()));
+            output.extend(element);
+        }
+        output
+    }
+}
+
+#[derive(Debug)]
+/// Range Image Enumerations, packed into a single BER-OID value
+/// 
+/// From least significant bit: the [`RangeImageCompressionMethod`] (3 bits),
+/// the [`RangeImageDataType`] (3 bits), and the [`RangeImageSource`] (1 bit)
+/// 
+/// See [`crate::misb1002::Misb1002::range_image_enumerations`]
+pub struct RangeImageEnumerations {
+    pub compression_method: RangeImageCompressionMethod,
+    pub data_type: RangeImageDataType,
+    pub source: RangeImageSource,
+}
+/// [`RangeImageEnumerations`] implementation of [`tinyklv::prelude::Decode`]
+impl tinyklv::prelude::Decode<&[u8]> for RangeImageEnumerations {
+    fn decode(input: &mut &[u8]) -> winnow::PResult<Self> {
+        let value = tinyklv::codecs::ber::dec::ber_oid::<u64>.parse_next(input)?;
+        Ok(Self {
+            compression_method: RangeImageCompressionMethod::try_from((value & 0x07) as u8).map_err(|_| tinyklv::err!())?,
+            data_type: RangeImageDataType::try_from(((value >> 3) & 0x07) as u8).map_err(|_| tinyklv::err!())?,
+            source: RangeImageSource::try_from(((value >> 6) & 0x01) as u8).map_err(|_| tinyklv::err!())?,
+        })
+    }
+}
+/// [`RangeImageEnumerations`] implementation of [`tinyklv::prelude::Encode`]
+impl tinyklv::prelude::Encode<Vec<u8>> for RangeImageEnumerations {
+    fn encode(&self) -> Vec<u8> {
+        let value = ((*self.source.value() as u64) << 6)
+            | ((*self.data_type.value() as u64) << 3)
+            | (*self.compression_method.value() as u64);
+        tinyklv::codecs::ber::enc::ber_oid(&value)
+    }
+}
+
+#[derive(Const)]
+#[armtype(u8)]
+/// Compression method of the range image
+/// 
+/// See [`RangeImageEnumerations`]
+pub enum RangeImageCompressionMethod {
+    #[value = 0]
+    /// Every section contains its range measurements
+    NoCompression,
+    #[value = 1]
+    /// Sections may be described by plane fit coefficients instead of
+    /// range measurements, see [`SectionDataPack::range_at`]
+    PlanarFit,
+}
+
+#[derive(Const)]
+#[armtype(u8)]
+/// Type of range values within the range image
+/// 
+/// See [`RangeImageEnumerations`]
+pub enum RangeImageDataType {
+    #[value = 0]
+    /// Ranges are measured from the perspective center of the sensor
+    PerspectiveRangeImage,
+    #[value = 1]
+    /// Ranges are measured from a plane at the perspective center,
+    /// perpendicular to the sensor boresight
+    DepthRangeImage,
+}
+
+#[derive(Const)]
+#[armtype(u8)]
+/// Source of the range image
+/// 
+/// See [`RangeImageEnumerations`]
+pub enum RangeImageSource {
+    #[value = 0]
+    /// Computationally extracted, e.g. from stereo imagery
+    ComputationallyExtracted,
+    #[value = 1]
+    /// Measured by a range sensor, e.g. LIDAR
+    RangeSensor,
+}
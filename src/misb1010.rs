@@ -0,0 +1,446 @@
+// --------------------------------------------------
+// tinyklv
+// --------------------------------------------------
+use tinyklv::prelude::*;
+
+// --------------------------------------------------
+// local
+// --------------------------------------------------
+use crate::misb1201::ImapB;
+
+/// Minimum of the correlation coefficient IMAP
+pub const CORRELATION_MIN: f64 = -1.0;
+
+/// Maximum of the correlation coefficient IMAP
+pub const CORRELATION_MAX: f64 = 1.0;
+
+/// Parse control bit set when a bit vector of the present correlation
+/// coefficients follows the parse control byte
+const PARSE_CONTROL_SPARSE: u8 = 0x80;
+
+/// Parse control bit set when the correlation coefficients are IEEE 754
+/// floats, rather than [`ImapB`] in [`CORRELATION_MIN`], [`CORRELATION_MAX`]
+const PARSE_CONTROL_FLOAT: u8 = 0x40;
+
+/// [`ImapB`] of the correlation coefficients, in [`CORRELATION_MIN`],
+/// [`CORRELATION_MAX`], for each length of 1 to 8 bytes
+static CORRELATION_IMAPB: std::sync::LazyLock<Vec<ImapB<f64>>> = std::sync::LazyLock::new(|| {
+    (1..=8).map(|len| ImapB::new(CORRELATION_MIN, CORRELATION_MAX, len).unwrap()).collect()
+});
+
+#[cfg(any(
+    feature = "misb1010-3",
+))]
+#[derive(Debug, Clone, Default, PartialEq)]
+/// Standard Deviation and Correlation Coefficient Floating Length Pack
+/// (SDCC-FLP)
+/// 
+/// MISB Standard 1010
+/// 
+/// Represents a symmetric `n` by `n` covariance matrix, using the standard
+/// deviation of each of the `n` values, and the correlation coefficient of
+/// each pair of values (i.e. the upper triangle, without the diagonal).
+/// 
+/// The pack consists of:
+/// 
+/// * The BER-OID encoded matrix size `n`
+/// * A parse control byte, from the most significant bit: the sparse flag,
+///   the float flag, the length of each correlation coefficient minus 1
+///   (3 bits), and the length of each standard deviation minus 1 (3 bits)
+/// * If sparse, a bit vector of the correlation coefficients present,
+///   most significant bit first. Absent coefficients are zero.
+/// * The standard deviations, as IEEE 754 floats
+/// * The correlation coefficients in row-major order, as IEEE 754 floats if
+///   the float flag is set, otherwise as [`ImapB`] in [`CORRELATION_MIN`],
+///   [`CORRELATION_MAX`]
+/// 
+/// The lengths of the decoded standard deviations and correlation
+/// coefficients are kept, so that re-encoding uses the same format.
+/// 
+/// See [`KeyedSdcc`]
+/// 
+/// For more information, see [Motion Imagery Standards Board (MISB)](https://nsgreg.nga.mil/misb.jsp)
+pub struct Misb1010 {
+    /// Standard deviation of each value
+    pub standard_deviations: Vec<f64>,
+    /// Correlation coefficient of each pair of values `(i, j)` where
+    /// `i < j`, in row-major order
+    /// 
+    /// See [`Misb1010::correlation`]
+    pub correlation_coefficients: Vec<f64>,
+    /// Length of each encoded standard deviation, either 4 or 8 bytes
+    pub sd_len: usize,
+    /// Length of each encoded correlation coefficient, either 4 or 8
+    /// bytes as IEEE 754 floats, or 1 to 8 bytes as [`ImapB`]
+    pub cc_len: usize,
+    /// Whether the correlation coefficients are encoded as [`ImapB`],
+    /// rather than IEEE 754 floats
+    pub cc_imap: bool,
+}
+
+#[cfg(feature = "misb1010-3")]
+/// [`Misb1010`] implementation
+impl Misb1010 {
+    /// Creates a new [`Misb1010`], returning [`None`] if the number of
+    /// correlation coefficients is not `n * (n - 1) / 2`
+    /// 
+    /// Both the standard deviations and correlation coefficients are
+    /// encoded as 4-byte IEEE 754 floats, see [`Misb1010::with_imap`]
+    pub fn new(standard_deviations: Vec<f64>, correlation_coefficients: Vec<f64>) -> Option<Self> {
+        let n = standard_deviations.len();
+        match correlation_coefficients.len() == n * n.saturating_sub(1) / 2 {
+            true => Some(Self { standard_deviations, correlation_coefficients, sd_len: 4, cc_len: 4, cc_imap: false }),
+            false => None,
+        }
+    }
+
+    /// Encodes the correlation coefficients as [`ImapB`] of `len` bytes,
+    /// clamped to 1 to 8 bytes
+    pub fn with_imap(self, len: usize) -> Self {
+        Self { cc_len: len.clamp(1, 8), cc_imap: true, ..self }
+    }
+
+    /// Size of the matrix, i.e. the number of values
+    pub fn len(&self) -> usize {
+        self.standard_deviations.len()
+    }
+
+    /// Returns `true` if the matrix describes no values
+    pub fn is_empty(&self) -> bool {
+        self.standard_deviations.is_empty()
+    }
+
+    /// Correlation coefficient between values `i` and `j`, which is 1
+    /// when `i == j`
+    pub fn correlation(&self, i: usize, j: usize) -> Option<f64> {
+        let n = self.len();
+        if i >= n || j >= n { return None }
+        if i == j { return Some(1.0) }
+        let (i, j) = (i.min(j), i.max(j));
+        // --------------------------------------------------
+        // offset of row `i` within the upper triangle
+        // --------------------------------------------------
+        let row = i * (2 * n - i - 1) / 2;
+        self.correlation_coefficients.get(row + j - i - 1).copied()
+    }
+
+    /// Covariance between values `i` and `j`
+    pub fn covariance(&self, i: usize, j: usize) -> Option<f64> {
+        Some(self.correlation(i, j)? * self.standard_deviations.get(i)? * self.standard_deviations.get(j)?)
+    }
+
+    /// The full, symmetric `n` by `n` covariance matrix
+    pub fn to_matrix(&self) -> Vec<Vec<f64>> {
+        let n = self.len();
+        (0..n)
+            .map(|i| (0..n).map(|j| self.covariance(i, j).unwrap_or_default()).collect())
+            .collect()
+    }
+
+    /// Decodes a [`Misb1010`] of length `len`
+    pub fn decode(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<Self> {
+        move |input: &mut &[u8]| {
+            let mut value = winnow::token::take(len).parse_next(input)?;
+            Self::decode_pack(&mut value)
+        }
+    }
+
+    /// Decodes a single [`Misb1010`], consuming only the bytes of the pack
+    pub(crate) fn decode_pack(input: &mut &[u8]) -> winnow::PResult<Self> {
+        let n = tinyklv::codecs::ber::dec::ber_oid::<u64>.parse_next(input)?;
+        let n = usize::try_from(n).map_err(|_| tinyklv::err!())?;
+        let parse_control = tinyklv::codecs::binary::dec::be_u8.parse_next(input)?;
+        let cc_len = ((parse_control >> 3) & 0x07) as usize + 1;
+        let sd_len = (parse_control & 0x07) as usize + 1;
+        let m = n.checked_mul(n.saturating_sub(1)).ok_or_else(|| tinyklv::err!())? / 2;
+        // --------------------------------------------------
+        // the standard deviations, and every coefficient
+        // when not sparse, must fit within the input before
+        // anything is allocated
+        // --------------------------------------------------
+        let sds = n.checked_mul(sd_len).filter(|x| *x <= input.len()).ok_or_else(|| tinyklv::err!())?;
+        if parse_control & PARSE_CONTROL_SPARSE == 0 {
+            m.checked_mul(cc_len)
+                .and_then(|x| x.checked_add(sds))
+                .filter(|x| *x <= input.len())
+                .ok_or_else(|| tinyklv::err!())?;
+        }
+        // --------------------------------------------------
+        // present correlation coefficients
+        // --------------------------------------------------
+        let present = match parse_control & PARSE_CONTROL_SPARSE {
+            0 => vec![true; m],
+            _ => {
+                let bits = winnow::token::take(m.div_ceil(8)).parse_next(input)?;
+                (0..m).map(|i| (bits[i / 8] >> (7 - i % 8)) & 0x01 == 1).collect()
+            }
+        };
+        let standard_deviations = (0..n)
+            .map(|_| {
+                let value = winnow::token::take(sd_len).parse_next(input)?;
+                crate::misb1303::float_from_be(value).ok_or_else(|| tinyklv::err!())
+            })
+            .collect::<winnow::PResult<Vec<f64>>>()?;
+        let cc_imap = parse_control & PARSE_CONTROL_FLOAT == 0;
+        let imap = match cc_imap {
+            false => None,
+            true => Some(&CORRELATION_IMAPB[cc_len - 1]),
+        };
+        let correlation_coefficients = present
+            .into_iter()
+            .map(|present| {
+                if !present { return Ok(0.0) }
+                let value = winnow::token::take(cc_len).parse_next(input)?;
+                match imap {
+                    Some(imap) => imap.from_imap(value).map_err(|_| tinyklv::err!()),
+                    None => crate::misb1303::float_from_be(value).ok_or_else(|| tinyklv::err!()),
+                }
+            })
+            .collect::<winnow::PResult<Vec<f64>>>()?;
+        Ok(Self { standard_deviations, correlation_coefficients, sd_len, cc_len, cc_imap })
+    }
+
+    /// Encodes the [`Misb1010`], using [`Misb1010::sd_len`],
+    /// [`Misb1010::cc_len`], and [`Misb1010::cc_imap`]
+    /// 
+    /// IEEE 754 floats are 8 bytes if their length is 8, otherwise 4
+    /// bytes, and [`ImapB`] are clamped to 1 to 8 bytes. A bit vector is
+    /// used whenever any correlation coefficient is zero.
+    /// 
+    /// A correlation coefficient which cannot be mapped to an [`ImapB`] is
+    /// encoded as a MISB ST 1201 special value, see [`ImapB::to_imap_or_special`].
+    pub fn encode_value(&self) -> Vec<u8> {
+        let float = |x: f64, len: usize| match len {
+            8 => x.to_be_bytes().to_vec(),
+            _ => (x as f32).to_be_bytes().to_vec(),
+        };
+        let sd_len = match self.sd_len { 8 => 8, _ => 4 };
+        let cc_len = match (self.cc_imap, self.cc_len) {
+            (true, x) => x.clamp(1, 8),
+            (false, 8) => 8,
+            (false, _) => 4,
+        };
+        let sparse = self.correlation_coefficients.contains(&0.0);
+        let mut output = tinyklv::codecs::ber::enc::ber_oid(&(self.len() as u64));
+        output.push(
+            if sparse { PARSE_CONTROL_SPARSE } else { 0 }
+                | if self.cc_imap { 0 } else { PARSE_CONTROL_FLOAT }
+                | (((cc_len - 1) as u8) << 3)
+                | (sd_len - 1) as u8
+        );
+        if sparse {
+            output.extend(self.correlation_coefficients.chunks(8).map(|x| {
+                x.iter()
+                    .enumerate()
+                    .fold(0u8, |acc, (i, x)| acc | (((*x != 0.0) as u8) << (7 - i)))
+            }));
+        }
+        output.extend(self.standard_deviations.iter().flat_map(|x| float(*x, sd_len)));
+        let imap = match self.cc_imap {
+            true => Some(&CORRELATION_IMAPB[cc_len - 1]),
+            false => None,
+        };
+        for x in self.correlation_coefficients.iter().filter(|x| !sparse || **x != 0.0) {
+            match imap {
+                Some(imap) => output.extend(imap.to_imap_or_special(*x)),
+                None => output.extend(float(*x, cc_len)),
+            }
+        }
+        output
+    }
+}
+
+#[cfg(any(
+    feature = "misb1010-3",
+))]
+#[derive(Debug, Clone, Default, PartialEq)]
+/// A [`Misb1010`] whose rows are mapped to the keys of the items they
+/// describe, within the parent local set
+/// 
+/// Encoded as the BER-OID encoded number of keys, followed by each
+/// BER-OID encoded key, followed by the [`Misb1010`]. This is the value
+/// of MISB ST 0601 Item 102 (SDCC-FLP), where the keys are the tags of
+/// the UAS Datalink LS items described, and the [`Misb1010`] is the
+/// SDCC-FLP of MISB ST 1010 without its own key and length.
+/// 
+/// See [`crate::misb0601::Misb0601::sdcc_flp`]
+pub struct KeyedSdcc {
+    /// Key of the item described by each row of the [`KeyedSdcc::sdcc`]
+    pub keys: Vec<u64>,
+    /// Standard deviations and correlation coefficients
+    pub sdcc: Misb1010,
+}
+
+#[cfg(feature = "misb1010-3")]
+/// [`KeyedSdcc`] implementation
+impl KeyedSdcc {
+    /// Row of the [`KeyedSdcc::sdcc`] describing the item with `key`
+    pub fn index_of(&self, key: u64) -> Option<usize> {
+        self.keys.iter().position(|x| *x == key)
+    }
+
+    /// Standard deviation of the item with `key`
+    pub fn standard_deviation(&self, key: u64) -> Option<f64> {
+        self.sdcc.standard_deviations.get(self.index_of(key)?).copied()
+    }
+
+    /// Covariance between the items with keys `a` and `b`
+    pub fn covariance(&self, a: u64, b: u64) -> Option<f64> {
+        self.sdcc.covariance(self.index_of(a)?, self.index_of(b)?)
+    }
+
+    /// The symmetric covariance matrix of the items with `keys`, in the
+    /// order provided, e.g. for propagating the uncertainty of the
+    /// platform position
+    /// 
+    /// Returns [`None`] if any key is not described.
+    pub fn submatrix(&self, keys: &[u64]) -> Option<Vec<Vec<f64>>> {
+        let indices = keys.iter().map(|x| self.index_of(*x)).collect::<Option<Vec<_>>>()?;
+        indices
+            .iter()
+            .map(|i| indices.iter().map(|j| self.sdcc.covariance(*i, *j)).collect())
+            .collect()
+    }
+
+    /// Decodes a [`KeyedSdcc`] of length `len`
+    pub fn decode(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<Self> {
+        move |input: &mut &[u8]| {
+            let mut value = winnow::token::take(len).parse_next(input)?;
+            let value = &mut value;
+            let n = tinyklv::codecs::ber::dec::ber_oid::<u64>.parse_next(value)?;
+            // --------------------------------------------------
+            // each key takes at least one byte
+            // --------------------------------------------------
+            let n = usize::try_from(n).ok().filter(|x| *x <= value.len()).ok_or_else(|| tinyklv::err!())?;
+            let keys: Vec<u64> = winnow::combinator::repeat(n, tinyklv::codecs::ber::dec::ber_oid::<u64>).parse_next(value)?;
+            let sdcc = Misb1010::decode_pack(value)?;
+            if sdcc.len() != n { return Err(tinyklv::err!()) }
+            Ok(Self { keys, sdcc })
+        }
+    }
+
+    /// Encodes the [`KeyedSdcc`], see [`Misb1010::encode_value`]
+    pub fn encode_value(&self) -> Vec<u8> {
+        let mut output = tinyklv::codecs::ber::enc::ber_oid(&(self.keys.len() as u64));
+        for x in &self.keys { output.extend(tinyklv::codecs::ber::enc::ber_oid(x)) }
+        output.extend(self.sdcc.encode_value());
+        output
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "misb1010-3")]
+mod test {
+    use super::*;
+
+    #[test]
+    fn correlation_indexing() {
+        // --------------------------------------------------
+        // upper triangle of a 3 x 3 matrix:
+        // (0, 1), (0, 2), (1, 2)
+        // --------------------------------------------------
+        let sdcc = Misb1010::new(vec![2.0, 3.0, 4.0], vec![0.5, 0.0, -0.25]).unwrap();
+        assert_eq!(sdcc.correlation(0, 0), Some(1.0));
+        assert_eq!(sdcc.correlation(1, 0), Some(0.5));
+        assert_eq!(sdcc.correlation(0, 2), Some(0.0));
+        assert_eq!(sdcc.correlation(2, 1), Some(-0.25));
+        assert_eq!(sdcc.correlation(3, 1), None);
+        assert_eq!(sdcc.to_matrix(), vec![
+            vec![4.0, 3.0, 0.0],
+            vec![3.0, 9.0, -3.0],
+            vec![0.0, -3.0, 16.0],
+        ]);
+        assert!(Misb1010::new(vec![1.0, 2.0], vec![]).is_none());
+    }
+
+    #[test]
+    fn float_roundtrip() {
+        let sdcc = Misb1010::new(vec![2.0, 3.0, 4.0], vec![0.5, 0.0, -0.25]).unwrap();
+        let encoded = sdcc.encode_value();
+        // --------------------------------------------------
+        // sparse, float, 4-byte coefficients and deviations
+        // --------------------------------------------------
+        assert_eq!(&encoded[..3], &[0x03, 0xDB, 0b1010_0000]);
+        assert_eq!(encoded.len(), 3 + 3 * 4 + 2 * 4);
+        assert_eq!(Misb1010::decode(encoded.len())(&mut encoded.as_slice()).unwrap(), sdcc);
+    }
+
+    #[test]
+    fn imap_roundtrip() {
+        let sdcc = Misb1010::new(vec![10.0, 20.0], vec![-0.75]).unwrap().with_imap(2);
+        let encoded = sdcc.encode_value();
+        assert_eq!(&encoded[..2], &[0x02, 0x0B]);
+        let decoded = Misb1010::decode(encoded.len())(&mut encoded.as_slice()).unwrap();
+        assert_eq!(decoded.standard_deviations, sdcc.standard_deviations);
+        assert!((decoded.correlation_coefficients[0] + 0.75).abs() < 1e-4);
+        assert_eq!((decoded.sd_len, decoded.cc_len, decoded.cc_imap), (4, 2, true));
+        assert_eq!(decoded.encode_value(), encoded);
+        // --------------------------------------------------
+        // the length of the IMAP is clamped
+        // --------------------------------------------------
+        assert_eq!(sdcc.clone().with_imap(12).cc_len, 8);
+        assert_eq!(Misb1010 { cc_len: 12, ..sdcc.clone() }.encode_value()[1], 0x3B);
+        // --------------------------------------------------
+        // a coefficient outside of the IMAP is still encoded,
+        // as a special value of the same length
+        // --------------------------------------------------
+        let invalid = Misb1010 { correlation_coefficients: vec![2.0], ..sdcc };
+        assert_eq!(invalid.encode_value().len(), encoded.len());
+    }
+
+    #[test]
+    fn known_pack() {
+        // --------------------------------------------------
+        // n = 2, with:
+        // * parse control: not sparse, float, 4-byte
+        //   coefficients, 8-byte deviations
+        // * deviations: 1.5, 0.25 (f64)
+        // * coefficient: 0.5 (f32)
+        // --------------------------------------------------
+        let encoded = [
+            0x02,
+            0b0101_1111,
+            0x3F, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x3F, 0xD0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x3F, 0x00, 0x00, 0x00,
+        ];
+        let decoded = Misb1010::decode(encoded.len())(&mut &encoded[..]).unwrap();
+        assert_eq!(decoded.standard_deviations, vec![1.5, 0.25]);
+        assert_eq!(decoded.correlation_coefficients, vec![0.5]);
+        assert_eq!(decoded.covariance(0, 1), Some(0.1875));
+        assert_eq!(decoded.encode_value(), encoded);
+    }
+
+    #[test]
+    fn hostile_size() {
+        // --------------------------------------------------
+        // a huge matrix size, without the bytes to back it
+        // --------------------------------------------------
+        let mut encoded = tinyklv::codecs::ber::enc::ber_oid(&(u32::MAX as u64));
+        encoded.extend([0x5B, 0x00, 0x00, 0x00, 0x00]);
+        assert!(Misb1010::decode(encoded.len())(&mut encoded.as_slice()).is_err());
+        let mut encoded = tinyklv::codecs::ber::enc::ber_oid(&(u32::MAX as u64));
+        encoded.extend([0x01, 0x02, 0x5B]);
+        assert!(KeyedSdcc::decode(encoded.len())(&mut encoded.as_slice()).is_err());
+        // --------------------------------------------------
+        // more standard deviations than the input
+        // --------------------------------------------------
+        assert!(Misb1010::decode(3)(&mut &[0x40, 0xDB, 0x00][..]).is_err());
+    }
+
+    #[test]
+    fn keyed() {
+        let keyed = KeyedSdcc {
+            keys: vec![0x0d, 0x0e, 0x0f],
+            sdcc: Misb1010::new(vec![2.0, 3.0, 4.0], vec![0.5, 0.0, -0.25]).unwrap(),
+        };
+        let encoded = keyed.encode_value();
+        assert_eq!(&encoded[..4], &[0x03, 0x0d, 0x0e, 0x0f]);
+        let decoded = KeyedSdcc::decode(encoded.len())(&mut encoded.as_slice()).unwrap();
+        assert_eq!(decoded, keyed);
+        assert_eq!(decoded.standard_deviation(0x0e), Some(3.0));
+        assert_eq!(decoded.covariance(0x0f, 0x0e), Some(-3.0));
+        assert_eq!(decoded.covariance(0x0d, 0x10), None);
+        assert_eq!(decoded.submatrix(&[0x0f, 0x0d]), Some(vec![vec![16.0, 0.0], vec![0.0, 4.0]]));
+    }
+}
@@ -170,10 +170,15 @@ impl<T: ImapFloat> ImapB<T> {
         user_enc: Option<fn(T) -> Vec<u8>>,
         user_dec: Option<fn(&[u8]) -> T>,
     ) -> Result<Self, ImapError<T>> {
-        match min < max {
+        // --------------------------------------------------
+        // the length must fit the scale factors into a u64,
+        // and the range must fit into the length
+        // --------------------------------------------------
+        match min < max && (1..=8).contains(&len) {
             true => {
                 let b_pow = Self::calc_b_pow(&min, &max)?;
                 let d_pow = Self::calc_d_pow(&len);
+                if b_pow > d_pow { return Err(ImapError::InvalidInputArgs(min, max, Some(len), None)) }
                 let s_f = Self::calc_s_f(&b_pow, &d_pow)?;
                 let s_r = Self::calc_s_r(&b_pow, &d_pow)?;
                 let z_offset = Self::calc_z_offset(&min, &max, &s_f);
@@ -196,14 +201,14 @@ impl<T: ImapFloat> ImapB<T> {
 
     /// Calculates [`ImapB::s_f`]
     fn calc_s_f(b_pow: &usize, d_pow: &usize) -> Result<T, ImapError<T>> {
-        let s_f = 2_usize.pow((*d_pow - *b_pow) as u32);
-        T::from_usize(s_f).ok_or(ImapError::ParseError(format!("Unable to convert forward scale factor {} to input floating-point precision", s_f)))
+        let s_f = 2_u64.pow((*d_pow - *b_pow) as u32);
+        T::from_u64(s_f).ok_or(ImapError::ParseError(format!("Unable to convert forward scale factor {} to input floating-point precision", s_f)))
     }
 
     /// Calculates [`ImapB::s_r`]
     fn calc_s_r(b_pow: &usize, d_pow: &usize) -> Result<T, ImapError<T>> {
         // is `2^(b_pow - d_pow)`, but `b_pow` will always be less than `d_pow`
-        let s_r = 1.0 / (1 << (*d_pow - *b_pow)) as f64;
+        let s_r = 1.0 / (1_u64 << (*d_pow - *b_pow)) as f64;
         T::from_f64(s_r).ok_or(ImapError::ParseError(format!("Unable to convert reverse scale factor {} to input floating-point precision", s_r)))
     }
 
@@ -266,6 +271,16 @@ impl<T: ImapFloat> ImapB<T> {
         }
     }
 
+    /// Maps a floating point value to an integer value, always returning
+    /// the length of the [`ImapB`] in bytes
+    /// 
+    /// Identical to [`ImapB::to_imap`], except that a value whose integer
+    /// cannot be represented is mapped to [`SpecialValue::ImapAboveMaximum`],
+    /// rather than failing
+    pub fn to_imap_or_special(&self, x: T) -> Vec<u8> {
+        self.to_imap(x).unwrap_or_else(|_| Value::Special(SpecialValue::ImapAboveMaximum).to_imap(self.len))
+    }
+
     /// Maps an IMAP integer value to a floating point value
     /// 
     /// Returns [`None`] when the value fails to be mapped
@@ -498,6 +513,18 @@ mod test {
         assert_eq!(example.from_imap(&vec![0xE8_u8, 0x00]).unwrap(), f64::NEG_INFINITY);
     }
 
+    #[test]
+    fn imap_b_invalid_len() {
+        assert!(ImapB::new(0.0, 1.0, 0).is_err());
+        assert!(ImapB::new(0.0, 1.0, 9).is_err());
+        assert!(ImapB::new(-1.0, 1.0, 8).is_ok());
+        // --------------------------------------------------
+        // range which does not fit into the length
+        // --------------------------------------------------
+        assert!(ImapB::new(0.0, 1e6, 1).is_err());
+        assert!(ImapB::new(0.0, 100.0, 1).is_ok());
+    }
+
     #[test]
     /// From: https://nsgreg.nga.mil/misb.jsp Misb Standard 1201 v5
     /// Page 24
@@ -0,0 +1,389 @@
+// --------------------------------------------------
+// tinyklv
+// --------------------------------------------------
+use tinyklv::prelude::*;
+
+// --------------------------------------------------
+// external
+// --------------------------------------------------
+use thiserror::Error;
+
+/// Version of the MIIS Core Identifier
+pub const MIIS_VERSION: u8 = 0x01;
+
+/// Length in bytes of a [`Uuid`]
+pub const UUID_LEN: usize = 16;
+
+#[cfg(feature = "misb1204-3")]
+/// Usage bit set when the [`Misb1204Miis::window_id`] is present
+const USAGE_WINDOW: u8 = 0x08;
+
+#[cfg(feature = "misb1204-3")]
+/// Usage bit set when the [`Misb1204Miis::minor_id`] is present
+const USAGE_MINOR: u8 = 0x04;
+
+#[cfg(feature = "misb1204-3")]
+/// Reserved bits of the usage byte, which must be 0
+const USAGE_RESERVED: u8 = 0x03;
+
+#[cfg(any(
+    feature = "misb1204-3",
+))]
+#[derive(Error, Debug, PartialEq)]
+/// Error type when parsing the text form of a [`Misb1204Miis`]
+pub enum Misb1204Error {
+    #[error("Invalid MIIS Core Identifier text: {0}.")]
+    /// The text is not of the form described in [`Misb1204Miis`]
+    InvalidFormat(String),
+    #[error("MIIS Core Identifier CRC mismatch: expected {expected:#06X}, got {actual:#06X}.")]
+    /// The CRC computed over the identifier does not match the CRC
+    /// within the text
+    CrcMismatch {
+        /// CRC computed over the identifier
+        expected: u16,
+        /// CRC contained within the text
+        actual: u16,
+    },
+}
+
+#[cfg(any(
+    feature = "misb1204-3",
+))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Type of the sensor or platform identifier, see [`Misb1204Miis`]
+pub enum IdType {
+    /// Assigned and tracked by a managing organization
+    Managed,
+    /// Generated at random when the sensor or platform has no
+    /// managed or physical identifier, see [`Uuid::new_v4`]
+    Virtual,
+    /// Derived from the hardware of the sensor or platform
+    Physical,
+}
+#[cfg(feature = "misb1204-3")]
+/// [`IdType`] implementation
+impl IdType {
+    /// Converts the 2 usage bits of an identifier, where 0 is absent
+    fn from_bits(bits: u8) -> Option<Self> {
+        match bits & 0x03 {
+            1 => Some(Self::Managed),
+            2 => Some(Self::Virtual),
+            3 => Some(Self::Physical),
+            _ => None,
+        }
+    }
+
+    /// See [`IdType::from_bits`]
+    fn to_bits(id: &Option<(Self, Uuid)>) -> u8 {
+        match id {
+            None => 0,
+            Some((Self::Managed, _)) => 1,
+            Some((Self::Virtual, _)) => 2,
+            Some((Self::Physical, _)) => 3,
+        }
+    }
+}
+
+#[cfg(any(
+    feature = "misb1204-3",
+))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// 16-byte Universally Unique Identifier (UUID)
+/// 
+/// Displayed as 8 groups of 4 uppercase hexadecimal digits, separated
+/// by `-`, e.g. `F592-F023-7336-4AF8-AA91-62C0-0F2E-B2DA`
+pub struct Uuid(pub [u8; UUID_LEN]);
+#[cfg(feature = "misb1204-3")]
+/// [`Uuid`] implementation
+impl Uuid {
+    /// Generates a random (version 4, variant 1) [`Uuid`]
+    pub fn new_v4() -> Self {
+        let mut output = rand::random::<[u8; UUID_LEN]>();
+        output[6] = (output[6] & 0x0F) | 0x40;
+        output[8] = (output[8] & 0x3F) | 0x80;
+        Self(output)
+    }
+}
+#[cfg(feature = "misb1204-3")]
+/// [`Uuid`] implementation of [`std::fmt::Display`]
+impl std::fmt::Display for Uuid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, x) in self.0.chunks(2).enumerate() {
+            if i > 0 { write!(f, "-")? }
+            write!(f, "{:02X}{:02X}", x[0], x[1])?;
+        }
+        Ok(())
+    }
+}
+#[cfg(feature = "misb1204-3")]
+/// [`Uuid`] implementation of [`std::str::FromStr`]
+impl std::str::FromStr for Uuid {
+    type Err = Misb1204Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || Misb1204Error::InvalidFormat(format!("invalid UUID `{s}`"));
+        let digits = s.split('-').collect::<Vec<_>>();
+        if digits.len() != UUID_LEN / 2 || digits.iter().any(|x| x.len() != 4) { return Err(err()) }
+        let mut output = [0u8; UUID_LEN];
+        for (i, x) in digits.iter().enumerate() {
+            let x = u16::from_str_radix(x, 16).map_err(|_| err())?;
+            output[2 * i..2 * i + 2].copy_from_slice(&x.to_be_bytes());
+        }
+        Ok(Self(output))
+    }
+}
+
+#[cfg(any(
+    feature = "misb1204-3",
+))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Motion Imagery Identification System (MIIS) Core Identifier
+/// 
+/// MISB Standard 1204
+/// 
+/// Uniquely identifies the Motion Imagery of a sensor, on a platform, to
+/// index and discover it (e.g., within an archive).
+/// 
+/// The binary form consists of the version byte, the usage byte, and then
+/// each present identifier as a [`Uuid`], in the order: sensor, platform,
+/// window, minor. From the most significant bit, the usage byte is: the
+/// sensor [`IdType`] (2 bits), the platform [`IdType`] (2 bits), whether
+/// the window identifier is present, whether the minor identifier is
+/// present, and 2 reserved bits, which must be 0. An [`IdType`] of 0
+/// means absent. Only version [`MIIS_VERSION`] is supported.
+/// 
+/// The text form is the version and usage bytes in hexadecimal, a `:`,
+/// every present identifier separated by `/`, and finally a `:` followed
+/// by the CRC-16-CCITT of the binary form (see [`crate::checksum::crc16_ccitt`]).
+/// 
+/// See [`crate::misb0601::Misb0601::miis_core_identifier`]
+/// 
+/// For more information, see [Motion Imagery Standards Board (MISB)](https://nsgreg.nga.mil/misb.jsp)
+/// 
+/// # Example
+/// 
+/// ```
+/// use misb::misb1204::{Misb1204Miis, IdType};
+/// let text = "0170:F592-F023-7336-4AF8-AA91-62C0-0F2E-B2DA/16B7-4341-0008-41A0-BE36-5B5A-B5D5-5B8D:35AD";
+/// let miis = text.parse::<Misb1204Miis>().unwrap();
+/// assert_eq!(miis.usage(), 0x70);
+/// assert!(matches!(miis.sensor_id, Some((IdType::Managed, _))));
+/// assert!(matches!(miis.platform_id, Some((IdType::Physical, _))));
+/// assert_eq!(miis.to_string(), text);
+/// ```
+pub struct Misb1204Miis {
+    /// Version of the identifier, see [`MIIS_VERSION`]
+    pub version: u8,
+    /// Identifier of the sensor
+    pub sensor_id: Option<(IdType, Uuid)>,
+    /// Identifier of the platform the sensor is mounted on
+    pub platform_id: Option<(IdType, Uuid)>,
+    /// Identifier of a window (i.e., a sub-region) of the sensor's
+    /// Motion Imagery
+    pub window_id: Option<Uuid>,
+    /// Identifier distinguishing Motion Imagery otherwise sharing the
+    /// same identifiers, e.g. after re-processing
+    pub minor_id: Option<Uuid>,
+}
+
+#[cfg(feature = "misb1204-3")]
+/// [`Misb1204Miis`] implementation
+impl Misb1204Miis {
+    /// Generates a new [`Misb1204Miis`] with a [`IdType::Virtual`] sensor
+    /// identifier, on the platform provided
+    /// 
+    /// Used when the sensor has no managed or physical identifier. The
+    /// identifier should be kept for the lifetime of the sensor, rather
+    /// than generated per Motion Imagery stream.
+    pub fn generate(platform_id: Option<(IdType, Uuid)>) -> Self {
+        Self {
+            version: MIIS_VERSION,
+            sensor_id: Some((IdType::Virtual, Uuid::new_v4())),
+            platform_id,
+            window_id: None,
+            minor_id: None,
+        }
+    }
+
+    /// The usage byte, describing which identifiers are present
+    pub fn usage(&self) -> u8 {
+        (IdType::to_bits(&self.sensor_id) << 6)
+            | (IdType::to_bits(&self.platform_id) << 4)
+            | if self.window_id.is_some() { USAGE_WINDOW } else { 0 }
+            | if self.minor_id.is_some() { USAGE_MINOR } else { 0 }
+    }
+
+    /// CRC-16-CCITT of the binary form, used by the text form
+    pub fn crc(&self) -> u16 {
+        crate::checksum::crc16_ccitt(&self.encode())
+    }
+
+    /// Every present identifier, in the order: sensor, platform, window, minor
+    fn ids(&self) -> impl Iterator<Item = &Uuid> {
+        [
+            self.sensor_id.as_ref().map(|(_, x)| x),
+            self.platform_id.as_ref().map(|(_, x)| x),
+            self.window_id.as_ref(),
+            self.minor_id.as_ref(),
+        ].into_iter().flatten()
+    }
+}
+
+#[cfg(feature = "misb1204-3")]
+/// [`Misb1204Miis`] implementation of [`tinyklv::prelude::Decode`]
+/// 
+/// Fails on any version other than [`MIIS_VERSION`], or if any reserved
+/// bit of the usage byte is set. Only the identifiers described by the
+/// usage byte are consumed, see [`crate::misb0601::Misb0601::miis_core_identifier`]
+/// for decoding the value of an entire item.
+impl tinyklv::prelude::Decode<&[u8]> for Misb1204Miis {
+    fn decode(input: &mut &[u8]) -> winnow::PResult<Self> {
+        let (version, usage) = (
+            tinyklv::codecs::binary::dec::be_u8,
+            tinyklv::codecs::binary::dec::be_u8,
+        ).parse_next(input)?;
+        if version != MIIS_VERSION || usage & USAGE_RESERVED != 0 { return Err(tinyklv::err!()) }
+        let mut uuid = |present: bool| -> winnow::PResult<Option<Uuid>> {
+            if !present { return Ok(None) }
+            let value = winnow::token::take(UUID_LEN).parse_next(input)?;
+            Ok(Some(Uuid(value.try_into().map_err(|_| tinyklv::err!())?)))
+        };
+        let sensor_type = IdType::from_bits(usage >> 6);
+        let platform_type = IdType::from_bits(usage >> 4);
+        let sensor_id = uuid(sensor_type.is_some())?.zip(sensor_type).map(|(id, ty)| (ty, id));
+        let platform_id = uuid(platform_type.is_some())?.zip(platform_type).map(|(id, ty)| (ty, id));
+        let window_id = uuid(usage & USAGE_WINDOW != 0)?;
+        let minor_id = uuid(usage & USAGE_MINOR != 0)?;
+        Ok(Self { version, sensor_id, platform_id, window_id, minor_id })
+    }
+}
+
+#[cfg(feature = "misb1204-3")]
+/// [`Misb1204Miis`] implementation of [`tinyklv::prelude::Encode`]
+impl tinyklv::prelude::Encode<Vec<u8>> for Misb1204Miis {
+    fn encode(&self) -> Vec<u8> {
+        let mut output = vec![self.version, self.usage()];
+        for x in self.ids() { output.extend(x.0) }
+        output
+    }
+}
+
+#[cfg(feature = "misb1204-3")]
+/// [`Misb1204Miis`] implementation of [`std::fmt::Display`], as the text form
+impl std::fmt::Display for Misb1204Miis {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:02X}{:02X}:", self.version, self.usage())?;
+        for (i, x) in self.ids().enumerate() {
+            if i > 0 { write!(f, "/")? }
+            write!(f, "{x}")?;
+        }
+        write!(f, ":{:04X}", self.crc())
+    }
+}
+
+#[cfg(feature = "misb1204-3")]
+/// [`Misb1204Miis`] implementation of [`std::str::FromStr`], from the text form
+/// 
+/// The CRC is verified.
+impl std::str::FromStr for Misb1204Miis {
+    type Err = Misb1204Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = |x: &str| Misb1204Error::InvalidFormat(x.to_string());
+        let mut parts = s.trim().split(':');
+        let (Some(header), Some(ids), Some(crc), None) = (parts.next(), parts.next(), parts.next(), parts.next()) else {
+            return Err(err("expected 3 parts, separated by `:`"))
+        };
+        if header.len() != 4 { return Err(err("expected the version and usage as 4 hexadecimal digits")) }
+        let header = u16::from_str_radix(header, 16).map_err(|_| err("invalid version or usage"))?;
+        let actual = u16::from_str_radix(crc, 16).map_err(|_| err("invalid CRC"))?;
+        // --------------------------------------------------
+        // re-use the binary decoder, so that the usage
+        // byte is interpreted in a single place
+        // --------------------------------------------------
+        let mut binary = header.to_be_bytes().to_vec();
+        for x in ids.split('/').filter(|x| !x.is_empty()) { binary.extend(x.parse::<Uuid>()?.0) }
+        let mut input = binary.as_slice();
+        let output = <Self as tinyklv::prelude::Decode<&[u8]>>::decode(&mut input).map_err(|_| err("identifiers do not match the usage"))?;
+        if !input.is_empty() { return Err(err("identifiers do not match the usage")) }
+        let expected = output.crc();
+        match expected == actual {
+            true => Ok(output),
+            false => Err(Misb1204Error::CrcMismatch { expected, actual }),
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "misb1204-3")]
+mod test {
+    use super::*;
+    use tinyklv::prelude::*;
+
+    #[test]
+    fn binary_roundtrip() {
+        let miis = Misb1204Miis {
+            version: MIIS_VERSION,
+            sensor_id: Some((IdType::Physical, Uuid([0x11; 16]))),
+            platform_id: None,
+            window_id: Some(Uuid([0x22; 16])),
+            minor_id: Some(Uuid([0x33; 16])),
+        };
+        assert_eq!(miis.usage(), 0xCC);
+        let encoded = miis.encode();
+        assert_eq!(encoded.len(), 2 + 3 * UUID_LEN);
+        assert_eq!(Misb1204Miis::decode(&mut encoded.as_slice()).unwrap(), miis);
+        // --------------------------------------------------
+        // truncated identifier, unsupported version, and
+        // reserved usage bits
+        // --------------------------------------------------
+        assert!(Misb1204Miis::decode(&mut &encoded[..40]).is_err());
+        let mut unsupported = encoded.clone();
+        unsupported[0] = 0x02;
+        assert!(Misb1204Miis::decode(&mut unsupported.as_slice()).is_err());
+        let mut reserved = encoded.clone();
+        reserved[1] |= 0x01;
+        assert!(Misb1204Miis::decode(&mut reserved.as_slice()).is_err());
+        // --------------------------------------------------
+        // as the value of an item, trailing bytes are
+        // rejected
+        // --------------------------------------------------
+        let mut trailing = encoded.clone();
+        trailing.push(0x00);
+        assert!(crate::decode_exact::<Misb1204Miis>(trailing.len())(&mut trailing.as_slice()).is_err());
+        assert_eq!(crate::decode_exact::<Misb1204Miis>(encoded.len())(&mut encoded.as_slice()).unwrap(), miis);
+    }
+
+    #[test]
+    fn text_roundtrip() {
+        let miis = Misb1204Miis {
+            version: MIIS_VERSION,
+            sensor_id: None,
+            platform_id: Some((IdType::Managed, "16B7-4341-0008-41A0-BE36-5B5A-B5D5-5B8D".parse().unwrap())),
+            window_id: None,
+            minor_id: None,
+        };
+        let text = miis.to_string();
+        assert!(text.starts_with("0110:16B7-4341-0008-41A0-BE36-5B5A-B5D5-5B8D:"));
+        assert_eq!(text.parse::<Misb1204Miis>(), Ok(miis));
+        // --------------------------------------------------
+        // corrupted CRC and identifiers
+        // --------------------------------------------------
+        let crc = u16::from_str_radix(&text[text.len() - 4..], 16).unwrap();
+        let corrupted = format!("{}:{:04X}", &text[..text.len() - 5], crc ^ 1);
+        assert!(matches!(corrupted.parse::<Misb1204Miis>(), Err(Misb1204Error::CrcMismatch { .. })));
+        assert!(matches!(text.replacen("16B7", "16B8", 1).parse::<Misb1204Miis>(), Err(Misb1204Error::CrcMismatch { .. })));
+        assert!(matches!(text.replacen("0110", "01D0", 1).parse::<Misb1204Miis>(), Err(Misb1204Error::InvalidFormat(_))));
+        assert!(matches!("0110:16B7-4341".parse::<Misb1204Miis>(), Err(Misb1204Error::InvalidFormat(_))));
+    }
+
+    #[test]
+    fn generate() {
+        let platform = Some((IdType::Physical, Uuid::new_v4()));
+        let (a, b) = (Misb1204Miis::generate(platform), Misb1204Miis::generate(platform));
+        assert_ne!(a, b);
+        assert_eq!(a.platform_id, b.platform_id);
+        assert_eq!(a.usage(), 0xB0);
+        let Some((IdType::Virtual, sensor)) = a.sensor_id else { panic!("expected a virtual sensor identifier") };
+        assert_eq!((sensor.0[6] >> 4, sensor.0[8] >> 6), (4, 2));
+        assert_eq!(a.to_string().parse::<Misb1204Miis>(), Ok(a));
+    }
+}
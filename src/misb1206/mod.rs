@@ -0,0 +1,413 @@
+// --------------------------------------------------
+// external
+// --------------------------------------------------
+use thisenum::Const;
+
+// --------------------------------------------------
+// tinyklv
+// --------------------------------------------------
+use tinyklv::Klv;
+use tinyklv::prelude::*;
+
+// --------------------------------------------------
+// local
+// --------------------------------------------------
+pub mod ops;
+
+#[cfg(any(
+    feature = "misb1206-2",
+))]
+#[derive(Klv, Debug)]
+#[klv(
+    // ------------------------------------------------
+    // Local Set only, embedded within MISB 0601
+    // ------------------------------------------------
+    stream = &[u8],
+    key(enc = tinyklv::codecs::ber::enc::ber_oid,
+        dec = tinyklv::codecs::ber::dec::ber_oid::<u64>),
+    len(enc = tinyklv::codecs::ber::enc::ber_length,
+        dec = tinyklv::codecs::ber::dec::ber_length),
+    default(ty = u8, dyn = true, dec = tinyklv::codecs::binary::dec::be_u8_lengthed),
+    default(ty = u32, dyn = true, dec = tinyklv::codecs::binary::dec::be_u32_lengthed),
+)]
+/// Synthetic Aperture Radar (SAR) Motion Imagery Metadata
+/// 
+/// MISB Standard 1206
+/// 
+/// Describes the collection geometry and quality of SAR Motion Imagery,
+/// needed to georeference each SAR image. The reference frame items
+/// describe a previously collected image, for coherent processing
+/// between the two.
+/// 
+/// See [`crate::misb0601::Misb0601::sar_motion_imagery_local_set`]
+/// 
+/// For more information, see [Motion Imagery Standards Board (MISB)](https://nsgreg.nga.mil/misb.jsp)
+pub struct Misb1206 {
+    #[cfg(any(
+        feature = "misb1206-2",
+    ))]
+    #[klv(key = 0x01, dyn = true, dec = ops::to_grazing_angle)]
+    /// (Mandatory) Grazing angle between the line of sight from the ARP (Aperture Reference Point)
+    /// to the image center, and the ground plane
+    /// 
+    /// Units: Degrees (°)
+    pub grazing_angle: f64,
+
+    #[cfg(any(
+        feature = "misb1206-2",
+    ))]
+    #[klv(key = 0x02, dyn = true, dec = ops::to_squint_angle)]
+    /// (Mandatory) Angle between the ground projection of the line of sight and the ground
+    /// projection of the platform velocity, minus 90 degrees
+    /// 
+    /// Units: Degrees (°)
+    pub ground_plane_squint_angle: f64,
+
+    #[cfg(any(
+        feature = "misb1206-2",
+    ))]
+    #[klv(key = 0x03, dec = LookDirection::decode)]
+    /// (Mandatory) Side of the platform the SAR is looking towards, see [`LookDirection`]
+    /// 
+    /// Units: None
+    pub look_direction: LookDirection,
+
+    #[cfg(any(
+        feature = "misb1206-2",
+    ))]
+    #[klv(key = 0x04, dec = ImagePlane::decode)]
+    /// (Mandatory) Plane the SAR image is formed in, see [`ImagePlane`]
+    /// 
+    /// Units: None
+    pub image_plane: ImagePlane,
+
+    #[cfg(any(
+        feature = "misb1206-2",
+    ))]
+    #[klv(key = 0x05, dyn = true, dec = ops::to_resolution)]
+    /// (Mandatory) Resolution of the image in the range direction
+    /// 
+    /// Units: Meters (m)
+    pub range_resolution: f64,
+
+    #[cfg(any(
+        feature = "misb1206-2",
+    ))]
+    #[klv(key = 0x06, dyn = true, dec = ops::to_resolution)]
+    /// (Mandatory) Resolution of the image in the cross range direction
+    /// 
+    /// Units: Meters (m)
+    pub cross_range_resolution: f64,
+
+    #[cfg(any(
+        feature = "misb1206-2",
+    ))]
+    #[klv(key = 0x07, dyn = true, dec = ops::to_resolution)]
+    /// (Mandatory) Size of a pixel in the range direction, within the image plane
+    /// 
+    /// Units: Meters (m)
+    pub range_image_plane_pixel_size: f64,
+
+    #[cfg(any(
+        feature = "misb1206-2",
+    ))]
+    #[klv(key = 0x08, dyn = true, dec = ops::to_resolution)]
+    /// (Mandatory) Size of a pixel in the cross range direction, within the image plane
+    /// 
+    /// Units: Meters (m)
+    pub cross_range_image_plane_pixel_size: f64,
+
+    #[cfg(any(
+        feature = "misb1206-2",
+    ))]
+    #[klv(key = 0x09)]
+    /// (Mandatory) Number of rows of the SAR image
+    /// 
+    /// Units: Pixels
+    pub image_rows: u32,
+
+    #[cfg(any(
+        feature = "misb1206-2",
+    ))]
+    #[klv(key = 0x0A)]
+    /// (Mandatory) Number of columns of the SAR image
+    /// 
+    /// Units: Pixels
+    pub image_columns: u32,
+
+    #[cfg(any(
+        feature = "misb1206-2",
+    ))]
+    #[klv(key = 0x0B, dyn = true, dec = ops::to_angle)]
+    /// (Optional) Range direction of the image, measured clockwise from true north
+    /// 
+    /// Units: Degrees (°)
+    pub range_direction_angle: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb1206-2",
+    ))]
+    #[klv(key = 0x0C, dyn = true, dec = ops::to_angle)]
+    /// (Optional) Direction of true north, measured clockwise from the top edge of the image
+    /// 
+    /// Units: Degrees (°)
+    pub true_north_direction: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb1206-2",
+    ))]
+    #[klv(key = 0x0D, dyn = true, dec = ops::to_angle)]
+    /// (Optional) Layover direction of the image, measured clockwise from true north
+    /// 
+    /// Units: Degrees (°)
+    pub range_layover_angle: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb1206-2",
+    ))]
+    #[klv(key = 0x0E, dyn = true, dec = ops::to_angle)]
+    /// (Optional) Angular extent of the synthetic aperture, projected onto the ground plane
+    /// 
+    /// Units: Degrees (°)
+    pub ground_aperture_angular_extent: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb1206-2",
+    ))]
+    #[klv(key = 0x0F)]
+    /// (Optional) Time taken to collect the synthetic aperture
+    /// 
+    /// Units: Microseconds (μs)
+    pub aperture_duration: Option<u32>,
+
+    #[cfg(any(
+        feature = "misb1206-2",
+    ))]
+    #[klv(key = 0x10, dyn = true, dec = ops::to_angle)]
+    /// (Optional) Direction of the ground projection of the platform velocity, measured clockwise
+    /// from true north
+    /// 
+    /// Units: Degrees (°)
+    pub ground_trace_angle: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb1206-2",
+    ))]
+    #[klv(key = 0x11, dyn = true, dec = ops::to_squint_angle)]
+    /// (Optional) Angle between the line of sight and the platform velocity, minus 90 degrees,
+    /// measured in the slant plane
+    /// 
+    /// Units: Degrees (°)
+    pub squint_angle: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb1206-2",
+    ))]
+    #[klv(key = 0x12, dyn = true, dec = ops::to_doppler_cone_angle)]
+    /// (Optional) Angle between the line of sight and the platform velocity
+    /// 
+    /// Units: Degrees (°)
+    pub doppler_cone_angle: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb1206-2",
+    ))]
+    #[klv(key = 0x13, dec = crate::misb0601::ops::to_precision_timestamp)]
+    /// (Optional) Precision timestamp of the reference image, used for coherent processing (e.g.
+    /// coherent change detection) against this image
+    /// 
+    /// Units: Microseconds (μs)
+    pub reference_frame_precision_timestamp: Option<chrono::DateTime<chrono::Utc>>,
+
+    #[cfg(any(
+        feature = "misb1206-2",
+    ))]
+    #[klv(key = 0x14, dyn = true, dec = ops::to_grazing_angle)]
+    /// (Optional) [`Misb1206::grazing_angle`] of the reference image
+    /// 
+    /// Units: Degrees (°)
+    pub reference_frame_grazing_angle: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb1206-2",
+    ))]
+    #[klv(key = 0x15, dyn = true, dec = ops::to_squint_angle)]
+    /// (Optional) [`Misb1206::ground_plane_squint_angle`] of the reference image
+    /// 
+    /// Units: Degrees (°)
+    pub reference_frame_ground_plane_squint_angle: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb1206-2",
+    ))]
+    #[klv(key = 0x16, dyn = true, dec = ops::to_angle)]
+    /// (Optional) [`Misb1206::range_direction_angle`] of the reference image
+    /// 
+    /// Units: Degrees (°)
+    pub reference_frame_range_direction_angle: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb1206-2",
+    ))]
+    #[klv(key = 0x17, dyn = true, dec = ops::to_angle)]
+    /// (Optional) [`Misb1206::range_layover_angle`] of the reference image
+    /// 
+    /// Units: Degrees (°)
+    pub reference_frame_range_layover_angle: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb1206-2",
+    ))]
+    #[klv(key = 0x18)]
+    /// (Optional) Version number of the MISB ST 1206 document used to encode the SARMI metadata
+    /// 
+    /// Units: None
+    pub document_version: Option<u8>,
+}
+
+#[cfg(feature = "misb1206-2")]
+/// [`Misb1206`] implementation
+impl Misb1206 {
+    /// Encodes every present item of the [`Misb1206`] in tag order
+    /// 
+    /// This is the value of the SARMI LS when embedded within MISB 0601,
+    /// see [`crate::misb0601::Misb0601::sar_motion_imagery_local_set`]
+    pub fn encode_value(&self) -> Vec<u8> {
+        let mut output = Vec::new();
+        crate::encode_item(&mut output, 0x01, &ops::from_grazing_angle(self.grazing_angle));
+        crate::encode_item(&mut output, 0x02, &ops::from_squint_angle(self.ground_plane_squint_angle));
+        crate::encode_item(&mut output, 0x03, &self.look_direction.encode());
+        crate::encode_item(&mut output, 0x04, &self.image_plane.encode());
+        crate::encode_item(&mut output, 0x05, &ops::from_resolution(self.range_resolution));
+        crate::encode_item(&mut output, 0x06, &ops::from_resolution(self.cross_range_resolution));
+        crate::encode_item(&mut output, 0x07, &ops::from_resolution(self.range_image_plane_pixel_size));
+        crate::encode_item(&mut output, 0x08, &ops::from_resolution(self.cross_range_image_plane_pixel_size));
+        crate::encode_item(&mut output, 0x09, &self.image_rows.to_be_bytes());
+        crate::encode_item(&mut output, 0x0A, &self.image_columns.to_be_bytes());
+        if let Some(x) = self.range_direction_angle { crate::encode_item(&mut output, 0x0B, &ops::from_angle(x)) }
+        if let Some(x) = self.true_north_direction { crate::encode_item(&mut output, 0x0C, &ops::from_angle(x)) }
+        if let Some(x) = self.range_layover_angle { crate::encode_item(&mut output, 0x0D, &ops::from_angle(x)) }
+        if let Some(x) = self.ground_aperture_angular_extent { crate::encode_item(&mut output, 0x0E, &ops::from_angle(x)) }
+        if let Some(x) = self.aperture_duration { crate::encode_item(&mut output, 0x0F, &x.to_be_bytes()) }
+        if let Some(x) = self.ground_trace_angle { crate::encode_item(&mut output, 0x10, &ops::from_angle(x)) }
+        if let Some(x) = self.squint_angle { crate::encode_item(&mut output, 0x11, &ops::from_squint_angle(x)) }
+        if let Some(x) = self.doppler_cone_angle { crate::encode_item(&mut output, 0x12, &ops::from_doppler_cone_angle(x)) }
+        if let Some(x) = self.reference_frame_precision_timestamp { crate::encode_item(&mut output, 0x13, &crate::misb0601::ops::from_precision_timestamp(x)) }
+        if let Some(x) = self.reference_frame_grazing_angle { crate::encode_item(&mut output, 0x14, &ops::from_grazing_angle(x)) }
+        if let Some(x) = self.reference_frame_ground_plane_squint_angle { crate::encode_item(&mut output, 0x15, &ops::from_squint_angle(x)) }
+        if let Some(x) = self.reference_frame_range_direction_angle { crate::encode_item(&mut output, 0x16, &ops::from_angle(x)) }
+        if let Some(x) = self.reference_frame_range_layover_angle { crate::encode_item(&mut output, 0x17, &ops::from_angle(x)) }
+        if let Some(x) = self.document_version { crate::encode_item(&mut output, 0x18, &[x]) }
+        output
+    }
+}
+
+#[derive(Const)]
+#[armtype(u8)]
+/// MISB Standard 1206 Look Direction
+/// 
+/// See [`Misb1206::look_direction`]
+pub enum LookDirection {
+    #[value = 0]
+    Left,
+    #[value = 1]
+    Right,
+}
+/// [`LookDirection`] implementation of [`tinyklv::prelude::Decode`]
+impl tinyklv::prelude::Decode<&[u8]> for LookDirection {
+    fn decode(input: &mut &[u8]) -> winnow::PResult<Self> {
+        Self::try_from(tinyklv::codecs::binary::dec::be_u8.parse_next(input)?).map_err(|_| tinyklv::err!())
+    }
+}
+/// [`LookDirection`] implementation of [`tinyklv::prelude::Encode`]
+impl tinyklv::prelude::Encode<Vec<u8>> for LookDirection {
+    fn encode(&self) -> Vec<u8> {
+        vec![*self.value()]
+    }
+}
+
+#[derive(Const)]
+#[armtype(u8)]
+/// MISB Standard 1206 Image Plane
+/// 
+/// See [`Misb1206::image_plane`]
+pub enum ImagePlane {
+    #[value = 0]
+    Ground,
+    #[value = 1]
+    Slant,
+}
+/// [`ImagePlane`] implementation of [`tinyklv::prelude::Decode`]
+impl tinyklv::prelude::Decode<&[u8]> for ImagePlane {
+    fn decode(input: &mut &[u8]) -> winnow::PResult<Self> {
+        Self::try_from(tinyklv::codecs::binary::dec::be_u8.parse_next(input)?).map_err(|_| tinyklv::err!())
+    }
+}
+/// [`ImagePlane`] implementation of [`tinyklv::prelude::Encode`]
+impl tinyklv::prelude::Encode<Vec<u8>> for ImagePlane {
+    fn encode(&self) -> Vec<u8> {
+        vec![*self.value()]
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "misb1206-2")]
+mod test {
+    use super::*;
+    use tinyklv::prelude::*;
+
+    #[test]
+    fn encode_roundtrip() {
+        let sarmi = Misb1206 {
+            grazing_angle: 30.0,
+            ground_plane_squint_angle: -12.5,
+            look_direction: LookDirection::Right,
+            image_plane: ImagePlane::Slant,
+            range_resolution: 0.3,
+            cross_range_resolution: 0.3,
+            range_image_plane_pixel_size: 0.25,
+            cross_range_image_plane_pixel_size: 0.25,
+            image_rows: 1080,
+            image_columns: 1920,
+            range_direction_angle: Some(270.0),
+            true_north_direction: None,
+            range_layover_angle: None,
+            ground_aperture_angular_extent: None,
+            aperture_duration: Some(500_000),
+            ground_trace_angle: None,
+            squint_angle: None,
+            doppler_cone_angle: Some(95.0),
+            reference_frame_precision_timestamp: None,
+            reference_frame_grazing_angle: Some(31.0),
+            reference_frame_ground_plane_squint_angle: None,
+            reference_frame_range_direction_angle: None,
+            reference_frame_range_layover_angle: None,
+            document_version: Some(2),
+        };
+        let encoded = sarmi.encode_value();
+        let decoded = Misb1206::decode(&mut encoded.as_slice()).unwrap();
+        assert!((decoded.grazing_angle - 30.0).abs() < 1e-6);
+        assert!((decoded.ground_plane_squint_angle + 12.5).abs() < 1e-6);
+        assert!(matches!(decoded.look_direction, LookDirection::Right));
+        assert!(matches!(decoded.image_plane, ImagePlane::Slant));
+        assert!((decoded.range_image_plane_pixel_size - 0.25).abs() < 1e-4);
+        assert_eq!((decoded.image_rows, decoded.image_columns), (1080, 1920));
+        assert!((decoded.range_direction_angle.unwrap() - 270.0).abs() < 1e-6);
+        assert_eq!(decoded.true_north_direction, None);
+        assert_eq!(decoded.aperture_duration, Some(500_000));
+        assert!((decoded.doppler_cone_angle.unwrap() - 95.0).abs() < 1e-6);
+        assert!((decoded.reference_frame_grazing_angle.unwrap() - 31.0).abs() < 1e-6);
+        assert_eq!(decoded.document_version, Some(2));
+    }
+
+    #[test]
+    fn imapb_special_values() {
+        for from in [ops::from_grazing_angle, ops::from_squint_angle, ops::from_angle, ops::from_doppler_cone_angle] {
+            assert_eq!(from(f64::NAN), vec![0xD0, 0x00, 0x00, 0x00]);
+            assert_eq!(from(-1000.0), vec![0xE0, 0x00, 0x00, 0x00]);
+            assert_eq!(from(1000.0), vec![0xE1, 0x00, 0x00, 0x00]);
+        }
+        assert_eq!(ops::from_resolution(f64::INFINITY), vec![0xC8, 0x00, 0x00]);
+        assert_eq!(ops::from_resolution(-0.5), vec![0xE0, 0x00, 0x00]);
+    }
+}
@@ -0,0 +1,161 @@
+// --------------------------------------------------
+// static
+// --------------------------------------------------
+use std::sync::LazyLock;
+
+/// Grazing angle maximum
+pub const GRAZING_ANGLE_MAX: f64 = 90.0;
+/// Squint angle magnitude
+pub const SQUINT_ANGLE_MAX: f64 = 90.0;
+/// Resolution / pixel size maximum
+pub const RESOLUTION_MAX: f64 = 100.0;
+/// Full circle angle maximum
+pub const ANGLE_MAX: f64 = 360.0;
+/// Doppler cone angle maximum
+pub const DOPPLER_CONE_ANGLE_MAX: f64 = 180.0;
+/// Number of bytes used when encoding angles
+pub const ANGLE_LEN: usize = 4;
+/// Number of bytes used when encoding resolutions / pixel sizes
+pub const RESOLUTION_LEN: usize = 3;
+
+/// 4 byte-precision in range [0, 90]
+/// 
+/// Used for grazing angles
+/// 
+/// Units: Degrees (°)
+pub(crate) static IMAPB_0_90_4_F64: LazyLock<crate::misb1201::ImapB<f64>> = LazyLock::new(|| {
+    crate::misb1201::ImapB::new(0.0, GRAZING_ANGLE_MAX, ANGLE_LEN).unwrap()
+});
+
+/// 4 byte-precision in range [-90, 90]
+/// 
+/// Used for squint angles
+/// 
+/// Units: Degrees (°)
+pub(crate) static IMAPB_N90_90_4_F64: LazyLock<crate::misb1201::ImapB<f64>> = LazyLock::new(|| {
+    crate::misb1201::ImapB::new(-SQUINT_ANGLE_MAX, SQUINT_ANGLE_MAX, ANGLE_LEN).unwrap()
+});
+
+/// 3 byte-precision in range [0, 100]
+/// 
+/// Used for resolutions and pixel sizes
+/// 
+/// Units: Meters (m)
+pub(crate) static IMAPB_0_100_3_F64: LazyLock<crate::misb1201::ImapB<f64>> = LazyLock::new(|| {
+    crate::misb1201::ImapB::new(0.0, RESOLUTION_MAX, RESOLUTION_LEN).unwrap()
+});
+
+/// 4 byte-precision in range [0, 360]
+/// 
+/// Used for angles relative to true north, and angular extents
+/// 
+/// Units: Degrees (°)
+pub(crate) static IMAPB_0_360_4_F64: LazyLock<crate::misb1201::ImapB<f64>> = LazyLock::new(|| {
+    crate::misb1201::ImapB::new(0.0, ANGLE_MAX, ANGLE_LEN).unwrap()
+});
+
+/// 4 byte-precision in range [0, 180]
+/// 
+/// Used for the doppler cone angle
+/// 
+/// Units: Degrees (°)
+pub(crate) static IMAPB_0_180_4_F64: LazyLock<crate::misb1201::ImapB<f64>> = LazyLock::new(|| {
+    crate::misb1201::ImapB::new(0.0, DOPPLER_CONE_ANGLE_MAX, ANGLE_LEN).unwrap()
+});
+
+/// See [`crate::misb1206::Misb1206`]
+/// 
+/// * [`crate::misb1206::Misb1206::grazing_angle`]
+/// * [`crate::misb1206::Misb1206::reference_frame_grazing_angle`]
+pub fn to_grazing_angle(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<f64> {
+    crate::misb0601::ops::to_imapb(0.0, GRAZING_ANGLE_MAX, len)
+}
+
+#[inline(always)]
+/// See [`crate::misb1206::Misb1206`]
+/// 
+/// * [`crate::misb1206::Misb1206::grazing_angle`]
+/// * [`crate::misb1206::Misb1206::reference_frame_grazing_angle`]
+/// 
+/// Always encoded using [`ANGLE_LEN`] bytes
+pub fn from_grazing_angle(input: f64) -> Vec<u8> {
+    crate::misb0601::ops::from_imapb(&IMAPB_0_90_4_F64, input)
+}
+
+/// See [`crate::misb1206::Misb1206`]
+/// 
+/// * [`crate::misb1206::Misb1206::ground_plane_squint_angle`]
+/// * [`crate::misb1206::Misb1206::squint_angle`]
+/// * [`crate::misb1206::Misb1206::reference_frame_ground_plane_squint_angle`]
+pub fn to_squint_angle(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<f64> {
+    crate::misb0601::ops::to_imapb(-SQUINT_ANGLE_MAX, SQUINT_ANGLE_MAX, len)
+}
+
+#[inline(always)]
+/// See [`crate::misb1206::Misb1206`]
+/// 
+/// * [`crate::misb1206::Misb1206::ground_plane_squint_angle`]
+/// * [`crate::misb1206::Misb1206::squint_angle`]
+/// * [`crate::misb1206::Misb1206::reference_frame_ground_plane_squint_angle`]
+/// 
+/// Always encoded using [`ANGLE_LEN`] bytes
+pub fn from_squint_angle(input: f64) -> Vec<u8> {
+    crate::misb0601::ops::from_imapb(&IMAPB_N90_90_4_F64, input)
+}
+
+/// See [`crate::misb1206::Misb1206`]
+/// 
+/// * [`crate::misb1206::Misb1206::range_resolution`]
+/// * [`crate::misb1206::Misb1206::cross_range_resolution`]
+/// * [`crate::misb1206::Misb1206::range_image_plane_pixel_size`]
+/// * [`crate::misb1206::Misb1206::cross_range_image_plane_pixel_size`]
+pub fn to_resolution(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<f64> {
+    crate::misb0601::ops::to_imapb(0.0, RESOLUTION_MAX, len)
+}
+
+#[inline(always)]
+/// See [`crate::misb1206::Misb1206`]
+/// 
+/// * [`crate::misb1206::Misb1206::range_resolution`]
+/// * [`crate::misb1206::Misb1206::cross_range_resolution`]
+/// * [`crate::misb1206::Misb1206::range_image_plane_pixel_size`]
+/// * [`crate::misb1206::Misb1206::cross_range_image_plane_pixel_size`]
+/// 
+/// Always encoded using [`RESOLUTION_LEN`] bytes
+pub fn from_resolution(input: f64) -> Vec<u8> {
+    crate::misb0601::ops::from_imapb(&IMAPB_0_100_3_F64, input)
+}
+
+/// See [`crate::misb1206::Misb1206`]
+/// 
+/// * [`crate::misb1206::Misb1206::range_direction_angle`]
+/// * [`crate::misb1206::Misb1206::true_north_direction`]
+/// * [`crate::misb1206::Misb1206::range_layover_angle`]
+/// * [`crate::misb1206::Misb1206::ground_aperture_angular_extent`]
+/// * [`crate::misb1206::Misb1206::ground_trace_angle`]
+/// * [`crate::misb1206::Misb1206::reference_frame_range_direction_angle`]
+/// * [`crate::misb1206::Misb1206::reference_frame_range_layover_angle`]
+pub fn to_angle(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<f64> {
+    crate::misb0601::ops::to_imapb(0.0, ANGLE_MAX, len)
+}
+
+#[inline(always)]
+/// See [`to_angle`]
+/// 
+/// Always encoded using [`ANGLE_LEN`] bytes
+pub fn from_angle(input: f64) -> Vec<u8> {
+    crate::misb0601::ops::from_imapb(&IMAPB_0_360_4_F64, input)
+}
+
+/// See [`crate::misb1206::Misb1206::doppler_cone_angle`]
+pub fn to_doppler_cone_angle(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<f64> {
+    crate::misb0601::ops::to_imapb(0.0, DOPPLER_CONE_ANGLE_MAX, len)
+}
+
+#[inline(always)]
+/// See [`crate::misb1206::Misb1206::doppler_cone_angle`]
+/// 
+/// Always encoded using [`ANGLE_LEN`] bytes
+pub fn from_doppler_cone_angle(input: f64) -> Vec<u8> {
+    crate::misb0601::ops::from_imapb(&IMAPB_0_180_4_F64, input)
+}
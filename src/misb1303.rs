@@ -0,0 +1,614 @@
+// --------------------------------------------------
+// tinyklv
+// --------------------------------------------------
+use tinyklv::prelude::*;
+
+// --------------------------------------------------
+// external
+// --------------------------------------------------
+use thisenum::Const;
+use thiserror::Error;
+
+// --------------------------------------------------
+// local
+// --------------------------------------------------
+use crate::misb1201::{ImapB, ImapError};
+
+#[derive(Error, Debug, PartialEq)]
+/// Error type when encoding a Multi-Dimensional Array Pack (MDAP)
+pub enum MdapError {
+    #[error("Number of elements does not match the dimensions: expected {expected}, got {actual}.")]
+    /// The number of elements is not the product of the dimensions
+    DimensionMismatch {
+        /// Product of the dimensions
+        expected: usize,
+        /// Number of elements provided
+        actual: usize,
+    },
+    #[error("Element cannot be represented using {0} bytes.")]
+    /// An element is negative, fractional, or too large to be
+    /// represented using the element size provided
+    Overflow(usize),
+    #[error("IMAP error: {0}")]
+    /// The [`ImapB`] could not be created, or failed to map an element
+    Imap(#[from] ImapError<f64>),
+}
+
+/// Maximum number of elements of a decoded [`Apa::RunLength`] [`Mdap`]
+/// 
+/// Unlike the other algorithms, the number of run-length encoded elements
+/// is not bounded by the length of the encoded value.
+pub const MAX_RUN_LENGTH_ELEMENTS: usize = 1 << 24;
+
+#[derive(Const)]
+#[armtype(u8)]
+/// MDAP Array Processing Algorithm (APA)
+/// 
+/// Describes how each element of the array is packed. Some algorithms
+/// require additional information, which is conveyed within the Array
+/// Processing Algorithm Support (APAS) that precedes the elements.
+pub enum Apa {
+    #[value = 1]
+    /// Natural Format (NAT)
+    /// 
+    /// Elements are IEEE 754 floats or big-endian integers of the
+    /// element size. No APAS.
+    Natural,
+    #[value = 2]
+    /// Elements are [`ImapB`] mapped floats of the element size
+    /// 
+    /// The APAS is the minimum and maximum as IEEE 754 floats, each
+    /// taking half of the APAS.
+    Imapb,
+    #[value = 3]
+    /// Elements are single bits, packed most significant bit first,
+    /// and padded with zero bits to the next byte. No APAS.
+    Boolean,
+    #[value = 4]
+    /// Elements are unsigned integers of the element size, offset by
+    /// a bias
+    /// 
+    /// The APAS is the BER-OID encoded bias, which is added to every
+    /// element. An empty APAS is a bias of zero.
+    UnsignedInteger,
+    #[value = 5]
+    /// Run-Length Encoding (RLE)
+    /// 
+    /// The elements are a background value in natural format, followed
+    /// by patches. Each patch is a value in natural format, then the
+    /// BER-OID encoded starting index of each dimension, then the
+    /// BER-OID encoded extent of each dimension. Every element of the
+    /// array not covered by a patch is the background value. No APAS.
+    RunLength,
+}
+
+/// Element of a [`Mdap`]
+/// 
+/// Implemented for [`f32`], [`f64`], [`bool`], and the signed and
+/// unsigned integers up to 64 bits.
+pub trait MdapElement: Copy + PartialEq {
+    /// Size in bytes of the element when encoded in natural format
+    const NATURAL_LEN: usize;
+    /// Decodes the element from natural format
+    /// 
+    /// Floats are 4 or 8 bytes, integers are between 1 and
+    /// [`MdapElement::NATURAL_LEN`] bytes, big-endian
+    fn from_natural(input: &[u8]) -> Option<Self>;
+    /// Encodes the element in natural format, using
+    /// [`MdapElement::NATURAL_LEN`] bytes
+    fn to_natural(&self) -> Vec<u8>;
+    /// Converts from a [`f64`], used by [`Apa::Imapb`]
+    fn from_f64(input: f64) -> Option<Self>;
+    /// Converts to a [`f64`], used by [`Apa::Imapb`]
+    fn to_f64(&self) -> f64;
+    /// Converts from a [`u64`], used by [`Apa::Boolean`] and [`Apa::UnsignedInteger`]
+    fn from_u64(input: u64) -> Option<Self>;
+    /// Converts to a [`u64`], used by [`Apa::Boolean`] and [`Apa::UnsignedInteger`]
+    /// 
+    /// Returns [`None`] if negative or fractional
+    fn to_u64(&self) -> Option<u64>;
+}
+
+/// Converts a big-endian IEEE 754 float of 4 or 8 bytes to a [`f64`]
+pub(crate) fn float_from_be(input: &[u8]) -> Option<f64> {
+    match input.len() {
+        4 => Some(f32::from_be_bytes(input.try_into().ok()?) as f64),
+        8 => Some(f64::from_be_bytes(input.try_into().ok()?)),
+        _ => None,
+    }
+}
+
+/// Converts a big-endian unsigned integer of up to 8 bytes to a [`u64`]
+fn uint_from_be(input: &[u8]) -> Option<u64> {
+    if input.is_empty() || input.len() > 8 { return None }
+    Some(input.iter().fold(0u64, |acc, x| (acc << 8) | *x as u64))
+}
+
+macro_rules! impl_float_element {
+    ($($t:ty),*) => {$(
+        /// [`MdapElement`] implementation for IEEE 754 floats
+        impl MdapElement for $t {
+            const NATURAL_LEN: usize = std::mem::size_of::<$t>();
+            fn from_natural(input: &[u8]) -> Option<Self> { float_from_be(input).map(|x| x as $t) }
+            fn to_natural(&self) -> Vec<u8> { self.to_be_bytes().to_vec() }
+            fn from_f64(input: f64) -> Option<Self> { Some(input as $t) }
+            fn to_f64(&self) -> f64 { *self as f64 }
+            fn from_u64(input: u64) -> Option<Self> { Some(input as $t) }
+            fn to_u64(&self) -> Option<u64> {
+                match self.fract() == 0.0 {
+                    true => num_traits::ToPrimitive::to_u64(self),
+                    false => None,
+                }
+            }
+        }
+    )*};
+}
+impl_float_element!(f32, f64);
+
+macro_rules! impl_int_element {
+    ($($t:ty),*) => {$(
+        /// [`MdapElement`] implementation for big-endian integers
+        impl MdapElement for $t {
+            const NATURAL_LEN: usize = std::mem::size_of::<$t>();
+            fn from_natural(input: &[u8]) -> Option<Self> {
+                if input.len() > Self::NATURAL_LEN { return None }
+                // --------------------------------------------------
+                // sign-extend shorter values, a no-op for unsigned
+                // --------------------------------------------------
+                let shift = 64 - 8 * input.len() as u32;
+                let value = uint_from_be(input)?;
+                let value = match <$t>::MIN != 0 {
+                    true => (((value << shift) as i64) >> shift) as $t,
+                    false => value as $t,
+                };
+                Some(value)
+            }
+            fn to_natural(&self) -> Vec<u8> { self.to_be_bytes().to_vec() }
+            fn from_f64(input: f64) -> Option<Self> { num_traits::FromPrimitive::from_f64(input.round()) }
+            fn to_f64(&self) -> f64 { *self as f64 }
+            fn from_u64(input: u64) -> Option<Self> { num_traits::FromPrimitive::from_u64(input) }
+            fn to_u64(&self) -> Option<u64> { num_traits::ToPrimitive::to_u64(self) }
+        }
+    )*};
+}
+impl_int_element!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+/// [`MdapElement`] implementation for [`bool`]
+/// 
+/// In natural format, any non-zero byte is `true`
+impl MdapElement for bool {
+    const NATURAL_LEN: usize = 1;
+    fn from_natural(input: &[u8]) -> Option<Self> { Some(input.iter().any(|x| *x != 0)) }
+    fn to_natural(&self) -> Vec<u8> { vec![*self as u8] }
+    fn from_f64(input: f64) -> Option<Self> { Some(input != 0.0) }
+    fn to_f64(&self) -> f64 { *self as u8 as f64 }
+    fn from_u64(input: u64) -> Option<Self> { Some(input != 0) }
+    fn to_u64(&self) -> Option<u64> { Some(*self as u64) }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Multi-Dimensional Array Pack (MDAP)
+/// 
+/// MISB Standard 1303
+/// 
+/// An N-dimensional array of elements, stored in row-major order,
+/// meaning the last dimension varies the fastest.
+/// 
+/// An encoded MDAP consists of a header of BER-OID encoded values: the
+/// number of dimensions, the size of each dimension, the element size,
+/// and the [`Apa`]. The header is followed by the Array Processing
+/// Algorithm Support (APAS), and then the elements.
+/// 
+/// For more information, see [Motion Imagery Standards Board (MISB)](https://nsgreg.nga.mil/misb.jsp)
+/// 
+/// # Example
+/// 
+/// ```
+/// use misb::misb1303::Mdap;
+/// // 1 row, 2 columns, 2-byte IMAPB elements in [0.0, 100.0]
+/// let mut input: &[u8] = &[
+///     0x02, 0x01, 0x02, 0x02, 0x02,
+///     0x00, 0x00, 0x00, 0x00, 0x42, 0xC8, 0x00, 0x00,
+///     0x00, 0x00, 0x01, 0x00,
+/// ];
+/// let mdap = Mdap::<f32>::decode(input.len())(&mut input).unwrap();
+/// assert_eq!(mdap.dimensions, vec![1, 2]);
+/// assert_eq!(mdap.get(&[0, 1]), Some(&1.0));
+/// ```
+pub struct Mdap<T: MdapElement> {
+    /// Size of each dimension
+    pub dimensions: Vec<usize>,
+    /// Elements, in row-major order
+    pub data: Vec<T>,
+}
+/// [`Mdap`] implementation
+impl<T: MdapElement> Mdap<T> {
+    /// Creates a new [`Mdap`], where the number of elements must be
+    /// the product of the `dimensions`
+    pub fn new(dimensions: Vec<usize>, data: Vec<T>) -> Result<Self, MdapError> {
+        let expected = dimensions.iter().product();
+        match data.len() == expected {
+            true => Ok(Self { dimensions, data }),
+            false => Err(MdapError::DimensionMismatch { expected, actual: data.len() }),
+        }
+    }
+
+    /// Number of elements
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if there are no elements
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns the position within [`Mdap::data`] of the element at
+    /// `index`, which has one entry per dimension
+    pub fn offset(&self, index: &[usize]) -> Option<usize> {
+        if index.len() != self.dimensions.len() { return None }
+        index
+            .iter()
+            .zip(&self.dimensions)
+            .try_fold(0usize, |acc, (i, n)| (i < n).then(|| acc * n + i))
+    }
+
+    /// Returns the element at `index`, which has one entry per
+    /// dimension, if within bounds
+    pub fn get(&self, index: &[usize]) -> Option<&T> {
+        self.data.get(self.offset(index)?)
+    }
+
+    /// Iterates over each row of the [`Mdap`], i.e. chunks of the
+    /// last dimension
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.data.chunks(self.dimensions.last().copied().unwrap_or(1).max(1))
+    }
+
+    /// Decodes a [`Mdap`] of length `len`
+    pub fn decode(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<Self> {
+        move |input: &mut &[u8]| {
+            let mut value = winnow::token::take(len).parse_next(input)?;
+            let value = &mut value;
+            // --------------------------------------------------
+            // header
+            // --------------------------------------------------
+            let dim = |input: &mut &[u8]| -> winnow::PResult<usize> {
+                let x = tinyklv::codecs::ber::dec::ber_oid::<u64>.parse_next(input)?;
+                usize::try_from(x).map_err(|_| tinyklv::err!())
+            };
+            // --------------------------------------------------
+            // each dimension takes at least one byte, bounding
+            // the number of dimensions by the remaining input
+            // --------------------------------------------------
+            let ndims = dim(value)?;
+            if ndims == 0 || ndims > value.len() { return Err(tinyklv::err!()) }
+            let dimensions: Vec<usize> = winnow::combinator::repeat(ndims, dim).parse_next(value)?;
+            let element_size = dim(value)?;
+            let apa = tinyklv::codecs::ber::dec::ber_oid::<u64>.parse_next(value)?;
+            let apa = u8::try_from(apa)
+                .ok()
+                .and_then(|x| Apa::try_from(x).ok())
+                .ok_or_else(|| tinyklv::err!())?;
+            let n = dimensions
+                .iter()
+                .try_fold(1usize, |acc, x| acc.checked_mul(*x))
+                .ok_or_else(|| tinyklv::err!())?;
+            if n == 0 || element_size == 0 { return Err(tinyklv::err!()) }
+            // --------------------------------------------------
+            // run-length elements are not bounded by the input,
+            // so their number is capped instead
+            // --------------------------------------------------
+            if matches!(apa, Apa::RunLength) && n > MAX_RUN_LENGTH_ELEMENTS { return Err(tinyklv::err!()) }
+            // --------------------------------------------------
+            // the APAS is whatever remains between the header
+            // and the elements
+            // --------------------------------------------------
+            let data_len = match apa {
+                Apa::Boolean => Some(n.div_ceil(8)),
+                Apa::RunLength => Some(value.len()),
+                _ => n.checked_mul(element_size),
+            };
+            let apas_len = data_len
+                .and_then(|x| value.len().checked_sub(x))
+                .ok_or_else(|| tinyklv::err!())?;
+            let apas = winnow::token::take(apas_len).parse_next(value)?;
+            let elements = std::mem::take(value);
+            let data = match apa {
+                Apa::Natural => elements
+                    .chunks_exact(element_size)
+                    .map(T::from_natural)
+                    .collect::<Option<Vec<T>>>(),
+                Apa::Imapb => {
+                    let (min, max) = apas.split_at(apas_len / 2);
+                    let imap = float_from_be(min)
+                        .zip(float_from_be(max))
+                        .and_then(|(min, max)| ImapB::new(min, max, element_size).ok())
+                        .ok_or_else(|| tinyklv::err!())?;
+                    elements
+                        .chunks_exact(element_size)
+                        .map(|x| imap.from_imap(x).ok().and_then(T::from_f64))
+                        .collect::<Option<Vec<T>>>()
+                }
+                Apa::Boolean => (0..n)
+                    .map(|i| T::from_u64(((elements[i / 8] >> (7 - i % 8)) & 0x01) as u64))
+                    .collect::<Option<Vec<T>>>(),
+                Apa::UnsignedInteger => {
+                    let bias = match apas.is_empty() {
+                        true => 0,
+                        false => tinyklv::codecs::ber::dec::ber_oid::<u64>.parse_next(&mut &apas[..])?,
+                    };
+                    elements
+                        .chunks_exact(element_size)
+                        .map(|x| uint_from_be(x).and_then(|x| x.checked_add(bias)).and_then(T::from_u64))
+                        .collect::<Option<Vec<T>>>()
+                }
+                Apa::RunLength => Self::decode_run_length(&dimensions, n, element_size)(&mut &elements[..]).ok(),
+            };
+            Ok(Self { dimensions, data: data.ok_or_else(|| tinyklv::err!())? })
+        }
+    }
+
+    /// Decodes the elements of an [`Apa::RunLength`] MDAP, which has `n`
+    /// elements in total
+    fn decode_run_length(dimensions: &[usize], n: usize, element_size: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<Vec<T>> + '_ {
+        move |input: &mut &[u8]| {
+            let element = |input: &mut &[u8]| -> winnow::PResult<T> {
+                let value = winnow::token::take(element_size).parse_next(input)?;
+                T::from_natural(value).ok_or_else(|| tinyklv::err!())
+            };
+            let background = element(input)?;
+            let mut output = Self { dimensions: dimensions.to_vec(), data: vec![background; n] };
+            while !input.is_empty() {
+                let value = element(input)?;
+                let start: Vec<u64> = winnow::combinator::repeat(dimensions.len(), tinyklv::codecs::ber::dec::ber_oid::<u64>).parse_next(input)?;
+                let extent: Vec<u64> = winnow::combinator::repeat(dimensions.len(), tinyklv::codecs::ber::dec::ber_oid::<u64>).parse_next(input)?;
+                output.fill(&start, &extent, value).ok_or_else(|| tinyklv::err!())?;
+            }
+            Ok(output.data)
+        }
+    }
+
+    /// Sets every element within the patch of `extent`, beginning at
+    /// `start`, to `value`
+    /// 
+    /// Returns [`None`] if the patch exceeds the bounds of the array
+    fn fill(&mut self, start: &[u64], extent: &[u64], value: T) -> Option<()> {
+        let end = start.iter().zip(extent).map(|(s, e)| s.checked_add(*e).map(|x| x as usize)).collect::<Option<Vec<_>>>()?;
+        if extent.contains(&0) { return Some(()) }
+        let mut index = start.iter().map(|x| *x as usize).collect::<Vec<_>>();
+        if end.iter().zip(&self.dimensions).any(|(e, n)| e > n) { return None }
+        loop {
+            let offset = self.offset(&index)?;
+            self.data[offset] = value;
+            // --------------------------------------------------
+            // increment the index, last dimension first
+            // --------------------------------------------------
+            let mut d = index.len();
+            loop {
+                if d == 0 { return Some(()) }
+                d -= 1;
+                index[d] += 1;
+                if index[d] < end[d] { break }
+                index[d] = start[d] as usize;
+            }
+        }
+    }
+
+    /// Encodes the MDAP header
+    fn encode_header(&self, element_size: usize, apa: Apa) -> Vec<u8> {
+        let mut output = tinyklv::codecs::ber::enc::ber_oid(&(self.dimensions.len() as u64));
+        for x in &self.dimensions { output.extend(tinyklv::codecs::ber::enc::ber_oid(&(*x as u64))) }
+        output.extend(tinyklv::codecs::ber::enc::ber_oid(&(element_size as u64)));
+        output.extend(tinyklv::codecs::ber::enc::ber_oid(&(*apa.value() as u64)));
+        output
+    }
+
+    /// Encodes the [`Mdap`] using [`Apa::Natural`], where each element
+    /// is [`MdapElement::NATURAL_LEN`] bytes
+    pub fn encode_natural(&self) -> Vec<u8> {
+        let mut output = self.encode_header(T::NATURAL_LEN, Apa::Natural);
+        output.extend(self.data.iter().flat_map(T::to_natural));
+        output
+    }
+
+    /// Encodes the [`Mdap`] using [`Apa::Imapb`], where each element is
+    /// mapped to `len` bytes between `min` and `max`
+    pub fn encode_imapb(&self, min: f64, max: f64, len: usize) -> Result<Vec<u8>, MdapError> {
+        let imap = ImapB::new(min, max, len)?;
+        let mut output = self.encode_header(len, Apa::Imapb);
+        output.extend(min.to_be_bytes());
+        output.extend(max.to_be_bytes());
+        for x in &self.data { output.extend(imap.to_imap(x.to_f64())?) }
+        Ok(output)
+    }
+
+    /// Encodes the [`Mdap`] using [`Apa::Boolean`], where every non-zero
+    /// element is a set bit
+    pub fn encode_boolean(&self) -> Vec<u8> {
+        let mut output = self.encode_header(1, Apa::Boolean);
+        output.extend(self.data.chunks(8).map(|x| {
+            x.iter()
+                .enumerate()
+                .fold(0u8, |acc, (i, x)| acc | (((x.to_f64() != 0.0) as u8) << (7 - i)))
+        }));
+        output
+    }
+
+    /// Encodes the [`Mdap`] using [`Apa::UnsignedInteger`], where each
+    /// element is `len` bytes, and the bias is the smallest element
+    pub fn encode_unsigned_integer(&self, len: usize) -> Result<Vec<u8>, MdapError> {
+        let values = self.data.iter().map(T::to_u64).collect::<Option<Vec<_>>>().ok_or(MdapError::Overflow(len))?;
+        let bias = values.iter().min().copied().unwrap_or_default();
+        let mut output = self.encode_header(len, Apa::UnsignedInteger);
+        output.extend(tinyklv::codecs::ber::enc::ber_oid(&bias));
+        for x in values.into_iter().map(|x| x - bias) {
+            if len == 0 || (len < 8 && x >> (8 * len) != 0) { return Err(MdapError::Overflow(len)) }
+            output.extend((0..len).rev().map(|i| match i < 8 {
+                true => ((x >> (8 * i)) & 0xFF) as u8,
+                false => 0,
+            }));
+        }
+        Ok(output)
+    }
+
+    /// Encodes the [`Mdap`] using [`Apa::RunLength`], where the background
+    /// is the first element, and every run of other values along the
+    /// last dimension is a patch
+    pub fn encode_run_length(&self) -> Vec<u8> {
+        let mut output = self.encode_header(T::NATURAL_LEN, Apa::RunLength);
+        let Some(background) = self.data.first() else { return output };
+        output.extend(background.to_natural());
+        let columns = self.dimensions.last().copied().unwrap_or(1).max(1);
+        for (row, values) in self.rows().enumerate() {
+            let mut column = 0;
+            while column < values.len() {
+                let value = values[column];
+                let run = values[column..].iter().take_while(|x| **x == value).count();
+                if value != *background {
+                    // --------------------------------------------------
+                    // row-major offset of the run, back to an index
+                    // --------------------------------------------------
+                    let mut offset = row * columns + column;
+                    let mut start = vec![0; self.dimensions.len()];
+                    for (d, n) in self.dimensions.iter().enumerate().rev() {
+                        start[d] = offset % n;
+                        offset /= n;
+                    }
+                    output.extend(value.to_natural());
+                    for x in &start { output.extend(tinyklv::codecs::ber::enc::ber_oid(&(*x as u64))) }
+                    for (d, _) in self.dimensions.iter().enumerate() {
+                        let extent = match d + 1 == self.dimensions.len() { true => run, false => 1 };
+                        output.extend(tinyklv::codecs::ber::enc::ber_oid(&(extent as u64)));
+                    }
+                }
+                column += run;
+            }
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn roundtrip<T: MdapElement + std::fmt::Debug>(mdap: &Mdap<T>, encoded: Vec<u8>) {
+        let decoded = Mdap::<T>::decode(encoded.len())(&mut encoded.as_slice()).unwrap();
+        assert_eq!(&decoded, mdap);
+    }
+
+    #[test]
+    fn natural() {
+        let mdap = Mdap::new(vec![2, 2, 2], vec![1.5f32, -2.0, 3.25, 4.0, 5.0, 6.0, 7.0, 8.0]).unwrap();
+        assert_eq!(mdap.get(&[1, 0, 1]), Some(&6.0));
+        assert_eq!(mdap.get(&[2, 0, 0]), None);
+        assert_eq!(mdap.get(&[0, 0]), None);
+        roundtrip(&mdap, mdap.encode_natural());
+        let mdap = Mdap::new(vec![3], vec![-1i16, 0, 300]).unwrap();
+        let encoded = mdap.encode_natural();
+        assert_eq!(encoded, [0x01, 0x03, 0x02, 0x01, 0xFF, 0xFF, 0x00, 0x00, 0x01, 0x2C]);
+        roundtrip(&mdap, encoded);
+        // --------------------------------------------------
+        // shorter elements are sign-extended
+        // --------------------------------------------------
+        let decoded = Mdap::<i16>::decode(6)(&mut &[0x01, 0x02, 0x01, 0x01, 0xFF, 0x7F][..]).unwrap();
+        assert_eq!(decoded.data, vec![-1, 127]);
+        assert_eq!(Mdap::new(vec![2, 2], vec![1u8]), Err(MdapError::DimensionMismatch { expected: 4, actual: 1 }));
+    }
+
+    #[test]
+    fn imapb() {
+        let mdap = Mdap::new(vec![1, 3], vec![0.0f64, 50.0, 100.0]).unwrap();
+        roundtrip(&mdap, mdap.encode_imapb(0.0, 100.0, 4).unwrap());
+        assert!(mdap.encode_imapb(100.0, 0.0, 4).is_err());
+    }
+
+    #[test]
+    fn boolean() {
+        let mdap = Mdap::new(vec![3, 3], vec![true, false, true, true, true, false, false, false, true]).unwrap();
+        let encoded = mdap.encode_boolean();
+        assert_eq!(encoded, [0x02, 0x03, 0x03, 0x01, 0x03, 0b1011_1000, 0b1000_0000]);
+        roundtrip(&mdap, encoded);
+    }
+
+    #[test]
+    fn unsigned_integer() {
+        let mdap = Mdap::new(vec![4], vec![1000u32, 1001, 1255, 1000]).unwrap();
+        let encoded = mdap.encode_unsigned_integer(1).unwrap();
+        assert_eq!(encoded, [0x01, 0x04, 0x01, 0x04, 0x87, 0x68, 0x00, 0x01, 0xFF, 0x00]);
+        roundtrip(&mdap, encoded);
+        assert_eq!(mdap.encode_unsigned_integer(0), Err(MdapError::Overflow(0)));
+        let mdap = Mdap::new(vec![2], vec![0u32, 256]).unwrap();
+        assert_eq!(mdap.encode_unsigned_integer(1), Err(MdapError::Overflow(1)));
+        let mdap = Mdap::new(vec![1], vec![-1i8]).unwrap();
+        assert_eq!(mdap.encode_unsigned_integer(1), Err(MdapError::Overflow(1)));
+    }
+
+    #[test]
+    fn run_length() {
+        let mdap = Mdap::new(vec![2, 4], vec![0u8, 7, 7, 0, 0, 0, 0, 3]).unwrap();
+        let encoded = mdap.encode_run_length();
+        assert_eq!(encoded, [
+            0x02, 0x02, 0x04, 0x01, 0x05,
+            0x00,
+            0x07, 0x00, 0x01, 0x01, 0x02,
+            0x03, 0x01, 0x03, 0x01, 0x01,
+        ]);
+        roundtrip(&mdap, encoded);
+        // --------------------------------------------------
+        // a single patch spanning both dimensions
+        // --------------------------------------------------
+        let decoded = Mdap::<u8>::decode(11)(&mut &[0x02, 0x03, 0x03, 0x01, 0x05, 0x00, 0x09, 0x01, 0x01, 0x02, 0x02][..]).unwrap();
+        assert_eq!(decoded.rows().collect::<Vec<_>>(), vec![&[0, 0, 0][..], &[0, 9, 9][..], &[0, 9, 9][..]]);
+        assert!(Mdap::<u8>::decode(11)(&mut &[0x02, 0x03, 0x03, 0x01, 0x05, 0x00, 0x09, 0x01, 0x02, 0x02, 0x02][..]).is_err());
+    }
+
+    #[test]
+    fn hostile_header() {
+        let decode = |input: &[u8]| Mdap::<u8>::decode(input.len())(&mut &input[..]);
+        // --------------------------------------------------
+        // no dimensions, or a zero dimension
+        // --------------------------------------------------
+        assert!(decode(&[0x00, 0x01, 0x01, 0x00]).is_err());
+        assert!(decode(&[0x01, 0x00, 0x01, 0x01]).is_err());
+        // --------------------------------------------------
+        // more dimensions than remaining bytes
+        // --------------------------------------------------
+        assert!(decode(&[0x8F, 0xFF, 0xFF, 0xFF, 0x7F, 0x01, 0x01, 0x01]).is_err());
+        // --------------------------------------------------
+        // dimensions whose product overflows
+        // --------------------------------------------------
+        let mut input = vec![0x02];
+        for _ in 0..2 { input.extend(tinyklv::codecs::ber::enc::ber_oid(&(u64::MAX >> 1))) }
+        input.extend([0x01, 0x01, 0x00]);
+        assert!(decode(&input).is_err());
+        // --------------------------------------------------
+        // more natural elements than remaining bytes
+        // --------------------------------------------------
+        assert!(decode(&[0x02, 0x84, 0x00, 0x84, 0x00, 0x01, 0x01, 0x00, 0x00]).is_err());
+        // --------------------------------------------------
+        // run-length elements beyond the maximum
+        // --------------------------------------------------
+        let mut input = vec![0x01];
+        input.extend(tinyklv::codecs::ber::enc::ber_oid(&(MAX_RUN_LENGTH_ELEMENTS as u64 + 1)));
+        input.extend([0x01, 0x05, 0x00]);
+        assert!(decode(&input).is_err());
+        // --------------------------------------------------
+        // IMAPB range which does not fit into the element size
+        // --------------------------------------------------
+        let mut input = vec![0x01, 0x01, 0x01, 0x02];
+        input.extend(0.0f32.to_be_bytes());
+        input.extend(1e6f32.to_be_bytes());
+        input.push(0x00);
+        assert!(decode(&input).is_err());
+        // --------------------------------------------------
+        // IMAPB element size beyond 8 bytes
+        // --------------------------------------------------
+        let mut input = vec![0x01, 0x01, 0x09, 0x02];
+        input.extend(0.0f32.to_be_bytes());
+        input.extend(1.0f32.to_be_bytes());
+        input.extend([0x00; 9]);
+        assert!(decode(&input).is_err());
+    }
+}
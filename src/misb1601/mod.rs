@@ -0,0 +1,401 @@
+// --------------------------------------------------
+// tinyklv
+// --------------------------------------------------
+use tinyklv::Klv;
+use tinyklv::prelude::*;
+
+// --------------------------------------------------
+// local
+// --------------------------------------------------
+pub mod ops;
+
+#[cfg(any(
+    feature = "misb1601-2",
+))]
+#[derive(Klv, Debug)]
+#[klv(
+    // ------------------------------------------------
+    // Local Set only, embedded within MISB 0601
+    // ------------------------------------------------
+    stream = &[u8],
+    key(enc = tinyklv::codecs::ber::enc::ber_oid,
+        dec = tinyklv::codecs::ber::dec::ber_oid::<u64>),
+    len(enc = tinyklv::codecs::ber::enc::ber_length,
+        dec = tinyklv::codecs::ber::dec::ber_length),
+    default(ty = u8, dyn = true, dec = tinyklv::codecs::binary::dec::be_u8_lengthed),
+    default(ty = String, dyn = true, dec = tinyklv::codecs::binary::dec::to_string_utf8),
+)]
+/// Geo-Registration Local Set
+/// 
+/// MISB Standard 1601
+/// 
+/// Conveys the result of a geo-registration algorithm, which matches
+/// the Motion Imagery frame against a secondary (reference) image. The
+/// corrected frame center and corners supersede the uncorrected values
+/// of MISB 0601, e.g. [`crate::misb0601::Misb0601::offset_corner_lat_p1`].
+/// 
+/// See [`crate::misb0601::Misb0601::geo_registration_local_set`]
+/// 
+/// For more information, see [Motion Imagery Standards Board (MISB)](https://nsgreg.nga.mil/misb.jsp)
+pub struct Misb1601 {
+    #[cfg(any(
+        feature = "misb1601-2",
+    ))]
+    #[klv(key = 0x01)]
+    /// (Mandatory) Version number of the MISB ST 1601 document used to encode
+    /// the Geo-Registration LS
+    /// 
+    /// Units: None
+    pub document_version: Option<u8>,
+
+    #[cfg(any(
+        feature = "misb1601-2",
+    ))]
+    #[klv(key = 0x02)]
+    /// (Mandatory) Name of the geo-registration algorithm
+    /// 
+    /// Units: None
+    pub algorithm_name: Option<String>,
+
+    #[cfg(any(
+        feature = "misb1601-2",
+    ))]
+    #[klv(key = 0x03)]
+    /// (Mandatory) Version of the geo-registration algorithm
+    /// 
+    /// Units: None
+    pub algorithm_version: Option<String>,
+
+    #[cfg(any(
+        feature = "misb1601-2",
+    ))]
+    #[klv(key = 0x04, dyn = true, dec = ops::to_point_pairs)]
+    /// (Optional) Row and column of each correspondence point within the Motion
+    /// Imagery frame, as an MDAP with one row per point
+    /// 
+    /// See [`Misb1601::correspondence_points`]
+    /// 
+    /// Units: Pixels
+    pub correspondence_points_row_column: Option<crate::misb1303::Mdap<f64>>,
+
+    #[cfg(any(
+        feature = "misb1601-2",
+    ))]
+    #[klv(key = 0x05, dyn = true, dec = ops::to_point_pairs)]
+    /// (Optional) Latitude and longitude of each correspondence point, as an
+    /// MDAP with one row per point
+    /// 
+    /// See [`Misb1601::correspondence_points`]
+    /// 
+    /// Units: Degrees (°)
+    pub correspondence_points_latitude_longitude: Option<crate::misb1303::Mdap<f64>>,
+
+    #[cfg(any(
+        feature = "misb1601-2",
+    ))]
+    #[klv(key = 0x06)]
+    /// (Optional) Name of the secondary (reference) image the Motion Imagery
+    /// frame was registered against
+    /// 
+    /// Units: None
+    pub secondary_image_name: Option<String>,
+
+    #[cfg(any(
+        feature = "misb1601-2",
+    ))]
+    #[klv(key = 0x07)]
+    /// (Optional) Identifier of the configuration the geo-registration
+    /// algorithm was run with
+    /// 
+    /// Units: None
+    pub algorithm_configuration_identifier: Option<String>,
+
+    #[cfg(any(
+        feature = "misb1601-2",
+    ))]
+    #[klv(key = 0x08, dyn = true, dec = ops::to_point_values)]
+    /// (Optional) Height above the ellipsoid of each correspondence point, as
+    /// an MDAP with one element per point
+    /// 
+    /// Units: Meters (m)
+    pub correspondence_points_elevation: Option<crate::misb1303::Mdap<f64>>,
+
+    #[cfg(any(
+        feature = "misb1601-2",
+    ))]
+    #[klv(key = 0x09, dec = crate::misb0601::ops::to_lat)]
+    /// (Optional) Geo-registered latitude of the center of the Motion Imagery frame
+    /// 
+    /// See [`crate::misb0601::Misb0601::frame_center_latitude`]
+    /// 
+    /// Units: Degrees (°)
+    pub corrected_frame_center_latitude: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb1601-2",
+    ))]
+    #[klv(key = 0x0A, dec = crate::misb0601::ops::to_lon)]
+    /// (Optional) Geo-registered longitude of the center of the Motion Imagery frame
+    /// 
+    /// See [`crate::misb0601::Misb0601::frame_center_longitude`]
+    /// 
+    /// Units: Degrees (°)
+    pub corrected_frame_center_longitude: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb1601-2",
+    ))]
+    #[klv(key = 0x0B, dec = crate::misb0601::ops::to_alt)]
+    /// (Optional) Geo-registered altitude of the center of the Motion Imagery
+    /// frame, relative to mean sea level
+    /// 
+    /// See [`crate::misb0601::Misb0601::frame_center_elevation`]
+    /// 
+    /// Units: Meters (m)
+    pub corrected_frame_center_elevation: Option<f32>,
+
+    #[cfg(any(
+        feature = "misb1601-2",
+    ))]
+    #[klv(key = 0x0C, dec = crate::misb0601::ops::to_lat)]
+    /// (Optional) Geo-registered latitude of the upper left corner of the Motion
+    /// Imagery frame, full range
+    /// 
+    /// See [`Misb1601::corrected_corners`]
+    /// 
+    /// Units: Degrees (°)
+    pub corrected_corner_latitude_point_1: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb1601-2",
+    ))]
+    #[klv(key = 0x0D, dec = crate::misb0601::ops::to_lon)]
+    /// (Optional) Geo-registered longitude of the upper left corner of the Motion
+    /// Imagery frame, full range
+    /// 
+    /// See [`Misb1601::corrected_corners`]
+    /// 
+    /// Units: Degrees (°)
+    pub corrected_corner_longitude_point_1: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb1601-2",
+    ))]
+    #[klv(key = 0x0E, dec = crate::misb0601::ops::to_lat)]
+    /// (Optional) Geo-registered latitude of the upper right corner of the Motion
+    /// Imagery frame, full range
+    /// 
+    /// See [`Misb1601::corrected_corners`]
+    /// 
+    /// Units: Degrees (°)
+    pub corrected_corner_latitude_point_2: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb1601-2",
+    ))]
+    #[klv(key = 0x0F, dec = crate::misb0601::ops::to_lon)]
+    /// (Optional) Geo-registered longitude of the upper right corner of the Motion
+    /// Imagery frame, full range
+    /// 
+    /// See [`Misb1601::corrected_corners`]
+    /// 
+    /// Units: Degrees (°)
+    pub corrected_corner_longitude_point_2: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb1601-2",
+    ))]
+    #[klv(key = 0x10, dec = crate::misb0601::ops::to_lat)]
+    /// (Optional) Geo-registered latitude of the lower right corner of the Motion
+    /// Imagery frame, full range
+    /// 
+    /// See [`Misb1601::corrected_corners`]
+    /// 
+    /// Units: Degrees (°)
+    pub corrected_corner_latitude_point_3: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb1601-2",
+    ))]
+    #[klv(key = 0x11, dec = crate::misb0601::ops::to_lon)]
+    /// (Optional) Geo-registered longitude of the lower right corner of the Motion
+    /// Imagery frame, full range
+    /// 
+    /// See [`Misb1601::corrected_corners`]
+    /// 
+    /// Units: Degrees (°)
+    pub corrected_corner_longitude_point_3: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb1601-2",
+    ))]
+    #[klv(key = 0x12, dec = crate::misb0601::ops::to_lat)]
+    /// (Optional) Geo-registered latitude of the lower left corner of the Motion
+    /// Imagery frame, full range
+    /// 
+    /// See [`Misb1601::corrected_corners`]
+    /// 
+    /// Units: Degrees (°)
+    pub corrected_corner_latitude_point_4: Option<f64>,
+
+    #[cfg(any(
+        feature = "misb1601-2",
+    ))]
+    #[klv(key = 0x13, dec = crate::misb0601::ops::to_lon)]
+    /// (Optional) Geo-registered longitude of the lower left corner of the Motion
+    /// Imagery frame, full range
+    /// 
+    /// See [`Misb1601::corrected_corners`]
+    /// 
+    /// Units: Degrees (°)
+    pub corrected_corner_longitude_point_4: Option<f64>,
+}
+
+#[cfg(feature = "misb1601-2")]
+/// [`Misb1601`] implementation
+impl Misb1601 {
+    /// Pairs each correspondence point's row and column with its latitude,
+    /// longitude, and elevation (if present)
+    /// 
+    /// Returns an empty vector if either the row / column or latitude /
+    /// longitude items are missing. Points are paired by index, up to
+    /// the shorter of the two.
+    pub fn correspondence_points(&self) -> Vec<CorrespondencePoint> {
+        let (Some(pixels), Some(coordinates)) = (
+            &self.correspondence_points_row_column,
+            &self.correspondence_points_latitude_longitude,
+        ) else { return Vec::new() };
+        pixels
+            .rows()
+            .zip(coordinates.rows())
+            .enumerate()
+            .map(|(i, (pixel, coordinate))| CorrespondencePoint {
+                row: pixel[0],
+                column: pixel[1],
+                latitude: coordinate[0],
+                longitude: coordinate[1],
+                elevation: self.correspondence_points_elevation.as_ref().and_then(|x| x.get(&[i]).copied()),
+            })
+            .collect()
+    }
+
+    /// Returns the corrected `(latitude, longitude)` of the upper left,
+    /// upper right, lower right, and lower left corners, in that order,
+    /// if all are present
+    pub fn corrected_corners(&self) -> Option<[(f64, f64); 4]> {
+        Some([
+            (self.corrected_corner_latitude_point_1?, self.corrected_corner_longitude_point_1?),
+            (self.corrected_corner_latitude_point_2?, self.corrected_corner_longitude_point_2?),
+            (self.corrected_corner_latitude_point_3?, self.corrected_corner_longitude_point_3?),
+            (self.corrected_corner_latitude_point_4?, self.corrected_corner_longitude_point_4?),
+        ])
+    }
+
+    /// Encodes every present item of the [`Misb1601`] in tag order
+    /// 
+    /// This is the value of the Geo-Registration LS when embedded within
+    /// MISB 0601, see [`crate::misb0601::Misb0601::geo_registration_local_set`]
+    pub fn encode_value(&self) -> Vec<u8> {
+        let mut output = Vec::new();
+        if let Some(x) = self.document_version { crate::encode_item(&mut output, 0x01, &[x]) }
+        if let Some(x) = &self.algorithm_name { crate::encode_item(&mut output, 0x02, x.as_bytes()) }
+        if let Some(x) = &self.algorithm_version { crate::encode_item(&mut output, 0x03, x.as_bytes()) }
+        if let Some(x) = &self.correspondence_points_row_column { crate::encode_item(&mut output, 0x04, &ops::from_points(x)) }
+        if let Some(x) = &self.correspondence_points_latitude_longitude { crate::encode_item(&mut output, 0x05, &ops::from_points(x)) }
+        if let Some(x) = &self.secondary_image_name { crate::encode_item(&mut output, 0x06, x.as_bytes()) }
+        if let Some(x) = &self.algorithm_configuration_identifier { crate::encode_item(&mut output, 0x07, x.as_bytes()) }
+        if let Some(x) = &self.correspondence_points_elevation { crate::encode_item(&mut output, 0x08, &ops::from_points(x)) }
+        if let Some(x) = self.corrected_frame_center_latitude { crate::encode_item(&mut output, 0x09, &crate::misb0601::ops::from_lat(x)) }
+        if let Some(x) = self.corrected_frame_center_longitude { crate::encode_item(&mut output, 0x0A, &crate::misb0601::ops::from_lon(x)) }
+        if let Some(x) = self.corrected_frame_center_elevation { crate::encode_item(&mut output, 0x0B, &crate::misb0601::ops::from_alt(x)) }
+        if let Some(x) = self.corrected_corner_latitude_point_1 { crate::encode_item(&mut output, 0x0C, &crate::misb0601::ops::from_lat(x)) }
+        if let Some(x) = self.corrected_corner_longitude_point_1 { crate::encode_item(&mut output, 0x0D, &crate::misb0601::ops::from_lon(x)) }
+        if let Some(x) = self.corrected_corner_latitude_point_2 { crate::encode_item(&mut output, 0x0E, &crate::misb0601::ops::from_lat(x)) }
+        if let Some(x) = self.corrected_corner_longitude_point_2 { crate::encode_item(&mut output, 0x0F, &crate::misb0601::ops::from_lon(x)) }
+        if let Some(x) = self.corrected_corner_latitude_point_3 { crate::encode_item(&mut output, 0x10, &crate::misb0601::ops::from_lat(x)) }
+        if let Some(x) = self.corrected_corner_longitude_point_3 { crate::encode_item(&mut output, 0x11, &crate::misb0601::ops::from_lon(x)) }
+        if let Some(x) = self.corrected_corner_latitude_point_4 { crate::encode_item(&mut output, 0x12, &crate::misb0601::ops::from_lat(x)) }
+        if let Some(x) = self.corrected_corner_longitude_point_4 { crate::encode_item(&mut output, 0x13, &crate::misb0601::ops::from_lon(x)) }
+        output
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A single correspondence point, relating a pixel of the Motion Imagery
+/// frame to a location on the ground
+/// 
+/// See [`Misb1601::correspondence_points`]
+pub struct CorrespondencePoint {
+    /// Row within the Motion Imagery frame
+    /// 
+    /// Units: Pixels
+    pub row: f64,
+    /// Column within the Motion Imagery frame
+    /// 
+    /// Units: Pixels
+    pub column: f64,
+    /// Latitude of the point
+    /// 
+    /// Units: Degrees (°)
+    pub latitude: f64,
+    /// Longitude of the point
+    /// 
+    /// Units: Degrees (°)
+    pub longitude: f64,
+    /// Height above the ellipsoid of the point, if known
+    /// 
+    /// Units: Meters (m)
+    pub elevation: Option<f64>,
+}
+
+#[cfg(test)]
+#[cfg(feature = "misb1601-2")]
+mod test {
+    use super::*;
+    use tinyklv::prelude::*;
+    use crate::misb1303::Mdap;
+
+    #[test]
+    fn encode_roundtrip() {
+        let georeg = Misb1601 {
+            document_version: Some(2),
+            algorithm_name: Some(String::from("ORB-RANSAC")),
+            algorithm_version: Some(String::from("1.4.0")),
+            correspondence_points_row_column: Some(Mdap::new(vec![2, 2], vec![10.0, 20.0, 400.5, 600.25]).unwrap()),
+            correspondence_points_latitude_longitude: Some(Mdap::new(vec![2, 2], vec![38.8, -77.1, 38.7, -77.0]).unwrap()),
+            secondary_image_name: Some(String::from("CIB-1M-0042")),
+            algorithm_configuration_identifier: None,
+            correspondence_points_elevation: Some(Mdap::new(vec![1], vec![12.5]).unwrap()),
+            corrected_frame_center_latitude: Some(38.75),
+            corrected_frame_center_longitude: Some(-77.05),
+            corrected_frame_center_elevation: Some(100.0),
+            corrected_corner_latitude_point_1: Some(38.8),
+            corrected_corner_longitude_point_1: Some(-77.1),
+            corrected_corner_latitude_point_2: Some(38.8),
+            corrected_corner_longitude_point_2: Some(-77.0),
+            corrected_corner_latitude_point_3: Some(38.7),
+            corrected_corner_longitude_point_3: Some(-77.0),
+            corrected_corner_latitude_point_4: Some(38.7),
+            corrected_corner_longitude_point_4: None,
+        };
+        let encoded = georeg.encode_value();
+        let decoded = Misb1601::decode(&mut encoded.as_slice()).unwrap();
+        assert_eq!(decoded.document_version, Some(2));
+        assert_eq!(decoded.algorithm_name.as_deref(), Some("ORB-RANSAC"));
+        assert_eq!(decoded.algorithm_version.as_deref(), Some("1.4.0"));
+        assert_eq!(decoded.secondary_image_name.as_deref(), Some("CIB-1M-0042"));
+        assert_eq!(decoded.algorithm_configuration_identifier, None);
+        assert!((decoded.corrected_frame_center_latitude.unwrap() - 38.75).abs() < 1e-6);
+        assert!((decoded.corrected_frame_center_longitude.unwrap() + 77.05).abs() < 1e-6);
+        assert!((decoded.corrected_frame_center_elevation.unwrap() - 100.0).abs() < 0.5);
+        assert_eq!(decoded.corrected_corners(), None);
+        // --------------------------------------------------
+        // correspondence points
+        // --------------------------------------------------
+        let points = decoded.correspondence_points();
+        assert_eq!(points.len(), 2);
+        assert_eq!(points[0], CorrespondencePoint { row: 10.0, column: 20.0, latitude: 38.8, longitude: -77.1, elevation: Some(12.5) });
+        assert_eq!(points[1], CorrespondencePoint { row: 400.5, column: 600.25, latitude: 38.7, longitude: -77.0, elevation: None });
+        assert_eq!(decoded.encode_value(), encoded);
+    }
+}
@@ -0,0 +1,48 @@
+// --------------------------------------------------
+// tinyklv
+// --------------------------------------------------
+use tinyklv::prelude::*;
+
+// --------------------------------------------------
+// local
+// --------------------------------------------------
+use crate::misb1303::Mdap;
+
+/// See [`crate::misb1601::Misb1601`]
+/// 
+/// * [`crate::misb1601::Misb1601::correspondence_points_row_column`]
+/// * [`crate::misb1601::Misb1601::correspondence_points_latitude_longitude`]
+/// 
+/// A 2-dimensional [`Mdap`] of length `len`, with one row per
+/// correspondence point and 2 columns
+pub fn to_point_pairs(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<Mdap<f64>> {
+    move |input: &mut &[u8]| {
+        let output = Mdap::<f64>::decode(len)(input)?;
+        match output.dimensions.as_slice() {
+            [_, 2] => Ok(output),
+            _ => Err(tinyklv::err!()),
+        }
+    }
+}
+
+/// See [`crate::misb1601::Misb1601::correspondence_points_elevation`]
+/// 
+/// A 1-dimensional [`Mdap`] of length `len`, with one element per
+/// correspondence point
+pub fn to_point_values(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<Mdap<f64>> {
+    move |input: &mut &[u8]| {
+        let output = Mdap::<f64>::decode(len)(input)?;
+        match output.dimensions.len() {
+            1 => Ok(output),
+            _ => Err(tinyklv::err!()),
+        }
+    }
+}
+
+#[inline(always)]
+/// See [`to_point_pairs`] and [`to_point_values`]
+/// 
+/// Always encoded using [`crate::misb1303::Apa::Natural`], with 8-byte elements
+pub fn from_points(input: &Mdap<f64>) -> Vec<u8> {
+    input.encode_natural()
+}
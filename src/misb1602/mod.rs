@@ -0,0 +1,390 @@
+// --------------------------------------------------
+// tinyklv
+// --------------------------------------------------
+use tinyklv::Klv;
+use tinyklv::prelude::*;
+
+#[cfg(any(
+    feature = "misb1602-1",
+))]
+#[derive(Klv, Debug)]
+#[klv(
+    // ------------------------------------------------
+    // Local Set only, embedded within MISB 0601
+    // ------------------------------------------------
+    stream = &[u8],
+    key(enc = tinyklv::codecs::ber::enc::ber_oid,
+        dec = tinyklv::codecs::ber::dec::ber_oid::<u64>),
+    len(enc = tinyklv::codecs::ber::enc::ber_length,
+        dec = tinyklv::codecs::ber::dec::ber_length),
+    default(ty = u8, dyn = true, dec = tinyklv::codecs::binary::dec::be_u8_lengthed),
+    default(ty = u32, dyn = true, dec = tinyklv::codecs::binary::dec::be_u32_lengthed),
+)]
+/// Composite Imaging Local Set
+/// 
+/// MISB Standard 1602
+/// 
+/// A composite image (e.g. picture-in-picture, or a mosaic of tiles) is
+/// made of one or more sub-images, each from a different source image.
+/// Each [`Misb1602`] describes a single sub-image: where its source AOI
+/// is taken from, and where it is placed within the composite image.
+/// 
+/// See [`Misb1602::to_source_pixel`] and [`find_sub_image`], to relate a
+/// pixel of the composite image (e.g. a [`crate::misb0903::Misb0903Target`]
+/// centroid) back to its source image.
+/// 
+/// See [`crate::misb0601::Misb0601::composite_imaging_local_set`]
+/// 
+/// For more information, see [Motion Imagery Standards Board (MISB)](https://nsgreg.nga.mil/misb.jsp)
+pub struct Misb1602 {
+    #[cfg(any(
+        feature = "misb1602-1",
+    ))]
+    #[klv(key = 0x01, dec = crate::misb0601::ops::to_precision_timestamp)]
+    /// (Mandatory) Microsecond count from Epoch of 1970, of the composite frame
+    /// See MISP Time System - MISB ST 0603
+    /// 
+    /// Units: Microseconds (μs)
+    pub precision_timestamp: Option<chrono::DateTime<chrono::Utc>>,
+
+    #[cfg(any(
+        feature = "misb1602-1",
+    ))]
+    #[klv(key = 0x02)]
+    /// (Mandatory) Version number of the MISB ST 1602 document used to encode
+    /// the Composite Imaging LS
+    /// 
+    /// Units: None
+    pub document_version: Option<u8>,
+
+    #[cfg(any(
+        feature = "misb1602-1",
+    ))]
+    #[klv(key = 0x03)]
+    /// (Optional) Number of rows of the source image
+    /// 
+    /// Units: Pixels
+    pub source_image_rows: Option<u32>,
+
+    #[cfg(any(
+        feature = "misb1602-1",
+    ))]
+    #[klv(key = 0x04)]
+    /// (Optional) Number of columns of the source image
+    /// 
+    /// Units: Pixels
+    pub source_image_columns: Option<u32>,
+
+    #[cfg(any(
+        feature = "misb1602-1",
+    ))]
+    #[klv(key = 0x05)]
+    /// (Optional) Number of rows of the area of interest (AOI) of the source
+    /// image shown within the sub-image
+    /// 
+    /// Defaults to [`Misb1602::source_image_rows`]
+    /// 
+    /// Units: Pixels
+    pub source_image_aoi_rows: Option<u32>,
+
+    #[cfg(any(
+        feature = "misb1602-1",
+    ))]
+    #[klv(key = 0x06)]
+    /// (Optional) Number of columns of the area of interest (AOI) of the source
+    /// image shown within the sub-image
+    /// 
+    /// Defaults to [`Misb1602::source_image_columns`]
+    /// 
+    /// Units: Pixels
+    pub source_image_aoi_columns: Option<u32>,
+
+    #[cfg(any(
+        feature = "misb1602-1",
+    ))]
+    #[klv(key = 0x07)]
+    /// (Optional) Column of the upper left corner of the AOI within the source
+    /// image, starting at 0
+    /// 
+    /// Defaults to 0
+    /// 
+    /// Units: Pixels
+    pub source_image_aoi_position_x: Option<u32>,
+
+    #[cfg(any(
+        feature = "misb1602-1",
+    ))]
+    #[klv(key = 0x08)]
+    /// (Optional) Row of the upper left corner of the AOI within the source
+    /// image, starting at 0
+    /// 
+    /// Defaults to 0
+    /// 
+    /// Units: Pixels
+    pub source_image_aoi_position_y: Option<u32>,
+
+    #[cfg(any(
+        feature = "misb1602-1",
+    ))]
+    #[klv(key = 0x09)]
+    /// (Mandatory) Number of rows of the sub-image within the composite image
+    /// 
+    /// Units: Pixels
+    pub sub_image_rows: Option<u32>,
+
+    #[cfg(any(
+        feature = "misb1602-1",
+    ))]
+    #[klv(key = 0x0A)]
+    /// (Mandatory) Number of columns of the sub-image within the composite image
+    /// 
+    /// Units: Pixels
+    pub sub_image_columns: Option<u32>,
+
+    #[cfg(any(
+        feature = "misb1602-1",
+    ))]
+    #[klv(key = 0x0B)]
+    /// (Mandatory) Column of the upper left corner of the sub-image within the
+    /// composite image, starting at 0
+    /// 
+    /// Units: Pixels
+    pub sub_image_position_x: Option<u32>,
+
+    #[cfg(any(
+        feature = "misb1602-1",
+    ))]
+    #[klv(key = 0x0C)]
+    /// (Mandatory) Row of the upper left corner of the sub-image within the
+    /// composite image, starting at 0
+    /// 
+    /// Units: Pixels
+    pub sub_image_position_y: Option<u32>,
+
+    #[cfg(any(
+        feature = "misb1602-1",
+    ))]
+    #[klv(key = 0x0D)]
+    /// (Optional) Number of rows of the sub-image containing source imagery,
+    /// e.g. excluding letterboxing
+    /// 
+    /// Defaults to [`Misb1602::sub_image_rows`]
+    /// 
+    /// Units: Pixels
+    pub active_sub_image_rows: Option<u32>,
+
+    #[cfg(any(
+        feature = "misb1602-1",
+    ))]
+    #[klv(key = 0x0E)]
+    /// (Optional) Number of columns of the sub-image containing source imagery,
+    /// e.g. excluding pillarboxing
+    /// 
+    /// Defaults to [`Misb1602::sub_image_columns`]
+    /// 
+    /// Units: Pixels
+    pub active_sub_image_columns: Option<u32>,
+
+    #[cfg(any(
+        feature = "misb1602-1",
+    ))]
+    #[klv(key = 0x0F)]
+    /// (Optional) Column offset of the active sub-image from the upper left
+    /// corner of the sub-image
+    /// 
+    /// Defaults to 0
+    /// 
+    /// Units: Pixels
+    pub active_sub_image_offset_x: Option<u32>,
+
+    #[cfg(any(
+        feature = "misb1602-1",
+    ))]
+    #[klv(key = 0x10)]
+    /// (Optional) Row offset of the active sub-image from the upper left
+    /// corner of the sub-image
+    /// 
+    /// Defaults to 0
+    /// 
+    /// Units: Pixels
+    pub active_sub_image_offset_y: Option<u32>,
+
+    #[cfg(any(
+        feature = "misb1602-1",
+    ))]
+    #[klv(key = 0x11)]
+    /// (Optional) Transparency of the sub-image, where 0 is fully opaque and
+    /// 255 is fully transparent
+    /// 
+    /// Units: None
+    pub transparency: Option<u8>,
+
+    #[cfg(any(
+        feature = "misb1602-1",
+    ))]
+    #[klv(key = 0x12)]
+    /// (Optional) Layer of the sub-image, where a larger value is drawn over
+    /// sub-images of a smaller value
+    /// 
+    /// Defaults to 0
+    /// 
+    /// Units: None
+    pub z_order: Option<u8>,
+
+    #[cfg(any(
+        feature = "misb1602-1",
+    ))]
+    #[klv(key = 0x13, dyn = true, dec = crate::decode_exact::<crate::misb1204::Misb1204Miis>)]
+    /// (Optional) MISB ST 1204 MIIS Core Identifier of the source image
+    /// 
+    /// Units: None
+    pub miis_core_identifier: Option<crate::misb1204::Misb1204Miis>,
+}
+
+#[cfg(feature = "misb1602-1")]
+/// [`Misb1602`] implementation
+impl Misb1602 {
+    /// Maps a pixel of the composite image, at zero-based `row` and
+    /// `column`, to a `(row, column)` within the source image
+    /// 
+    /// Returns [`None`] if the pixel is outside of the active sub-image,
+    /// or if the sub-image placement is not present.
+    /// 
+    /// Note that pixels of a [`crate::misb0903::Misb0903Target`] are
+    /// numbered from 1, and must be decremented first.
+    pub fn to_source_pixel(&self, row: u32, column: u32) -> Option<(f64, f64)> {
+        let (rows, columns) = (self.sub_image_rows?, self.sub_image_columns?);
+        let active_rows = self.active_sub_image_rows.unwrap_or(rows);
+        let active_columns = self.active_sub_image_columns.unwrap_or(columns);
+        // --------------------------------------------------
+        // position of the pixel within the active sub-image
+        // --------------------------------------------------
+        let y = row.checked_sub(self.sub_image_position_y?.saturating_add(self.active_sub_image_offset_y.unwrap_or(0)))?;
+        let x = column.checked_sub(self.sub_image_position_x?.saturating_add(self.active_sub_image_offset_x.unwrap_or(0)))?;
+        if y >= active_rows || x >= active_columns { return None }
+        // --------------------------------------------------
+        // scale from the active sub-image to the source AOI
+        // --------------------------------------------------
+        let aoi_rows = self.source_image_aoi_rows.or(self.source_image_rows).unwrap_or(active_rows);
+        let aoi_columns = self.source_image_aoi_columns.or(self.source_image_columns).unwrap_or(active_columns);
+        Some((
+            self.source_image_aoi_position_y.unwrap_or(0) as f64 + y as f64 * aoi_rows as f64 / active_rows as f64,
+            self.source_image_aoi_position_x.unwrap_or(0) as f64 + x as f64 * aoi_columns as f64 / active_columns as f64,
+        ))
+    }
+
+    /// Encodes every present item of the [`Misb1602`] in tag order
+    /// 
+    /// This is the value of the Composite Imaging LS when embedded within
+    /// MISB 0601, see [`crate::misb0601::Misb0601::composite_imaging_local_set`]
+    pub fn encode_value(&self) -> Vec<u8> {
+        let mut output = Vec::new();
+        if let Some(x) = self.precision_timestamp { crate::encode_item(&mut output, 0x01, &crate::misb0601::ops::from_precision_timestamp(x)) }
+        if let Some(x) = self.document_version { crate::encode_item(&mut output, 0x02, &[x]) }
+        if let Some(x) = self.source_image_rows { crate::encode_item(&mut output, 0x03, &x.to_be_bytes()) }
+        if let Some(x) = self.source_image_columns { crate::encode_item(&mut output, 0x04, &x.to_be_bytes()) }
+        if let Some(x) = self.source_image_aoi_rows { crate::encode_item(&mut output, 0x05, &x.to_be_bytes()) }
+        if let Some(x) = self.source_image_aoi_columns { crate::encode_item(&mut output, 0x06, &x.to_be_bytes()) }
+        if let Some(x) = self.source_image_aoi_position_x { crate::encode_item(&mut output, 0x07, &x.to_be_bytes()) }
+        if let Some(x) = self.source_image_aoi_position_y { crate::encode_item(&mut output, 0x08, &x.to_be_bytes()) }
+        if let Some(x) = self.sub_image_rows { crate::encode_item(&mut output, 0x09, &x.to_be_bytes()) }
+        if let Some(x) = self.sub_image_columns { crate::encode_item(&mut output, 0x0A, &x.to_be_bytes()) }
+        if let Some(x) = self.sub_image_position_x { crate::encode_item(&mut output, 0x0B, &x.to_be_bytes()) }
+        if let Some(x) = self.sub_image_position_y { crate::encode_item(&mut output, 0x0C, &x.to_be_bytes()) }
+        if let Some(x) = self.active_sub_image_rows { crate::encode_item(&mut output, 0x0D, &x.to_be_bytes()) }
+        if let Some(x) = self.active_sub_image_columns { crate::encode_item(&mut output, 0x0E, &x.to_be_bytes()) }
+        if let Some(x) = self.active_sub_image_offset_x { crate::encode_item(&mut output, 0x0F, &x.to_be_bytes()) }
+        if let Some(x) = self.active_sub_image_offset_y { crate::encode_item(&mut output, 0x10, &x.to_be_bytes()) }
+        if let Some(x) = self.transparency { crate::encode_item(&mut output, 0x11, &[x]) }
+        if let Some(x) = self.z_order { crate::encode_item(&mut output, 0x12, &[x]) }
+        if let Some(x) = &self.miis_core_identifier { crate::encode_item(&mut output, 0x13, &x.encode()) }
+        output
+    }
+}
+
+#[cfg(feature = "misb1602-1")]
+/// Finds the sub-image containing the pixel of the composite image at
+/// zero-based `row` and `column`
+/// 
+/// When sub-images overlap, the sub-image with the largest
+/// [`Misb1602::z_order`] is returned.
+/// 
+/// See [`Misb1602::to_source_pixel`]
+pub fn find_sub_image(sub_images: &[Misb1602], row: u32, column: u32) -> Option<&Misb1602> {
+    sub_images
+        .iter()
+        .filter(|x| x.to_source_pixel(row, column).is_some())
+        .max_by_key(|x| x.z_order.unwrap_or(0))
+}
+
+#[cfg(test)]
+#[cfg(feature = "misb1602-1")]
+mod test {
+    use super::*;
+    use tinyklv::prelude::*;
+    use crate::misb1204::{IdType, Misb1204Miis, Uuid, MIIS_VERSION};
+
+    fn sub_image(position_x: u32, z_order: Option<u8>) -> Misb1602 {
+        Misb1602 {
+            precision_timestamp: None,
+            document_version: Some(1),
+            source_image_rows: Some(1080),
+            source_image_columns: Some(1920),
+            source_image_aoi_rows: None,
+            source_image_aoi_columns: None,
+            source_image_aoi_position_x: None,
+            source_image_aoi_position_y: None,
+            sub_image_rows: Some(540),
+            sub_image_columns: Some(960),
+            sub_image_position_x: Some(position_x),
+            sub_image_position_y: Some(0),
+            active_sub_image_rows: None,
+            active_sub_image_columns: None,
+            active_sub_image_offset_x: None,
+            active_sub_image_offset_y: None,
+            transparency: None,
+            z_order,
+            miis_core_identifier: Some(Misb1204Miis {
+                version: MIIS_VERSION,
+                sensor_id: Some((IdType::Physical, Uuid([0x11; 16]))),
+                platform_id: None,
+                window_id: None,
+                minor_id: None,
+            }),
+        }
+    }
+
+    #[test]
+    fn encode_roundtrip() {
+        let composite = sub_image(960, Some(1));
+        let encoded = composite.encode_value();
+        let decoded = Misb1602::decode(&mut encoded.as_slice()).unwrap();
+        assert_eq!(decoded.document_version, Some(1));
+        assert_eq!((decoded.source_image_rows, decoded.source_image_columns), (Some(1080), Some(1920)));
+        assert_eq!((decoded.sub_image_position_x, decoded.sub_image_position_y), (Some(960), Some(0)));
+        assert_eq!(decoded.z_order, Some(1));
+        assert_eq!(decoded.miis_core_identifier, composite.miis_core_identifier);
+        assert_eq!(decoded.encode_value(), encoded);
+    }
+
+    #[test]
+    fn mosaic() {
+        // --------------------------------------------------
+        // two side-by-side tiles, with a picture-in-picture
+        // overlay on the left tile
+        // --------------------------------------------------
+        let mut overlay = sub_image(100, Some(2));
+        overlay.sub_image_rows = Some(108);
+        overlay.sub_image_columns = Some(192);
+        overlay.sub_image_position_y = Some(100);
+        let tiles = [sub_image(0, None), sub_image(960, None), overlay];
+        assert_eq!(tiles[1].to_source_pixel(270, 1440), Some((540.0, 960.0)));
+        assert_eq!(tiles[1].to_source_pixel(270, 959), None);
+        assert_eq!(tiles[1].to_source_pixel(540, 1440), None);
+        assert_eq!(tiles[2].to_source_pixel(154, 196), Some((540.0, 960.0)));
+        assert_eq!(find_sub_image(&tiles, 10, 10).and_then(|x| x.sub_image_position_x), Some(0));
+        assert_eq!(find_sub_image(&tiles, 10, 1000).and_then(|x| x.sub_image_position_x), Some(960));
+        assert_eq!(find_sub_image(&tiles, 150, 150).and_then(|x| x.sub_image_position_x), Some(100));
+        assert!(find_sub_image(&tiles, 600, 10).is_none());
+    }
+}
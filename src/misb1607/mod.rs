@@ -0,0 +1,108 @@
+// --------------------------------------------------
+// tinyklv
+// --------------------------------------------------
+use tinyklv::prelude::*;
+
+// --------------------------------------------------
+// local
+// --------------------------------------------------
+pub mod ops;
+
+/// Key of the Segment LS within MISB 0601
+pub const SEGMENT_KEY: u64 = 0x64;
+
+/// Key of the Amend LS within MISB 0601
+pub const AMEND_KEY: u64 = 0x65;
+
+#[cfg(any(
+    feature = "misb1607-1",
+))]
+#[derive(Debug, Clone, Default, PartialEq)]
+/// Child Local Set, used as either a Segment LS or an Amend LS
+/// 
+/// MISB Standard 1607
+/// 
+/// Both contain items of the parent local set (i.e., MISB 0601), using
+/// the parent's keys:
+/// 
+/// * An **Amend LS** overrides items of the parent. A zero-length item
+///   removes the item from the parent.
+/// * A **Segment LS** describes a single segment (e.g., one of several
+///   sensors), and inherits every item of the parent it does not contain.
+/// 
+/// The items are kept as raw bytes, since their meaning depends on the
+/// parent. To view them as a [`crate::misb0601::Misb0601`], see
+/// [`Misb1607::apply_to`], [`crate::misb0601::Misb0601::amended`], and
+/// [`crate::misb0601::Misb0601::segments`].
+/// 
+/// For more information, see [Motion Imagery Standards Board (MISB)](https://nsgreg.nga.mil/misb.jsp)
+pub struct Misb1607 {
+    /// Key and value of each item, in stream order
+    pub items: Vec<(u64, Vec<u8>)>,
+}
+
+#[cfg(feature = "misb1607-1")]
+/// [`Misb1607`] implementation
+impl Misb1607 {
+    /// Returns the value of the item with `key`, if present
+    pub fn get(&self, key: u64) -> Option<&[u8]> {
+        self.items.iter().find(|(k, _)| *k == key).map(|(_, v)| v.as_slice())
+    }
+
+    /// Overlays the items of the [`Misb1607`] onto the `parent`, returning
+    /// the resulting [`crate::misb0601::Misb0601`]
+    /// 
+    /// Items of the [`Misb1607`] replace items of the `parent` with the same
+    /// key, and zero-length items remove them. Any Segment LS or Amend LS of
+    /// the `parent` is not inherited.
+    /// 
+    /// The items are overlaid onto the [`crate::misb0601::Misb0601::raw_items`]
+    /// of the `parent`, keeping their original bytes and order, with new
+    /// items appended. If the `parent` was not decoded, its encoded items are
+    /// used instead. The [`crate::misb0601::Misb0601::checksum`] is computed
+    /// over the resulting items, see [`crate::misb0601::Misb0601::decode_items`].
+    pub fn apply_to(&self, parent: &crate::misb0601::Misb0601) -> winnow::PResult<crate::misb0601::Misb0601> {
+        let mut items = match parent.raw_items.is_empty() {
+            false => parent.raw_items.clone(),
+            true => Self::decode(&mut parent.encode_value().as_slice())?.items,
+        };
+        items.retain(|(key, _)| *key != SEGMENT_KEY && *key != AMEND_KEY);
+        for (key, value) in &self.items {
+            match (items.iter().position(|(k, _)| k == key), value.is_empty()) {
+                (Some(i), true) => { items.remove(i); },
+                (Some(i), false) => items[i].1 = value.clone(),
+                (None, true) => (),
+                (None, false) => items.push((*key, value.clone())),
+            }
+        }
+        crate::misb0601::Misb0601::decode_items(&items)
+    }
+
+    /// Encodes every item of the [`Misb1607`] in stream order
+    /// 
+    /// This is the value of the Segment LS or Amend LS when embedded within
+    /// MISB 0601, see [`crate::misb0601::Misb0601::segment_local_sets`] and
+    /// [`crate::misb0601::Misb0601::amend_local_set`]
+    pub fn encode_value(&self) -> Vec<u8> {
+        let mut output = Vec::new();
+        for (key, value) in &self.items { crate::encode_item(&mut output, *key, value) }
+        output
+    }
+}
+
+#[cfg(feature = "misb1607-1")]
+/// [`Misb1607`] implementation of [`tinyklv::prelude::Decode`]
+/// 
+/// Decodes every item until `input` is exhausted.
+impl tinyklv::prelude::Decode<&[u8]> for Misb1607 {
+    fn decode(input: &mut &[u8]) -> winnow::PResult<Self> {
+        let mut output = Self::default();
+        while !input.is_empty() {
+            let key = tinyklv::codecs::ber::dec::ber_oid::<u64>.parse_next(input)?;
+            let len = tinyklv::codecs::ber::dec::ber_length.parse_next(input)?;
+            let value = winnow::token::take(len).parse_next(input)?;
+            output.items.push((key, value.to_vec()));
+        }
+        Ok(output)
+    }
+}
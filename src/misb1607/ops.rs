@@ -0,0 +1,40 @@
+// --------------------------------------------------
+// tinyklv
+// --------------------------------------------------
+use tinyklv::prelude::*;
+
+// --------------------------------------------------
+// local
+// --------------------------------------------------
+use crate::misb1607::{Misb1607, SEGMENT_KEY};
+
+/// See [`crate::misb0601::Misb0601::amend_local_set`]
+/// 
+/// A [`Misb1607`] of length `len`
+pub fn to_amend(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<Misb1607> {
+    move |input: &mut &[u8]| {
+        let mut value = winnow::token::take(len).parse_next(input)?;
+        Misb1607::decode(&mut value)
+    }
+}
+
+/// See [`crate::misb0601::Misb0601::segment_local_sets`]
+/// 
+/// A single [`Misb1607`] of length `len`. Since the Segment LS may be
+/// repeated, every segment is collected afterwards using [`to_segments`].
+pub fn to_segment(len: usize) -> impl Fn(&mut &[u8]) -> winnow::PResult<Vec<Misb1607>> {
+    move |input: &mut &[u8]| Ok(vec![to_amend(len)(input)?])
+}
+
+/// Collects every Segment LS within the value of a UAS Datalink LS
+/// packet (i.e., without the UL and length), in stream order
+/// 
+/// See [`crate::misb0601::Misb0601::segment_local_sets`]
+pub fn to_segments(input: &[u8]) -> winnow::PResult<Vec<Misb1607>> {
+    Misb1607::decode(&mut &input[..])?
+        .items
+        .into_iter()
+        .filter(|(key, _)| *key == SEGMENT_KEY)
+        .map(|(_, value)| Misb1607::decode(&mut value.as_slice()))
+        .collect()
+}